@@ -2,7 +2,9 @@ use crate::nop_lib::Nop;
 use clap::{App, Arg, ArgMatches, SubCommand};
 use mdbook::book::Book;
 use mdbook::errors::Error;
+use mdbook::plugin::{self, Capabilities, ConfigType, Request, Response, ResponseError};
 use mdbook::preprocess::{CmdPreprocessor, Preprocessor, PreprocessorContext};
+use std::collections::BTreeMap;
 use std::io;
 use std::process;
 
@@ -14,6 +16,13 @@ pub fn make_app() -> App<'static, 'static> {
                 .arg(Arg::with_name("renderer").required(true))
                 .about("Check whether a renderer is supported by this preprocessor"),
         )
+        .subcommand(
+            SubCommand::with_name("capabilities")
+                .about("Tell mdbook which protocol and config this preprocessor understands"),
+        )
+        .subcommand(
+            SubCommand::with_name("rpc").about("Keep running, and answer requests from mdbook"),
+        )
 }
 
 fn main() {
@@ -24,6 +33,16 @@ fn main() {
 
     if let Some(sub_args) = matches.subcommand_matches("supports") {
         handle_supports(&preprocessor, sub_args);
+    } else if matches.subcommand_matches("capabilities").is_some() {
+        if let Err(e) = handle_capabilities() {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    } else if matches.subcommand_matches("rpc").is_some() {
+        if let Err(e) = handle_rpc(&preprocessor) {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
     } else if let Err(e) = handle_preprocessing(&preprocessor) {
         eprintln!("{}", e);
         process::exit(1);
@@ -63,6 +82,54 @@ fn handle_supports(pre: &dyn Preprocessor, sub_args: &ArgMatches) -> ! {
     }
 }
 
+fn handle_capabilities() -> Result<(), Error> {
+    // Which renderers are supported is left to `supports`, but the only config
+    // this preprocessor understands is `blow-up`
+    let mut config = BTreeMap::new();
+    config.insert("blow-up".to_string(), ConfigType::Boolean);
+
+    let mut capabilities = Capabilities::new();
+    capabilities.config = Some(config);
+    capabilities.persistent = true;
+    serde_json::to_writer(io::stdout(), &capabilities)?;
+
+    Ok(())
+}
+
+fn handle_rpc(pre: &dyn Preprocessor) -> Result<(), Error> {
+    let stdin = io::stdin();
+    let mut stdin = stdin.lock();
+
+    // Requests keep coming until mdbook hangs up
+    while let Some(request) = plugin::read_message::<_, Request>(&mut stdin)? {
+        let response = match request.method.as_str() {
+            "preprocess" => {
+                let (ctx, book): (PreprocessorContext, Book) =
+                    serde_json::from_value(request.params)?;
+                match pre.run(&ctx, book) {
+                    Ok(book) => Response::result(request.id, serde_json::to_value(book)?),
+                    Err(e) => Response::error(request.id, e.to_string()),
+                }
+            }
+            "supports" => {
+                let (renderer,): (String,) = serde_json::from_value(request.params)?;
+                let supported = pre.supports_renderer(&renderer);
+                Response::result(request.id, serde_json::Value::Bool(supported))
+            }
+            method => {
+                let mut response = Response::error(request.id, format!("No method {}", method));
+                if let Some(ref mut error) = response.error {
+                    error.code = ResponseError::METHOD_NOT_FOUND;
+                }
+                response
+            }
+        };
+        plugin::write_message(io::stdout(), &response)?;
+    }
+
+    Ok(())
+}
+
 /// The actual implementation of the `Nop` preprocessor. This would usually go
 /// in your main `lib.rs` file.
 mod nop_lib {
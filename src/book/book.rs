@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt::{self, Display, Formatter};
 use std::fs::{self, File};
 use std::io::{Read, Write};
@@ -24,7 +24,7 @@ pub fn load_book<P: AsRef<Path>>(src_dir: P, cfg: &BuildConfig) -> Result<Book>
         create_missing(&src_dir, &summary).chain_err(|| "Unable to create missing chapters")?;
     }
 
-    load_book_from_disk(&summary, src_dir)
+    load_book_from_disk(&summary, src_dir, cfg)
 }
 
 fn create_missing(src_dir: &Path, summary: &Summary) -> Result<()> {
@@ -39,17 +39,20 @@ fn create_missing(src_dir: &Path, summary: &Summary) -> Result<()> {
         let next = items.pop().expect("already checked");
 
         if let SummaryItem::Link(ref link) = *next {
-            let filename = src_dir.join(&link.location);
-            if !filename.exists() {
-                if let Some(parent) = filename.parent() {
-                    if !parent.exists() {
-                        fs::create_dir_all(parent)?;
+            // draft chapters don't have a file, so there's nothing to create
+            if let Some(ref location) = link.location {
+                let filename = src_dir.join(location);
+                if !filename.exists() {
+                    if let Some(parent) = filename.parent() {
+                        if !parent.exists() {
+                            fs::create_dir_all(parent)?;
+                        }
                     }
-                }
-                debug!("Creating missing file {}", filename.display());
+                    debug!("Creating missing file {}", filename.display());
 
-                let mut f = File::create(&filename)?;
-                writeln!(f, "# {}", link.name)?;
+                    let mut f = File::create(&filename)?;
+                    writeln!(f, "# {}", link.name)?;
+                }
             }
 
             items.extend(&link.nested_items);
@@ -131,6 +134,8 @@ pub enum BookItem {
     Chapter(Chapter),
     /// A section separator.
     Separator,
+    /// A part title, grouping the chapters that follow it.
+    PartTitle(String),
 }
 
 impl From<Chapter> for BookItem {
@@ -151,10 +156,15 @@ pub struct Chapter {
     pub number: Option<SectionNumber>,
     /// Nested items.
     pub sub_items: Vec<BookItem>,
-    /// The chapter's location, relative to the `SUMMARY.md` file.
-    pub path: PathBuf,
+    /// The chapter's location, relative to the `SUMMARY.md` file. Draft
+    /// chapters don't have a backing file, so this is `None` for them.
+    pub path: Option<PathBuf>,
     /// An ordered list of the names of each chapter above this one, in the hierarchy.
     pub parent_names: Vec<String>,
+    /// The data from the chapter's front matter, such as its authors or
+    /// description.
+    #[serde(default)]
+    pub meta: BTreeMap<String, serde_json::Value>,
 }
 
 impl Chapter {
@@ -168,18 +178,59 @@ impl Chapter {
         Chapter {
             name: name.to_string(),
             content,
-            path: path.into(),
+            path: Some(path.into()),
             parent_names,
             ..Default::default()
         }
     }
+
+    /// Create a new draft chapter that is not attached to a source markdown
+    /// file and has no content.
+    pub fn new_draft(name: &str, parent_names: Vec<String>) -> Self {
+        Chapter {
+            name: name.to_string(),
+            content: String::new(),
+            path: None,
+            parent_names,
+            ..Default::default()
+        }
+    }
+
+    /// Check if the chapter is a draft chapter, meaning it has no path to a
+    /// source markdown file.
+    pub fn is_draft_chapter(&self) -> bool {
+        self.path.is_none()
+    }
 }
 
 /// Use the provided `Summary` to load a `Book` from disk.
 ///
 /// You need to pass in the book's source directory because all the links in
 /// `SUMMARY.md` give the chapter locations relative to it.
-pub(crate) fn load_book_from_disk<P: AsRef<Path>>(summary: &Summary, src_dir: P) -> Result<Book> {
+pub(crate) fn load_book_from_disk<P: AsRef<Path>>(
+    summary: &Summary,
+    src_dir: P,
+    cfg: &BuildConfig,
+) -> Result<Book> {
+    load_book_with_fallback(summary, src_dir, cfg, None)
+}
+
+/// Where a translation's chapters are read from when it doesn't have them yet.
+pub(crate) struct Fallback<'a> {
+    /// The source directory of the book's default language.
+    pub(crate) src_dir: &'a Path,
+    /// Put at the top of each chapter which is read from `src_dir`.
+    pub(crate) notice: &'a str,
+}
+
+/// Load a book, reading any chapters which are missing from `src_dir` from
+/// the `fallback` instead.
+pub(crate) fn load_book_with_fallback<P: AsRef<Path>>(
+    summary: &Summary,
+    src_dir: P,
+    cfg: &BuildConfig,
+    fallback: Option<&Fallback<'_>>,
+) -> Result<Book> {
     debug!("Loading the book from disk");
     let src_dir = src_dir.as_ref();
 
@@ -192,7 +243,7 @@ pub(crate) fn load_book_from_disk<P: AsRef<Path>>(summary: &Summary, src_dir: P)
     let mut chapters = Vec::new();
 
     for summary_item in summary_items {
-        let chapter = load_summary_item(summary_item, src_dir, Vec::new())?;
+        let chapter = load_summary_item(summary_item, src_dir, cfg, fallback, Vec::new())?;
         chapters.push(chapter);
     }
 
@@ -205,12 +256,15 @@ pub(crate) fn load_book_from_disk<P: AsRef<Path>>(summary: &Summary, src_dir: P)
 fn load_summary_item<P: AsRef<Path>>(
     item: &SummaryItem,
     src_dir: P,
+    cfg: &BuildConfig,
+    fallback: Option<&Fallback<'_>>,
     parent_names: Vec<String>,
 ) -> Result<BookItem> {
     match *item {
         SummaryItem::Separator => Ok(BookItem::Separator),
+        SummaryItem::PartTitle(ref title) => Ok(BookItem::PartTitle(title.clone())),
         SummaryItem::Link(ref link) => {
-            load_chapter(link, src_dir, parent_names).map(BookItem::Chapter)
+            load_chapter(link, src_dir, cfg, fallback, parent_names).map(BookItem::Chapter)
         }
     }
 }
@@ -218,37 +272,76 @@ fn load_summary_item<P: AsRef<Path>>(
 fn load_chapter<P: AsRef<Path>>(
     link: &Link,
     src_dir: P,
+    cfg: &BuildConfig,
+    fallback: Option<&Fallback<'_>>,
     parent_names: Vec<String>,
 ) -> Result<Chapter> {
-    debug!("Loading {} ({})", link.name, link.location.display());
     let src_dir = src_dir.as_ref();
 
-    let location = if link.location.is_absolute() {
-        link.location.clone()
-    } else {
-        src_dir.join(&link.location)
-    };
+    let mut ch = if let Some(ref link_location) = link.location {
+        debug!("Loading {} ({})", link.name, link_location.display());
+
+        let location = if link_location.is_absolute() {
+            link_location.clone()
+        } else {
+            src_dir.join(link_location)
+        };
 
-    let mut f = File::open(&location)
-        .chain_err(|| format!("Chapter file not found, {}", link.location.display()))?;
+        let (file, notice) = match fallback {
+            Some(fallback) if !location.exists() => {
+                debug!("{} hasn't been translated yet", link_location.display());
+                (fallback.src_dir.join(link_location), Some(fallback.notice))
+            }
+            _ => (location.clone(), None),
+        };
+
+        let mut f = File::open(&file)
+            .chain_err(|| format!("Chapter file not found, {}", link_location.display()))?;
+
+        let mut content = String::new();
+        f.read_to_string(&mut content)
+            .chain_err(|| format!("Unable to read \"{}\" ({})", link.name, file.display()))?;
+
+        let mut meta = BTreeMap::new();
+        if cfg.front_matter {
+            let (front_matter, body) = parse_front_matter(&content).chain_err(|| {
+                format!(
+                    "Unable to parse the front matter of \"{}\" ({})",
+                    link.name,
+                    file.display()
+                )
+            })?;
+            meta = front_matter;
+            content = body.to_string();
+        }
 
-    let mut content = String::new();
-    f.read_to_string(&mut content)
-        .chain_err(|| format!("Unable to read \"{}\" ({})", link.name, location.display()))?;
+        if let Some(notice) = notice {
+            content = format!(
+                "<p class=\"untranslated-notice\">{}</p>\n\n{}",
+                notice, content
+            );
+        }
 
-    let stripped = location
-        .strip_prefix(&src_dir)
-        .expect("Chapters are always inside a book");
+        let stripped = location
+            .strip_prefix(&src_dir)
+            .expect("Chapters are always inside a book");
 
-    let mut sub_item_parents = parent_names.clone();
-    let mut ch = Chapter::new(&link.name, content, stripped, parent_names);
+        let mut ch = Chapter::new(&link.name, content, stripped, parent_names.clone());
+        ch.meta = meta;
+        ch
+    } else {
+        debug!("Loading draft chapter {}", link.name);
+        Chapter::new_draft(&link.name, parent_names.clone())
+    };
+
+    let mut sub_item_parents = parent_names;
     ch.number = link.number.clone();
 
     sub_item_parents.push(link.name.clone());
     let sub_items = link
         .nested_items
         .iter()
-        .map(|i| load_summary_item(i, src_dir, sub_item_parents.clone()))
+        .map(|i| load_summary_item(i, src_dir, cfg, fallback, sub_item_parents.clone()))
         .collect::<Result<Vec<_>>>()?;
 
     ch.sub_items = sub_items;
@@ -256,6 +349,60 @@ fn load_chapter<P: AsRef<Path>>(
     Ok(ch)
 }
 
+/// Split the front matter off the top of a chapter, if it has any. It's
+/// either YAML between `---` lines, or TOML between `+++` lines.
+///
+/// A `---` line is also a horizontal rule in markdown, so YAML which isn't a
+/// mapping is left in the chapter rather than taken as front matter.
+pub(crate) fn parse_front_matter(
+    content: &str,
+) -> Result<(BTreeMap<String, serde_json::Value>, &str)> {
+    let (delimiter, front_matter, body) = match split_front_matter(content) {
+        Some(parts) => parts,
+        None => return Ok((BTreeMap::new(), content)),
+    };
+
+    let meta = if delimiter == "---" {
+        match serde_yaml::from_str(front_matter) {
+            Ok(yaml @ serde_yaml::Value::Mapping(_)) => {
+                serde_yaml::from_value(yaml).chain_err(|| "Invalid YAML")?
+            }
+            _ => return Ok((BTreeMap::new(), content)),
+        }
+    } else if front_matter.trim().is_empty() {
+        BTreeMap::new()
+    } else {
+        toml::from_str(front_matter).chain_err(|| "Invalid TOML")?
+    };
+
+    Ok((meta, body))
+}
+
+/// Finds the delimiter on the first line, the front matter, and the content
+/// after the line which closes it.
+fn split_front_matter(content: &str) -> Option<(&str, &str, &str)> {
+    let first_line_end = content.find('\n')?;
+    let delimiter = content[..first_line_end].trim_end();
+    if delimiter != "---" && delimiter != "+++" {
+        return None;
+    }
+
+    let rest = &content[first_line_end + 1..];
+    let mut line_start = 0;
+    while line_start <= rest.len() {
+        let line_end = rest[line_start..]
+            .find('\n')
+            .map_or(rest.len(), |i| line_start + i);
+        if rest[line_start..line_end].trim_end() == delimiter {
+            let body = rest.get(line_end + 1..).unwrap_or("");
+            return Some((delimiter, &rest[..line_start], body));
+        }
+        line_start = line_end + 1;
+    }
+
+    None
+}
+
 /// A depth-first iterator over the items in a book.
 ///
 /// # Note
@@ -348,6 +495,7 @@ And here is some \
 
     #[test]
     fn load_a_single_chapter_from_disk() {
+        let cfg = BuildConfig::default();
         let (link, temp_dir) = dummy_link();
         let should_be = Chapter::new(
             "Chapter 1",
@@ -356,36 +504,161 @@ And here is some \
             Vec::new(),
         );
 
-        let got = load_chapter(&link, temp_dir.path(), Vec::new()).unwrap();
+        let got = load_chapter(&link, temp_dir.path(), &cfg, None, Vec::new()).unwrap();
         assert_eq!(got, should_be);
     }
 
+    #[test]
+    fn front_matter_is_parsed_and_removed() {
+        let cfg = BuildConfig {
+            front_matter: true,
+            ..Default::default()
+        };
+        let (link, temp_dir) = dummy_link();
+        let chapter_path = temp_dir.path().join("chapter_1.md");
+        fs::write(
+            &chapter_path,
+            format!("---\nauthors: [Jane]\ndraft: true\n---\n{}", DUMMY_SRC),
+        )
+        .unwrap();
+
+        let got = load_chapter(&link, temp_dir.path(), &cfg, None, Vec::new()).unwrap();
+
+        assert_eq!(got.content, DUMMY_SRC);
+        assert_eq!(got.meta["authors"], json!(["Jane"]));
+        assert_eq!(got.meta["draft"], json!(true));
+    }
+
+    #[test]
+    fn front_matter_is_only_parsed_when_enabled() {
+        let cfg = BuildConfig::default();
+        let (link, temp_dir) = dummy_link();
+        let content = format!("---\nauthors: [Jane]\n---\n{}", DUMMY_SRC);
+        fs::write(temp_dir.path().join("chapter_1.md"), &content).unwrap();
+
+        let got = load_chapter(&link, temp_dir.path(), &cfg, None, Vec::new()).unwrap();
+
+        assert_eq!(got.content, content);
+        assert!(got.meta.is_empty());
+    }
+
+    #[test]
+    fn chapters_starting_with_a_horizontal_rule_keep_it() {
+        let cfg = BuildConfig {
+            front_matter: true,
+            ..Default::default()
+        };
+        let (link, temp_dir) = dummy_link();
+        let content = "---\n\nAn aside.\n\n---\n\n# Chapter 1\n";
+        fs::write(temp_dir.path().join("chapter_1.md"), content).unwrap();
+
+        let got = load_chapter(&link, temp_dir.path(), &cfg, None, Vec::new()).unwrap();
+
+        assert_eq!(got.content, content);
+        assert!(got.meta.is_empty());
+    }
+
+    #[test]
+    fn parse_front_matter_variants() {
+        let (meta, body) = parse_front_matter("+++\ndescription = \"TOML\"\n+++\nBody").unwrap();
+        assert_eq!(meta["description"], json!("TOML"));
+        assert_eq!(body, "Body");
+
+        let (meta, body) = parse_front_matter("+++\r\n+++\r\nBody").unwrap();
+        assert!(meta.is_empty());
+        assert_eq!(body, "Body");
+
+        // A rule which is never closed isn't front matter
+        let content = "---\n\nJust a horizontal rule\n";
+        let (meta, body) = parse_front_matter(content).unwrap();
+        assert!(meta.is_empty());
+        assert_eq!(body, content);
+
+        // Nor is YAML which isn't a mapping, or isn't valid at all
+        for content in &[
+            "---\n---\nBody",
+            "---\nJust text\n---\n",
+            "---\n[unclosed\n---\n",
+        ] {
+            let (meta, body) = parse_front_matter(content).unwrap();
+            assert!(meta.is_empty());
+            assert_eq!(body, *content);
+        }
+
+        assert!(parse_front_matter("+++\n[unclosed\n+++\n").is_err());
+    }
+
+    #[test]
+    fn load_a_draft_chapter() {
+        let cfg = BuildConfig::default();
+        let link = Link {
+            name: String::from("Draft"),
+            location: None,
+            number: Some(SectionNumber(vec![2])),
+            nested_items: Vec::new(),
+        };
+        let mut should_be = Chapter::new_draft("Draft", Vec::new());
+        should_be.number = Some(SectionNumber(vec![2]));
+
+        let got = load_chapter(&link, "", &cfg, None, Vec::new()).unwrap();
+        assert_eq!(got, should_be);
+        assert!(got.is_draft_chapter());
+    }
+
     #[test]
     fn cant_load_a_nonexistent_chapter() {
+        let cfg = BuildConfig::default();
         let link = Link::new("Chapter 1", "/foo/bar/baz.md");
 
-        let got = load_chapter(&link, "", Vec::new());
+        let got = load_chapter(&link, "", &cfg, None, Vec::new());
         assert!(got.is_err());
     }
 
+    #[test]
+    fn untranslated_chapters_are_read_from_the_fallback() {
+        let cfg = BuildConfig::default();
+        let (_, original) = dummy_link();
+        let translation = TempFileBuilder::new().prefix("book").tempdir().unwrap();
+        let fallback = Fallback {
+            src_dir: original.path(),
+            notice: "Not translated yet.",
+        };
+        let link = Link::new("Chapter 1", "chapter_1.md");
+
+        let got =
+            load_chapter(&link, translation.path(), &cfg, Some(&fallback), Vec::new()).unwrap();
+
+        assert_eq!(got.path, Some(PathBuf::from("chapter_1.md")));
+        assert_eq!(
+            got.content,
+            format!(
+                "<p class=\"untranslated-notice\">Not translated yet.</p>\n\n{}",
+                DUMMY_SRC
+            )
+        );
+    }
+
     #[test]
     fn load_recursive_link_with_separators() {
+        let cfg = BuildConfig::default();
         let (root, temp) = nested_links();
 
         let nested = Chapter {
             name: String::from("Nested Chapter 1"),
             content: String::from("Hello World!"),
             number: Some(SectionNumber(vec![1, 2])),
-            path: PathBuf::from("second.md"),
+            path: Some(PathBuf::from("second.md")),
             parent_names: vec![String::from("Chapter 1")],
+            meta: BTreeMap::new(),
             sub_items: Vec::new(),
         };
         let should_be = BookItem::Chapter(Chapter {
             name: String::from("Chapter 1"),
             content: String::from(DUMMY_SRC),
             number: None,
-            path: PathBuf::from("chapter_1.md"),
+            path: Some(PathBuf::from("chapter_1.md")),
             parent_names: Vec::new(),
+            meta: BTreeMap::new(),
             sub_items: vec![
                 BookItem::Chapter(nested.clone()),
                 BookItem::Separator,
@@ -393,12 +666,20 @@ And here is some \
             ],
         });
 
-        let got = load_summary_item(&SummaryItem::Link(root), temp.path(), Vec::new()).unwrap();
+        let got = load_summary_item(
+            &SummaryItem::Link(root),
+            temp.path(),
+            &cfg,
+            None,
+            Vec::new(),
+        )
+        .unwrap();
         assert_eq!(got, should_be);
     }
 
     #[test]
     fn load_a_book_with_a_single_chapter() {
+        let cfg = BuildConfig::default();
         let (link, temp) = dummy_link();
         let summary = Summary {
             numbered_chapters: vec![SummaryItem::Link(link)],
@@ -408,13 +689,13 @@ And here is some \
             sections: vec![BookItem::Chapter(Chapter {
                 name: String::from("Chapter 1"),
                 content: String::from(DUMMY_SRC),
-                path: PathBuf::from("chapter_1.md"),
+                path: Some(PathBuf::from("chapter_1.md")),
                 ..Default::default()
             })],
             ..Default::default()
         };
 
-        let got = load_book_from_disk(&summary, temp.path()).unwrap();
+        let got = load_book_from_disk(&summary, temp.path(), &cfg).unwrap();
 
         assert_eq!(got, should_be);
     }
@@ -448,8 +729,9 @@ And here is some \
                     name: String::from("Chapter 1"),
                     content: String::from(DUMMY_SRC),
                     number: None,
-                    path: PathBuf::from("Chapter_1/index.md"),
+                    path: Some(PathBuf::from("Chapter_1/index.md")),
                     parent_names: Vec::new(),
+                    meta: BTreeMap::new(),
                     sub_items: vec![
                         BookItem::Chapter(Chapter::new(
                             "Hello World",
@@ -500,8 +782,9 @@ And here is some \
                     name: String::from("Chapter 1"),
                     content: String::from(DUMMY_SRC),
                     number: None,
-                    path: PathBuf::from("Chapter_1/index.md"),
+                    path: Some(PathBuf::from("Chapter_1/index.md")),
                     parent_names: Vec::new(),
+                    meta: BTreeMap::new(),
                     sub_items: vec![
                         BookItem::Chapter(Chapter::new(
                             "Hello World",
@@ -533,22 +816,24 @@ And here is some \
 
     #[test]
     fn cant_load_chapters_with_an_empty_path() {
+        let cfg = BuildConfig::default();
         let (_, temp) = dummy_link();
         let summary = Summary {
             numbered_chapters: vec![SummaryItem::Link(Link {
                 name: String::from("Empty"),
-                location: PathBuf::from(""),
+                location: Some(PathBuf::from("")),
                 ..Default::default()
             })],
             ..Default::default()
         };
 
-        let got = load_book_from_disk(&summary, temp.path());
+        let got = load_book_from_disk(&summary, temp.path(), &cfg);
         assert!(got.is_err());
     }
 
     #[test]
     fn cant_load_chapters_when_the_link_is_a_directory() {
+        let cfg = BuildConfig::default();
         let (_, temp) = dummy_link();
         let dir = temp.path().join("nested");
         fs::create_dir(&dir).unwrap();
@@ -556,13 +841,13 @@ And here is some \
         let summary = Summary {
             numbered_chapters: vec![SummaryItem::Link(Link {
                 name: String::from("nested"),
-                location: dir,
+                location: Some(dir),
                 ..Default::default()
             })],
             ..Default::default()
         };
 
-        let got = load_book_from_disk(&summary, temp.path());
+        let got = load_book_from_disk(&summary, temp.path(), &cfg);
         assert!(got.is_err());
     }
 }
@@ -0,0 +1,400 @@
+use std::collections::{HashMap, HashSet};
+use std::ffi::OsStr;
+use std::fmt::{self, Display, Formatter};
+use std::path::{Component, Path, PathBuf};
+
+use pulldown_cmark::{Event, Tag};
+use regex::Regex;
+
+use super::{Book, BookItem};
+use crate::utils;
+
+/// A link or image in a chapter which doesn't point anywhere.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BrokenLink {
+    /// The chapter containing the link, relative to the book's `src`
+    /// directory.
+    pub file: PathBuf,
+    /// The line the link is on, starting from 1.
+    pub line: usize,
+    /// Whether `line` refers to the chapter's content after preprocessing,
+    /// because the link isn't in its source file (e.g. it came from an
+    /// `{{#include}}`).
+    pub after_preprocessing: bool,
+    /// The link's destination, as written.
+    pub link: String,
+    /// What is wrong with the link.
+    pub problem: LinkProblem,
+}
+
+impl Display for BrokenLink {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: ", self.file.display(), self.line)?;
+        if self.after_preprocessing {
+            write!(f, "(after preprocessing) ")?;
+        }
+        write!(f, "{}: {}", self.problem, self.link)
+    }
+}
+
+/// The different ways a link can be broken.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum LinkProblem {
+    /// A link to a markdown file which isn't a chapter in `SUMMARY.md`.
+    UnknownChapter,
+    /// The `#fragment` doesn't match any of the headers in the linked chapter.
+    MissingAnchor,
+    /// An image or other file which doesn't exist in the `src` directory.
+    MissingFile,
+}
+
+impl Display for LinkProblem {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let msg = match *self {
+            LinkProblem::UnknownChapter => "link to a chapter which isn't in SUMMARY.md",
+            LinkProblem::MissingAnchor => "link to an anchor which doesn't exist",
+            LinkProblem::MissingFile => "link to a file which doesn't exist",
+        };
+
+        f.write_str(msg)
+    }
+}
+
+/// Check every link and image in the book's chapters.
+pub(crate) fn check_links(book: &Book, src_dir: &Path) -> Vec<BrokenLink> {
+    let mut header_ids = HashMap::new();
+    for item in book.iter() {
+        if let BookItem::Chapter(ref ch) = *item {
+            if let Some(ref path) = ch.path {
+                header_ids.insert(path.clone(), header_ids_in(&ch.content));
+            }
+        }
+    }
+
+    let checker = LinkChecker {
+        header_ids: &header_ids,
+        src_dir,
+    };
+    let mut broken = Vec::new();
+
+    for item in book.iter() {
+        if let BookItem::Chapter(ref ch) = *item {
+            if let Some(ref path) = ch.path {
+                debug!("Checking the links in {}", path.display());
+                checker.check_chapter(path, &ch.content, &mut broken);
+            }
+        }
+    }
+
+    broken
+}
+
+/// Point the lines of `broken` links, which were found in the preprocessed
+/// book, back at the chapters' sources in `source`. A link which isn't on the
+/// same line of the source is looked for elsewhere in it, and if it isn't
+/// there just once its line is left as it is and marked as such.
+pub(crate) fn map_lines_to_source(broken: &mut [BrokenLink], source: &Book) {
+    let mut sources = HashMap::new();
+    for item in source.iter() {
+        if let BookItem::Chapter(ref ch) = *item {
+            if let Some(ref path) = ch.path {
+                sources.insert(path.as_path(), ch.content.as_str());
+            }
+        }
+    }
+
+    for link in broken {
+        let lines: Vec<&str> = match sources.get(link.file.as_path()) {
+            Some(content) => content.lines().collect(),
+            None => Vec::new(),
+        };
+        let found: Vec<usize> = lines
+            .iter()
+            .enumerate()
+            .filter(|&(_, line)| line.contains(link.link.as_str()))
+            .map(|(i, _)| i + 1)
+            .collect();
+
+        if found.contains(&link.line) {
+            continue;
+        }
+        match found.as_slice() {
+            [line] => link.line = *line,
+            _ => link.after_preprocessing = true,
+        }
+    }
+}
+
+/// All the header ids in a chapter, generated the same way as the HTML
+/// renderer does.
+fn header_ids_in(content: &str) -> HashSet<String> {
+    lazy_static! {
+        static ref HEADER: Regex = Regex::new(r"<h\d>(.*?)</h\d>").unwrap();
+    }
+
+    let html = utils::render_markdown(content, false);
+    let mut id_counter = HashMap::new();
+
+    HEADER
+        .captures_iter(&html)
+        .map(|caps| {
+            let raw_id = utils::id_from_content(&caps[1]);
+            let id_count = id_counter.entry(raw_id.clone()).or_insert(0);
+            let id = match *id_count {
+                0 => raw_id,
+                other => format!("{}-{}", raw_id, other),
+            };
+            *id_count += 1;
+            id
+        })
+        .collect()
+}
+
+struct LinkChecker<'a> {
+    header_ids: &'a HashMap<PathBuf, HashSet<String>>,
+    src_dir: &'a Path,
+}
+
+impl<'a> LinkChecker<'a> {
+    fn check_chapter(&self, path: &Path, content: &str, broken: &mut Vec<BrokenLink>) {
+        lazy_static! {
+            static ref HTML_LINK: Regex =
+                Regex::new(r#"<(a|img) [^>]*?(?:src|href)="([^"]+?)""#).unwrap();
+        }
+
+        for (event, range) in utils::new_cmark_parser(content).into_offset_iter() {
+            let mut links = Vec::new();
+
+            match event {
+                Event::Start(Tag::Link(_, dest, _)) => links.push((dest.to_string(), false)),
+                Event::Start(Tag::Image(_, dest, _)) => links.push((dest.to_string(), true)),
+                Event::Html(ref html) | Event::InlineHtml(ref html) => {
+                    for caps in HTML_LINK.captures_iter(html) {
+                        links.push((caps[2].to_string(), &caps[1] == "img"));
+                    }
+                }
+                _ => {}
+            }
+
+            for (link, is_image) in links {
+                if let Some(problem) = self.check_link(path, &link, is_image) {
+                    broken.push(BrokenLink {
+                        file: path.to_path_buf(),
+                        line: content[..range.start].matches('\n').count() + 1,
+                        after_preprocessing: false,
+                        link,
+                        problem,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Check a single link found in the chapter at `chapter_path`.
+    fn check_link(&self, chapter_path: &Path, link: &str, is_image: bool) -> Option<LinkProblem> {
+        lazy_static! {
+            static ref SCHEME_LINK: Regex = Regex::new(r"^[a-z][a-z0-9+.-]*:").unwrap();
+        }
+
+        // We can't say anything about external links or absolute paths
+        if link.is_empty() || SCHEME_LINK.is_match(link) || link.starts_with('/') {
+            return None;
+        }
+
+        let mut parts = link.splitn(2, '#');
+        let file = parts.next().unwrap_or("");
+        let fragment = parts.next().filter(|f| !f.is_empty());
+        let file = percent_decode(file.split('?').next().unwrap_or(""));
+
+        if file.is_empty() {
+            return self.check_fragment(chapter_path, fragment);
+        }
+
+        let parent = chapter_path.parent().unwrap_or_else(|| Path::new(""));
+        // Links which leave the `src` directory can't be checked either
+        let target = normalize_path(&parent.join(&file))?;
+
+        let is_md = target.extension() == Some(OsStr::new("md"));
+        let is_chapter_page = target.extension() == Some(OsStr::new("html"))
+            && self.header_ids.contains_key(&target.with_extension("md"));
+
+        if !is_image && (is_md || is_chapter_page) {
+            let chapter = target.with_extension("md");
+            if !self.header_ids.contains_key(&chapter) {
+                return Some(LinkProblem::UnknownChapter);
+            }
+            self.check_fragment(&chapter, fragment)
+        } else if self.src_dir.join(&target).exists() {
+            None
+        } else {
+            Some(LinkProblem::MissingFile)
+        }
+    }
+
+    fn check_fragment(&self, chapter: &Path, fragment: Option<&str>) -> Option<LinkProblem> {
+        let fragment = fragment?;
+        let ids = self.header_ids.get(chapter)?;
+
+        if ids.contains(&percent_decode(fragment)) {
+            None
+        } else {
+            Some(LinkProblem::MissingAnchor)
+        }
+    }
+}
+
+/// Resolve any `.` and `..` components in a relative path, returning `None`
+/// if it would go above its starting point.
+fn normalize_path(path: &Path) -> Option<PathBuf> {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                if !normalized.pop() {
+                    return None;
+                }
+            }
+            Component::Normal(part) => normalized.push(part),
+            _ => {}
+        }
+    }
+
+    Some(normalized)
+}
+
+/// Decode `%xx` escapes, such as the `%20` used for spaces in links.
+fn percent_decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let escaped = if bytes[i] == b'%' {
+            text.get(i + 1..i + 3)
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        } else {
+            None
+        };
+
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => {
+                decoded.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::book::Chapter;
+    use std::fs;
+    use tempfile::Builder as TempFileBuilder;
+
+    fn check(chapters: &[(&str, &str)]) -> Vec<(String, usize, LinkProblem)> {
+        let temp = TempFileBuilder::new().prefix("check").tempdir().unwrap();
+        fs::create_dir(temp.path().join("images")).unwrap();
+        fs::write(temp.path().join("images/picture.png"), b"").unwrap();
+
+        let mut book = Book::new();
+        for &(path, content) in chapters {
+            let ch = Chapter::new("Chapter", content.to_string(), path, Vec::new());
+            book.push_item(ch);
+        }
+
+        check_links(&book, temp.path())
+            .into_iter()
+            .map(|broken| (broken.link, broken.line, broken.problem))
+            .collect()
+    }
+
+    #[test]
+    fn valid_links_are_ok() {
+        let content = "# Intro\n\n\
+                       [self](#intro) [other](../second.md#some-section)\n\
+                       [html](../second.html) [external](https://example.com/missing.md)\n\
+                       ![image](../images/picture.png) [outside](../../std/index.html)\n\
+                       <a href=\"nested.md\">raw</a> <img src=\"../images/picture.png\">\n\
+                       [space](../images/picture%2Epng) [query](nested.md?foo=bar#intro)";
+
+        let got = check(&[
+            ("first/nested.md", content),
+            ("second.md", "## Some Section"),
+        ]);
+
+        assert!(got.is_empty(), "{:?}", got);
+    }
+
+    #[test]
+    fn broken_links_are_reported_with_their_line() {
+        let content = "# Intro\n\n\
+                       [missing chapter](missing.md)\n\
+                       [missing anchor](second.md#nope) [local](#nope)\n\
+                       ![missing image](images/nope.png)\n\
+                       <a href=\"missing.md\">raw</a>";
+
+        let got = check(&[("first.md", content), ("second.md", "# Second")]);
+
+        let should_be = vec![
+            (String::from("missing.md"), 3, LinkProblem::UnknownChapter),
+            (
+                String::from("second.md#nope"),
+                4,
+                LinkProblem::MissingAnchor,
+            ),
+            (String::from("#nope"), 4, LinkProblem::MissingAnchor),
+            (String::from("images/nope.png"), 5, LinkProblem::MissingFile),
+            (String::from("missing.md"), 6, LinkProblem::UnknownChapter),
+        ];
+        assert_eq!(got, should_be);
+    }
+
+    #[test]
+    fn lines_are_mapped_back_to_the_chapter_source() {
+        let temp = TempFileBuilder::new().prefix("check").tempdir().unwrap();
+        let book_with = |content: &str| {
+            let mut book = Book::new();
+            book.push_item(Chapter::new(
+                "Intro",
+                content.to_string(),
+                "intro.md",
+                Vec::new(),
+            ));
+            book
+        };
+        let source = book_with("# Intro\n\n{{#include listing.md}}\n\n[missing](missing.md)\n");
+        let preprocessed = book_with("# Intro\n\nText\n[nope](nope.md)\n\n[missing](missing.md)\n");
+
+        let mut broken = check_links(&preprocessed, temp.path());
+        map_lines_to_source(&mut broken, &source);
+
+        let got: Vec<_> = broken
+            .into_iter()
+            .map(|broken| (broken.link, broken.line, broken.after_preprocessing))
+            .collect();
+        let should_be = vec![
+            (String::from("nope.md"), 4, true),
+            (String::from("missing.md"), 5, false),
+        ];
+        assert_eq!(got, should_be);
+    }
+
+    #[test]
+    fn duplicate_headers_get_numbered_ids() {
+        let ids = header_ids_in("# Foo\n\n## Foo\n\n### *Bar* baz");
+
+        let should_be: HashSet<String> = vec!["foo", "foo-1", "bar-baz"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        assert_eq!(ids, should_be);
+    }
+}
@@ -0,0 +1,382 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fs;
+use std::mem;
+use std::path::{Path, PathBuf};
+
+use super::book::parse_front_matter;
+use super::{Book, BookItem, Chapter, MDBook};
+use crate::errors::*;
+use crate::preprocess::links;
+use crate::renderer::Changes;
+use crate::utils;
+
+/// Builds a book and keeps enough state around to rebuild it cheaply when
+/// some of its files change. This is what `mdbook watch` and `mdbook serve`
+/// use.
+///
+/// Only the chapters which changed are preprocessed and rendered again, and
+/// renderers are told what changed so they can update their previous output
+/// (see [`Renderer::render_changes()`]). Changes to `book.toml` or
+/// `SUMMARY.md` cause the book to be loaded from scratch and fully rebuilt.
+///
+/// [`Renderer::render_changes()`]: ../renderer/trait.Renderer.html#method.render_changes
+pub struct IncrementalBuild {
+    book: MDBook,
+    load: Box<dyn Fn() -> Result<MDBook>>,
+    /// Each renderer's copy of the book after preprocessing, in the same
+    /// order as `book.renderers`. This is empty when the next rebuild needs to
+    /// start from scratch.
+    preprocessed: Vec<Book>,
+}
+
+impl IncrementalBuild {
+    /// Load the book with `load` and fully build it. The same closure is used
+    /// whenever the book needs to be loaded again, so any tweaks to the
+    /// configuration should be made inside it.
+    ///
+    /// Only an error loading the book is returned. If it can't be built, the
+    /// error is logged and the first rebuild builds the whole book again, so
+    /// that watching it can carry on until it's fixed.
+    pub fn new<F>(load: F) -> Result<IncrementalBuild>
+    where
+        F: Fn() -> Result<MDBook> + 'static,
+    {
+        let mut build = IncrementalBuild {
+            book: load()?,
+            load: Box::new(load),
+            preprocessed: Vec::new(),
+        };
+        if let Err(e) = build.build_all() {
+            error!("Unable to build the book");
+            utils::log_backtrace(&e);
+        }
+
+        Ok(build)
+    }
+
+    /// The book as it was last built.
+    pub fn book(&self) -> &MDBook {
+        &self.book
+    }
+
+    /// Bring the build up to date after the files at `changed_paths` were
+    /// created, modified or removed.
+    ///
+    /// If this fails, the next rebuild will build the whole book again.
+    pub fn rebuild(&mut self, changed_paths: &[PathBuf]) -> Result<()> {
+        let root = self.book.root.clone();
+        let src_dir = self.book.source_dir();
+        let theme_dir = self.book.theme_dir();
+
+        // Translations fall back to the default language's chapters, so any
+        // change can affect every language
+        if self.book.config.book.multilingual {
+            info!("Rebuilding every language of the book");
+            self.book = (self.load)()?;
+            return self.build_all();
+        }
+
+        let needs_reload = changed_paths.iter().any(|path| {
+            *path == root.join("book.toml")
+                || *path == src_dir.join("SUMMARY.md")
+                || !(path.starts_with(&src_dir) || path.starts_with(&theme_dir))
+        });
+        if needs_reload {
+            info!("The book's structure may have changed, rebuilding everything");
+            self.book = (self.load)()?;
+            return self.build_all();
+        }
+
+        // The last build failed, so start again from what is on disk
+        let preprocessed = mem::replace(&mut self.preprocessed, Vec::new());
+        if preprocessed.len() != self.book.renderers.len() {
+            self.book = (self.load)()?;
+            return self.build_all();
+        }
+
+        let mut changes = Changes::default();
+        let mut changed_files = Vec::new();
+        for path in changed_paths {
+            if path.starts_with(&theme_dir) {
+                changes.theme = true;
+            } else if let Ok(relative) = path.strip_prefix(&src_dir) {
+                changed_files.push(relative.to_path_buf());
+            }
+        }
+
+        let dirty = match self.reload_chapters(&src_dir, &changed_files) {
+            Some(dirty) => dirty,
+            None => {
+                info!("A chapter could not be reloaded, rebuilding everything");
+                self.book = (self.load)()?;
+                return self.build_all();
+            }
+        };
+        let chapter_paths: HashSet<&Path> = dirty.chapters.iter().filter_map(path_of).collect();
+        changes.files = changed_files
+            .into_iter()
+            .filter(|path| !chapter_paths.contains(path.as_path()))
+            .collect();
+
+        info!("Rebuilding {} changed chapter(s)", dirty.positions.len());
+
+        let config = self.book.language_config(None);
+        let mut updated = Vec::with_capacity(preprocessed.len());
+        for (renderer, mut book) in self.book.renderers.iter().zip(preprocessed) {
+            // Some preprocessors (e.g. cross-references) need the whole book
+            if !self.book.can_preprocess_changes(&config, renderer.name()) {
+                info!(
+                    "A preprocessor of the {} backend needs the whole book, rebuilding it",
+                    renderer.name()
+                );
+                updated.push(self.book.execute_build_process(&**renderer)?);
+                continue;
+            }
+
+            let preprocessed =
+                self.book
+                    .preprocess_changes(&config, dirty.as_book(), renderer.name());
+            let partial = match preprocessed {
+                Ok(partial) => partial,
+                Err(e) => {
+                    warn!(
+                        "Unable to preprocess the changed chapters on their own: {}",
+                        e
+                    );
+                    info!("Rebuilding everything");
+                    return self.build_all();
+                }
+            };
+            let fresh = chapters_of(partial);
+
+            if fresh.len() != dirty.positions.len()
+                || !replace_chapters(&mut book, &dirty.positions, &fresh)
+            {
+                info!("A preprocessor changed the book's structure, rebuilding everything");
+                return self.build_all();
+            }
+
+            let mut changes = changes.clone();
+            changes.chapters = fresh
+                .iter()
+                .filter_map(path_of)
+                .map(Path::to_path_buf)
+                .collect();
+
+            info!("Running the {} backend", renderer.name());
+            renderer
+                .render_changes(
+                    &self.book.render_context(&config, &book, &**renderer),
+                    &changes,
+                )
+                .chain_err(|| "Rendering failed")?;
+
+            updated.push(book);
+        }
+
+        self.preprocessed = updated;
+        Ok(())
+    }
+
+    fn build_all(&mut self) -> Result<()> {
+        info!("Book building has started");
+
+        let mut preprocessed = Vec::with_capacity(self.book.renderers.len());
+        for renderer in &self.book.renderers {
+            preprocessed.push(self.book.execute_build_process(&**renderer)?);
+        }
+
+        self.preprocessed = preprocessed;
+        Ok(())
+    }
+
+    /// Read the chapters affected by changes to `changed_files` back in from
+    /// disk. That is the changed chapters themselves, plus any chapter which
+    /// includes a changed file, directly or through another include.
+    ///
+    /// Returns `None` if one of them couldn't be read.
+    fn reload_chapters(
+        &mut self,
+        src_dir: &Path,
+        changed_files: &[PathBuf],
+    ) -> Option<DirtyChapters> {
+        let changed: HashSet<PathBuf> = changed_files
+            .iter()
+            .map(|path| normalize(&src_dir.join(path)))
+            .collect();
+
+        let front_matter = self.book.config.build.front_matter;
+        let mut dirty = DirtyChapters::default();
+        let mut unreadable = false;
+        let mut position = 0;
+
+        self.book.book.for_each_mut(|item| {
+            if let BookItem::Chapter(ref mut ch) = *item {
+                let is_dirty = match ch.path {
+                    Some(ref path) => {
+                        let path = src_dir.join(path);
+                        let dir = path.parent().expect("Chapters are always inside a book");
+                        changed.contains(&normalize(&path))
+                            || links::included_files(&ch.content, dir)
+                                .iter()
+                                .any(|file| changed.contains(&normalize(file)))
+                    }
+                    None => false,
+                };
+
+                if is_dirty {
+                    let path = src_dir.join(ch.path.as_ref().expect("Checked above"));
+                    let loaded =
+                        fs::read_to_string(&path)
+                            .map_err(Error::from)
+                            .and_then(|content| {
+                                if !front_matter {
+                                    return Ok((BTreeMap::new(), content));
+                                }
+                                let (meta, body) = parse_front_matter(&content)?;
+                                Ok((meta, body.to_string()))
+                            });
+                    match loaded {
+                        Ok((meta, content)) => {
+                            ch.meta = meta;
+                            ch.content = content;
+                        }
+                        Err(e) => {
+                            debug!("Unable to read {} ({})", path.display(), e);
+                            unreadable = true;
+                        }
+                    }
+
+                    let mut copy = ch.clone();
+                    copy.sub_items.clear();
+                    dirty.chapters.push(copy);
+                    dirty.positions.push(position);
+                }
+
+                position += 1;
+            }
+        });
+
+        if unreadable {
+            None
+        } else {
+            Some(dirty)
+        }
+    }
+}
+
+/// The chapters which need to be preprocessed and rendered again.
+#[derive(Debug, Default)]
+struct DirtyChapters {
+    /// Copies of the chapters, without their sub-chapters.
+    chapters: Vec<Chapter>,
+    /// Where each chapter is in the book, counting chapters in the order
+    /// `Book::for_each_mut()` visits them.
+    positions: Vec<usize>,
+}
+
+impl DirtyChapters {
+    /// A book containing just the dirty chapters, to be run through the
+    /// preprocessors.
+    fn as_book(&self) -> Book {
+        let mut book = Book::new();
+        for ch in &self.chapters {
+            book.push_item(ch.clone());
+        }
+        book
+    }
+}
+
+/// The canonical form of `path`, so that it can be compared with others which
+/// lead to the same file. Files which no longer exist are left as they are.
+fn normalize(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
+
+fn path_of(ch: &Chapter) -> Option<&Path> {
+    ch.path.as_ref().map(PathBuf::as_path)
+}
+
+fn chapters_of(mut book: Book) -> Vec<Chapter> {
+    let mut chapters = Vec::new();
+    book.for_each_mut(|item| {
+        if let BookItem::Chapter(ref ch) = *item {
+            chapters.push(ch.clone());
+        }
+    });
+    chapters
+}
+
+/// Swap the chapters at `positions` for freshly preprocessed versions, keeping
+/// their sub-chapters. Returns `false` if the book doesn't have a chapter at
+/// one of the positions.
+fn replace_chapters(book: &mut Book, positions: &[usize], fresh: &[Chapter]) -> bool {
+    let mut position = 0;
+    let mut replaced = 0;
+
+    book.for_each_mut(|item| {
+        if let BookItem::Chapter(ref mut ch) = *item {
+            if positions.get(replaced) == Some(&position) {
+                let sub_items = mem::replace(&mut ch.sub_items, Vec::new());
+                *ch = fresh[replaced].clone();
+                ch.sub_items = sub_items;
+                replaced += 1;
+            }
+
+            position += 1;
+        }
+    });
+
+    replaced == positions.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chapter(name: &str, sub_items: Vec<BookItem>) -> BookItem {
+        let mut ch = Chapter::new(
+            name,
+            format!("# {}", name),
+            format!("{}.md", name),
+            Vec::new(),
+        );
+        ch.sub_items = sub_items;
+        BookItem::Chapter(ch)
+    }
+
+    #[test]
+    fn replacing_chapters_keeps_their_sub_chapters() {
+        let mut book = Book::new();
+        book.push_item(chapter("first", vec![chapter("nested", Vec::new())]));
+        book.push_item(BookItem::Separator);
+        book.push_item(chapter("second", Vec::new()));
+
+        // `for_each_mut()` visits "nested" before "first"
+        let fresh = vec![
+            Chapter::new("First", String::from("new"), "first.md", Vec::new()),
+            Chapter::new("Second", String::from("newer"), "second.md", Vec::new()),
+        ];
+        assert!(replace_chapters(&mut book, &[1, 2], &fresh));
+
+        let got: Vec<(String, String, usize)> = chapters_of(book)
+            .into_iter()
+            .map(|ch| (ch.name, ch.content, ch.sub_items.len()))
+            .collect();
+        let should_be = vec![
+            (String::from("nested"), String::from("# nested"), 0),
+            (String::from("First"), String::from("new"), 1),
+            (String::from("Second"), String::from("newer"), 0),
+        ];
+        assert_eq!(got, should_be);
+    }
+
+    #[test]
+    fn replacing_a_missing_chapter_fails() {
+        let mut book = Book::new();
+        book.push_item(chapter("first", Vec::new()));
+
+        let fresh = vec![Chapter::new("Other", String::new(), "other.md", Vec::new())];
+        assert!(!replace_chapters(&mut book, &[3], &fresh));
+    }
+}
@@ -142,6 +142,9 @@ impl BookBuilder {
         let mut variables_css = File::create(cssdir.join("variables.css"))?;
         variables_css.write_all(theme::VARIABLES_CSS)?;
 
+        let mut epub_css = File::create(cssdir.join("epub.css"))?;
+        epub_css.write_all(theme::EPUB_CSS)?;
+
         let mut favicon = File::create(themedir.join("favicon.png"))?;
         favicon.write_all(theme::FAVICON)?;
 
@@ -163,6 +166,7 @@ impl BookBuilder {
         let mut f = File::create(self.root.join(".gitignore"))?;
 
         writeln!(f, "{}", self.config.build.build_dir.display())?;
+        writeln!(f, ".mdbook-cache")?;
 
         Ok(())
     }
@@ -6,25 +6,37 @@
 //! [1]: ../index.html
 
 mod book;
+mod check;
+mod incremental;
 mod init;
 mod summary;
+mod translation;
 
 pub use self::book::{load_book, Book, BookItem, BookItems, Chapter};
+pub use self::check::{BrokenLink, LinkProblem};
+pub use self::incremental::IncrementalBuild;
 pub use self::init::BookBuilder;
 pub use self::summary::{parse_summary, Link, SectionNumber, Summary, SummaryItem};
+pub use self::translation::Translation;
 
+use rayon::prelude::*;
+use std::collections::HashMap;
 use std::io::Write;
-use std::path::PathBuf;
-use std::process::Command;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
 use std::string::ToString;
 use tempfile::Builder as TempFileBuilder;
+use toml::value::Table;
 use toml::Value;
 
 use crate::errors::*;
 use crate::preprocess::{
-    CmdPreprocessor, IndexPreprocessor, LinkPreprocessor, Preprocessor, PreprocessorContext,
+    gettext, CmdPreprocessor, CrossRefPreprocessor, GettextPreprocessor, IndexPreprocessor,
+    LinkPreprocessor, Preprocessor, PreprocessorCache, PreprocessorContext,
+};
+use crate::renderer::{
+    CmdRenderer, EpubRenderer, HtmlHandlebars, PrintRenderer, RenderContext, Renderer,
 };
-use crate::renderer::{CmdRenderer, HtmlHandlebars, RenderContext, Renderer};
 use crate::utils;
 
 use crate::config::Config;
@@ -35,8 +47,11 @@ pub struct MDBook {
     pub root: PathBuf,
     /// The configuration used to tweak now a book is built.
     pub config: Config,
-    /// A representation of the book's contents in memory.
+    /// A representation of the book's contents in memory. For a multilingual
+    /// book, this is the default language.
     pub book: Book,
+    /// The book's other languages, if it is multilingual.
+    pub translations: Vec<Translation>,
     renderers: Vec<Box<dyn Renderer>>,
 
     /// List of pre-processors to be run on the book
@@ -82,7 +97,8 @@ impl MDBook {
         let root = book_root.into();
 
         let src_dir = root.join(&config.book.src);
-        let book = book::load_book(&src_dir, &config.build)?;
+        let book = book::load_book(default_src_dir(&src_dir, &config)?, &config.build)?;
+        let translations = translation::load_translations(&src_dir, &config)?;
 
         let renderers = determine_renderers(&config);
         let preprocessors = determine_preprocessors(&config)?;
@@ -91,6 +107,7 @@ impl MDBook {
             root,
             config,
             book,
+            translations,
             renderers,
             preprocessors,
         })
@@ -105,7 +122,12 @@ impl MDBook {
         let root = book_root.into();
 
         let src_dir = root.join(&config.book.src);
-        let book = book::load_book_from_disk(&summary, &src_dir)?;
+        let book = book::load_book_from_disk(
+            &summary,
+            default_src_dir(&src_dir, &config)?,
+            &config.build,
+        )?;
+        let translations = translation::load_translations(&src_dir, &config)?;
 
         let renderers = determine_renderers(&config);
         let preprocessors = determine_preprocessors(&config)?;
@@ -114,6 +136,7 @@ impl MDBook {
             root,
             config,
             book,
+            translations,
             renderers,
             preprocessors,
         })
@@ -133,6 +156,7 @@ impl MDBook {
     ///     match *item {
     ///         BookItem::Chapter(ref chapter) => {},
     ///         BookItem::Separator => {},
+    ///         BookItem::PartTitle(ref title) => {},
     ///     }
     /// }
     ///
@@ -181,15 +205,11 @@ impl MDBook {
         Ok(())
     }
 
-    /// Run the entire build process for a particular `Renderer`.
-    fn execute_build_process(&self, renderer: &dyn Renderer) -> Result<()> {
-        let mut preprocessed_book = self.book.clone();
-        let preprocess_ctx = PreprocessorContext::new(
-            self.root.clone(),
-            self.config.clone(),
-            renderer.name().to_string(),
-        );
-
+    /// Run the entire build process for a particular `Renderer`, returning
+    /// the book as it was after preprocessing. Each translation of a
+    /// multilingual book is built too, but only the default language is
+    /// returned.
+    fn execute_build_process(&self, renderer: &dyn Renderer) -> Result<Book> {
         let name = renderer.name();
         let build_dir = self.build_dir_for(name);
         if build_dir.exists() {
@@ -203,33 +223,116 @@ impl MDBook {
                 .chain_err(|| "Unable to clear output directory")?;
         }
 
+        let config = self.language_config(None);
+        let preprocessed_book = self.preprocess(&config, self.book.clone(), name)?;
+
+        info!("Running the {} backend", renderer.name());
+        self.render(&config, &preprocessed_book, renderer)?;
+
+        for translation in &self.translations {
+            let config = self.language_config(Some(&translation.language));
+            let preprocessed = self.preprocess(&config, translation.book.clone(), name)?;
+
+            info!(
+                "Running the {} backend for the {} translation",
+                renderer.name(),
+                translation.language
+            );
+            self.render(&config, &preprocessed, renderer)?;
+        }
+
+        Ok(preprocessed_book)
+    }
+
+    /// The configuration to preprocess and render one of the book's languages
+    /// with, where `None` is the default language. A book which isn't
+    /// multilingual only has the one configuration.
+    fn language_config(&self, language: Option<&str>) -> Config {
+        if !self.config.book.multilingual {
+            return self.config.clone();
+        }
+
+        let default = self
+            .config
+            .default_language()
+            .expect("Checked when the book was loaded");
+        translation::config_for_language(&self.config, language.unwrap_or(&default), &default)
+    }
+
+    /// Run a book through every preprocessor which should run for the
+    /// renderer called `renderer`.
+    fn preprocess(&self, config: &Config, book: Book, renderer: &str) -> Result<Book> {
+        self.preprocess_book(config, book, renderer, false)
+    }
+
+    /// Whether every preprocessor which runs for `renderer` can be given only
+    /// the chapters which changed since the last build.
+    fn can_preprocess_changes(&self, config: &Config, renderer: &str) -> bool {
+        self.preprocessors
+            .iter()
+            .filter(|p| preprocessor_should_run(&***p, renderer, config))
+            .all(|p| p.supports_partial_books())
+    }
+
+    /// Run only the chapters which changed since the last build through the
+    /// preprocessors, which must all support it.
+    fn preprocess_changes(&self, config: &Config, chapters: Book, renderer: &str) -> Result<Book> {
+        self.preprocess_book(config, chapters, renderer, true)
+    }
+
+    fn preprocess_book(
+        &self,
+        config: &Config,
+        book: Book,
+        renderer: &str,
+        partial: bool,
+    ) -> Result<Book> {
+        let mut preprocessed_book = book;
+        let mut preprocess_ctx =
+            PreprocessorContext::new(self.root.clone(), config.clone(), renderer.to_string())
+                .with_cache(PreprocessorCache::new(self.cache_dir()));
+        preprocess_ctx.partial = partial;
+
         for preprocessor in &self.preprocessors {
-            if preprocessor_should_run(&**preprocessor, renderer, &self.config) {
-                debug!("Running the {} preprocessor.", preprocessor.name());
-                preprocessed_book = preprocessor.run(&preprocess_ctx, preprocessed_book)?;
+            if preprocessor_should_run(&**preprocessor, renderer, config) {
+                preprocessed_book =
+                    run_preprocessor(&**preprocessor, &preprocess_ctx, preprocessed_book)?;
             }
         }
 
-        info!("Running the {} backend", renderer.name());
-        self.render(&preprocessed_book, renderer)?;
+        Ok(preprocessed_book)
+    }
 
-        Ok(())
+    fn render(
+        &self,
+        config: &Config,
+        preprocessed_book: &Book,
+        renderer: &dyn Renderer,
+    ) -> Result<()> {
+        renderer
+            .render(&self.render_context(config, preprocessed_book, renderer))
+            .chain_err(|| "Rendering failed")
     }
 
-    fn render(&self, preprocessed_book: &Book, renderer: &dyn Renderer) -> Result<()> {
-        let name = renderer.name();
-        let build_dir = self.build_dir_for(name);
+    /// Each language of a multilingual book is rendered to its own
+    /// subdirectory of the renderer's build directory.
+    fn render_context(
+        &self,
+        config: &Config,
+        preprocessed_book: &Book,
+        renderer: &dyn Renderer,
+    ) -> RenderContext {
+        let mut destination = self.build_dir_for(renderer.name());
+        if let (true, Some(language)) = (config.book.multilingual, config.book.language.as_ref()) {
+            destination.push(language);
+        }
 
-        let render_context = RenderContext::new(
+        RenderContext::new(
             self.root.clone(),
             preprocessed_book.clone(),
-            self.config.clone(),
-            build_dir,
-        );
-
-        renderer
-            .render(&render_context)
-            .chain_err(|| "Rendering failed")
+            config.clone(),
+            destination,
+        )
     }
 
     /// You can change the default renderer to another one by using this method.
@@ -248,6 +351,17 @@ impl MDBook {
 
     /// Run `rustdoc` tests on the book, linking against the provided libraries.
     pub fn test(&mut self, library_paths: Vec<&str>) -> Result<()> {
+        self.test_chapter(library_paths, None)
+    }
+
+    /// Run `rustdoc` tests on the book, linking against the provided
+    /// libraries. If `chapter` is given, only the chapter with that name or
+    /// source path is tested.
+    ///
+    /// The book is run through its preprocessors for the `test` renderer
+    /// first. Chapters are tested in parallel, and every failure is included
+    /// in the error.
+    pub fn test_chapter(&mut self, library_paths: Vec<&str>, chapter: Option<&str>) -> Result<()> {
         let library_args: Vec<&str> = (0..library_paths.len())
             .map(|_| "-L")
             .zip(library_paths.into_iter())
@@ -256,40 +370,61 @@ impl MDBook {
 
         let temp_dir = TempFileBuilder::new().prefix("mdbook-").tempdir()?;
 
-        // FIXME: Is "test" the proper renderer name to use here?
-        let preprocess_context =
-            PreprocessorContext::new(self.root.clone(), self.config.clone(), "test".to_string());
-
-        let book = LinkPreprocessor::new().run(&preprocess_context, self.book.clone())?;
-        // Index Preprocessor is disabled so that chapter paths continue to point to the
-        // actual markdown files.
-
-        for item in book.iter() {
-            if let BookItem::Chapter(ref ch) = *item {
-                if !ch.path.as_os_str().is_empty() {
-                    let path = self.source_dir().join(&ch.path);
-                    info!("Testing file: {:?}", path);
-
-                    // write preprocessed file to tempdir
-                    let path = temp_dir.path().join(&ch.path);
-                    let mut tmpf = utils::fs::create_file(&path)?;
-                    tmpf.write_all(ch.content.as_bytes())?;
-
-                    let output = Command::new("rustdoc")
-                        .arg(&path)
-                        .arg("--test")
-                        .args(&library_args)
-                        .output()?;
-
-                    if !output.status.success() {
-                        bail!(ErrorKind::Subprocess(
-                            "Rustdoc returned an error".to_string(),
-                            output
-                        ));
-                    }
-                }
+        let config = self.language_config(None);
+        let book = self.preprocess(&config, self.book.clone(), "test")?;
+
+        let chapters: Vec<&Chapter> = book
+            .iter()
+            .filter_map(|item| match *item {
+                BookItem::Chapter(ref ch) => Some(ch),
+                _ => None,
+            })
+            .filter(|ch| match ch.path {
+                Some(ref path) => !path.as_os_str().is_empty(),
+                None => false,
+            })
+            .filter(|ch| match chapter {
+                Some(wanted) => ch.name == wanted || ch.path == Some(PathBuf::from(wanted)),
+                None => true,
+            })
+            .collect();
+
+        if let Some(wanted) = chapter {
+            ensure!(
+                !chapters.is_empty(),
+                "The book doesn't have a chapter called \"{}\"",
+                wanted
+            );
+        }
+
+        let outputs = chapters
+            .par_iter()
+            .map(|ch| run_rustdoc(ch, temp_dir.path(), &library_args))
+            .collect::<Vec<_>>();
+
+        let mut failures = Vec::new();
+        for (ch, output) in chapters.iter().zip(outputs) {
+            let output = output.chain_err(|| format!("Unable to test \"{}\"", ch.name))?;
+            if !output.status.success() {
+                failures.push(format!(
+                    "{} ({}):\n{}{}",
+                    ch.name,
+                    ch.path.as_ref().expect("Checked above").display(),
+                    String::from_utf8_lossy(&output.stdout),
+                    String::from_utf8_lossy(&output.stderr)
+                ));
             }
         }
+
+        if !failures.is_empty() {
+            bail!(
+                "Rustdoc found problems in {} of {} chapter(s)\n\n{}",
+                failures.len(),
+                chapters.len(),
+                failures.join("\n")
+            );
+        }
+
         Ok(())
     }
 
@@ -327,6 +462,43 @@ impl MDBook {
         }
     }
 
+    /// Where preprocessors keep their results between builds, the hidden
+    /// `.mdbook-cache` directory of the book's root. It is kept out of the
+    /// build directory so it isn't deployed with the book.
+    pub fn cache_dir(&self) -> PathBuf {
+        self.root.join(".mdbook-cache")
+    }
+
+    /// Look for broken links and missing images in the book, as it would be
+    /// seen by the HTML renderer after preprocessing.
+    ///
+    /// Returns every problem found, so an empty list means all links are ok.
+    pub fn check(&self) -> Result<Vec<BrokenLink>> {
+        let config = self.language_config(None);
+        let book = self.preprocess(&config, self.book.clone(), "html")?;
+
+        let mut broken = check::check_links(&book, &self.root.join(&config.book.src));
+        check::map_lines_to_source(&mut broken, &self.book);
+
+        Ok(broken)
+    }
+
+    /// Extract every translatable message in the book into a gettext template
+    /// (the contents of a `.pot` file), for translators to make `.po` files
+    /// from. See [`GettextPreprocessor`].
+    ///
+    /// [`GettextPreprocessor`]: ../preprocess/struct.GettextPreprocessor.html
+    pub fn translation_template(&self) -> String {
+        let config = self.language_config(None);
+        gettext::template(&self.book, &config, &config.book.src)
+    }
+
+    /// Get the directory containing the book's gettext translations (`.po`
+    /// files).
+    pub fn po_dir(&self) -> PathBuf {
+        gettext::po_dir(&self.root, &self.config)
+    }
+
     /// Get the directory containing this book's source files.
     pub fn source_dir(&self) -> PathBuf {
         self.root.join(&self.config.book.src)
@@ -341,16 +513,30 @@ impl MDBook {
     }
 }
 
+/// Where the book's chapters are read from. A multilingual book has a
+/// directory for each language, and this is the default language's.
+fn default_src_dir(src_dir: &Path, config: &Config) -> Result<PathBuf> {
+    if config.book.multilingual {
+        Ok(src_dir.join(translation::default_language(config)?))
+    } else {
+        Ok(src_dir.to_path_buf())
+    }
+}
+
 /// Look at the `Config` and try to figure out what renderers to use.
 fn determine_renderers(config: &Config) -> Vec<Box<dyn Renderer>> {
     let mut renderers = Vec::new();
 
     if let Some(output_table) = config.get("output").and_then(Value::as_table) {
         renderers.extend(output_table.iter().map(|(key, table)| {
-            if key == "html" {
-                Box::new(HtmlHandlebars::new()) as Box<dyn Renderer>
-            } else {
-                interpret_custom_renderer(key, table)
+            // A `command` means a third-party renderer which predates the
+            // built-in one of the same name, such as `mdbook-epub`
+            let built_in = table.get("command").is_none();
+            match key.as_str() {
+                "html" => Box::new(HtmlHandlebars::new()) as Box<dyn Renderer>,
+                "epub" if built_in => Box::new(EpubRenderer::new()),
+                "print" if built_in => Box::new(PrintRenderer::new()),
+                _ => interpret_custom_renderer(key, table),
             }
         }));
     }
@@ -385,28 +571,160 @@ fn determine_preprocessors(config: &Config) -> Result<Vec<Box<dyn Preprocessor>>
 
     if let Some(preprocessor_table) = config.get("preprocessor").and_then(Value::as_table) {
         for key in preprocessor_table.keys() {
+            // The default preprocessors can have a table too
+            if preprocessors.iter().any(|p| p.name() == key) {
+                continue;
+            }
+
             match key.as_ref() {
                 "links" => preprocessors.push(Box::new(LinkPreprocessor::new())),
                 "index" => preprocessors.push(Box::new(IndexPreprocessor::new())),
+                "xref" => preprocessors.push(Box::new(CrossRefPreprocessor::new())),
+                // Messages are looked up by their original markdown, so
+                // translating has to come before anything else changes it
+                "gettext" => preprocessors.insert(0, Box::new(GettextPreprocessor::new())),
                 name => preprocessors.push(interpret_custom_preprocessor(
                     name,
                     &preprocessor_table[name],
                 )),
             }
         }
+
+        return sort_preprocessors(preprocessors, preprocessor_table);
     }
 
     Ok(preprocessors)
 }
 
+/// Sort the preprocessors so each one runs after the preprocessors in its
+/// `after` key, and before those in its `before` key. Apart from that they
+/// keep their order, with preprocessors only moved forward as far as they
+/// need to be.
+fn sort_preprocessors(
+    preprocessors: Vec<Box<dyn Preprocessor>>,
+    preprocessor_table: &Table,
+) -> Result<Vec<Box<dyn Preprocessor>>> {
+    let index: HashMap<&str, usize> = preprocessors
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (p.name(), i))
+        .collect();
+
+    // The preprocessors which have to run before each one
+    let mut predecessors = vec![Vec::new(); preprocessors.len()];
+    for (name, table) in preprocessor_table {
+        let i = index[name.as_str()];
+        for &(key, runs_before) in &[("before", true), ("after", false)] {
+            for other in preprocessor_names(table, name, key)? {
+                let j = match index.get(other) {
+                    Some(&j) => j,
+                    None => bail!(
+                        "The \"{}\" preprocessor has to run {} \"{}\", which isn't a \
+                         preprocessor of this book",
+                        name,
+                        key,
+                        other
+                    ),
+                };
+                if runs_before {
+                    predecessors[j].push(i);
+                } else {
+                    predecessors[i].push(j);
+                }
+            }
+        }
+    }
+
+    let names: Vec<&str> = preprocessors.iter().map(|p| p.name()).collect();
+    let mut order = Vec::with_capacity(preprocessors.len());
+    let mut path = Vec::new();
+    for i in 0..preprocessors.len() {
+        visit_preprocessor(i, &predecessors, &names, &mut path, &mut order)?;
+    }
+
+    let mut preprocessors: Vec<_> = preprocessors.into_iter().map(Some).collect();
+    Ok(order
+        .into_iter()
+        .map(|i| {
+            preprocessors[i]
+                .take()
+                .expect("Each preprocessor is taken once")
+        })
+        .collect())
+}
+
+/// Add preprocessor `i` to the `order`, after the preprocessors it has to run
+/// after. The `path` is how we got to it, to find cycles.
+fn visit_preprocessor(
+    i: usize,
+    predecessors: &[Vec<usize>],
+    names: &[&str],
+    path: &mut Vec<usize>,
+    order: &mut Vec<usize>,
+) -> Result<()> {
+    if order.contains(&i) {
+        return Ok(());
+    }
+    if let Some(start) = path.iter().position(|&j| j == i) {
+        // Each preprocessor on the path has to run after the next one
+        let mut cycle: Vec<&str> = path[start..].iter().rev().map(|&j| names[j]).collect();
+        let first = cycle[0];
+        cycle.push(first);
+        bail!(
+            "The preprocessors can't be ordered, because they would have to run in a cycle: {}",
+            cycle.join(" -> ")
+        );
+    }
+
+    path.push(i);
+    for &j in &predecessors[i] {
+        visit_preprocessor(j, predecessors, names, path, order)?;
+    }
+    path.pop();
+
+    order.push(i);
+    Ok(())
+}
+
+/// The names listed in a preprocessor's `before` or `after` key.
+fn preprocessor_names<'a>(table: &'a Value, name: &str, key: &str) -> Result<Vec<&'a str>> {
+    let names = match table.get(key) {
+        Some(names) => names,
+        None => return Ok(Vec::new()),
+    };
+
+    names
+        .as_array()
+        .and_then(|names| names.iter().map(Value::as_str).collect())
+        .ok_or_else(|| {
+            format!(
+                "`preprocessor.{}.{}` should be a list of preprocessor names",
+                name, key
+            )
+            .into()
+        })
+}
+
 fn interpret_custom_preprocessor(key: &str, table: &Value) -> Box<CmdPreprocessor> {
     let command = table
         .get("command")
         .and_then(Value::as_str)
         .map(ToString::to_string)
         .unwrap_or_else(|| format!("mdbook-{}", key));
-
-    Box::new(CmdPreprocessor::new(key.to_string(), command.to_string()))
+    let capabilities = table
+        .get("capabilities")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let persistent = table
+        .get("persistent")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+
+    Box::new(
+        CmdPreprocessor::new(key.to_string(), command.to_string())
+            .with_capabilities(capabilities)
+            .with_persistent(persistent),
+    )
 }
 
 fn interpret_custom_renderer(key: &str, table: &Value) -> Box<CmdRenderer> {
@@ -418,8 +736,88 @@ fn interpret_custom_renderer(key: &str, table: &Value) -> Box<CmdRenderer> {
         .map(ToString::to_string);
 
     let command = table_dot_command.unwrap_or_else(|| format!("mdbook-{}", key));
+    let capabilities = table
+        .get("capabilities")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let persistent = table
+        .get("persistent")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+
+    Box::new(
+        CmdRenderer::new(key.to_string(), command.to_string())
+            .with_capabilities(capabilities)
+            .with_persistent(persistent),
+    )
+}
+
+/// Run a preprocessor over the book, unless it has `cache = true` and its
+/// output for the same book, config, renderer and language is in the cache.
+fn run_preprocessor(
+    preprocessor: &dyn Preprocessor,
+    ctx: &PreprocessorContext,
+    book: Book,
+) -> Result<Book> {
+    let name = preprocessor.name();
+    let table = ctx.config.get(&format!("preprocessor.{}", name));
+    let use_cache = table
+        .and_then(|table| table.get("cache"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+
+    // The output for a partial book would replace that for the whole book
+    let cache = match ctx.cache {
+        Some(ref cache) if use_cache && !ctx.partial => cache,
+        _ => {
+            debug!("Running the {} preprocessor.", name);
+            return preprocessor.run(ctx, book);
+        }
+    };
+
+    // The table holds the preprocessor's command, if it isn't the default.
+    // Only the latest output for each renderer and language is kept.
+    let key = PreprocessorCache::key(&(&book, table, &ctx.mdbook_version))?;
+    let slot = match ctx.config.book.language {
+        Some(ref language) => format!("{}/{}", ctx.renderer, language),
+        None => ctx.renderer.clone(),
+    };
+    if let Some(cached) = cache.get(name, &slot, &key) {
+        debug!("Using the cached output of the {} preprocessor.", name);
+        return Ok(cached);
+    }
+
+    debug!("Running the {} preprocessor.", name);
+    let preprocessed = preprocessor.run(ctx, book)?;
+    if let Err(e) = cache.put(name, &slot, &key, &preprocessed) {
+        warn!(
+            "Unable to cache the output of the {} preprocessor, {}",
+            name, e
+        );
+    }
 
-    Box::new(CmdRenderer::new(key.to_string(), command.to_string()))
+    Ok(preprocessed)
+}
+
+/// Write a chapter to `dir` and run its tests with `rustdoc`.
+fn run_rustdoc(chapter: &Chapter, dir: &Path, library_args: &[&str]) -> Result<Output> {
+    let chapter_path = chapter
+        .path
+        .as_ref()
+        .expect("Only chapters with files are tested");
+    info!("Testing file: {:?}", chapter_path);
+
+    // write preprocessed file to tempdir
+    let path = dir.join(chapter_path);
+    let mut tmpf = utils::fs::create_file(&path)?;
+    tmpf.write_all(chapter.content.as_bytes())?;
+
+    Command::new("rustdoc")
+        .arg(&path)
+        .arg("--test")
+        .args(library_args)
+        .output()
+        .chain_err(|| "Unable to run rustdoc")
 }
 
 /// Check whether we should run a particular `Preprocessor` in combination
@@ -430,16 +828,15 @@ fn interpret_custom_renderer(key: &str, table: &Value) -> Box<CmdRenderer> {
 /// default preprocessors always run if they support the renderer.
 fn preprocessor_should_run(
     preprocessor: &dyn Preprocessor,
-    renderer: &dyn Renderer,
+    renderer_name: &str,
     cfg: &Config,
 ) -> bool {
     // default preprocessors should be run by default (if supported)
     if cfg.build.use_default_preprocessors && is_default_preprocessor(preprocessor) {
-        return preprocessor.supports_renderer(renderer.name());
+        return preprocessor.supports_renderer(renderer_name);
     }
 
     let key = format!("preprocessor.{}.renderers", preprocessor.name());
-    let renderer_name = renderer.name();
 
     if let Some(Value::Array(ref explicit_renderers)) = cfg.get(&key) {
         return explicit_renderers
@@ -481,6 +878,28 @@ mod tests {
         assert_eq!(got[0].name(), "random");
     }
 
+    #[test]
+    fn the_epub_renderer_is_built_in() {
+        let mut cfg = Config::default();
+        cfg.set("output.epub", Table::new()).unwrap();
+
+        let got = determine_renderers(&cfg);
+
+        assert_eq!(got.len(), 1);
+        assert_eq!(got[0].name(), "epub");
+    }
+
+    #[test]
+    fn the_print_renderer_is_built_in() {
+        let mut cfg = Config::default();
+        cfg.set("output.print", Table::new()).unwrap();
+
+        let got = determine_renderers(&cfg);
+
+        assert_eq!(got.len(), 1);
+        assert_eq!(got[0].name(), "print");
+    }
+
     #[test]
     fn add_a_random_renderer_with_custom_command_to_the_config() {
         let mut cfg = Config::default();
@@ -520,6 +939,35 @@ mod tests {
         assert_eq!(got.len(), 0);
     }
 
+    #[test]
+    fn the_xref_preprocessor_is_built_in() {
+        let cfg_str = r#"
+        [preprocessor.xref]
+        "#;
+
+        let cfg = Config::from_str(cfg_str).unwrap();
+
+        let got = determine_preprocessors(&cfg).unwrap();
+        let names: Vec<&str> = got.iter().map(|p| p.name()).collect();
+
+        assert_eq!(names, vec!["links", "index", "xref"]);
+    }
+
+    #[test]
+    fn the_gettext_preprocessor_runs_first() {
+        let cfg_str = r#"
+        [preprocessor.xref]
+        [preprocessor.gettext]
+        "#;
+
+        let cfg = Config::from_str(cfg_str).unwrap();
+
+        let got = determine_preprocessors(&cfg).unwrap();
+        let names: Vec<&str> = got.iter().map(|p| p.name()).collect();
+
+        assert_eq!(names, vec!["gettext", "links", "index", "xref"]);
+    }
+
     #[test]
     fn can_determine_third_party_preprocessors() {
         let cfg_str = r#"
@@ -580,10 +1028,65 @@ mod tests {
         let html_renderer = HtmlHandlebars::default();
         let pre = LinkPreprocessor::new();
 
-        let should_run = preprocessor_should_run(&pre, &html_renderer, &cfg);
+        let should_run = preprocessor_should_run(&pre, html_renderer.name(), &cfg);
         assert!(should_run);
     }
 
+    fn preprocessor_names_for(cfg_str: &str) -> Result<Vec<String>> {
+        let cfg = Config::from_str(cfg_str).unwrap();
+        let got = determine_preprocessors(&cfg)?;
+        Ok(got.iter().map(|p| p.name().to_string()).collect())
+    }
+
+    #[test]
+    fn preprocessors_can_run_before_and_after_others() {
+        let cfg_str = r#"
+        [preprocessor.first]
+        before = ["links"]
+
+        [preprocessor.last]
+
+        [preprocessor.middle]
+        after = ["index"]
+        before = ["last"]
+
+        [preprocessor.links]
+        "#;
+
+        let got = preprocessor_names_for(cfg_str).unwrap();
+
+        assert_eq!(got, vec!["first", "links", "index", "middle", "last"]);
+    }
+
+    #[test]
+    fn preprocessors_must_be_ordered_consistently() {
+        let cycle = r#"
+        [preprocessor.a]
+        before = ["b"]
+
+        [preprocessor.b]
+        before = ["links"]
+
+        [preprocessor.links]
+        before = ["a"]
+        "#;
+        let got = preprocessor_names_for(cycle).unwrap_err().to_string();
+        assert!(got.contains("a -> b -> links -> a"), "{}", got);
+
+        let unknown = r#"
+        [preprocessor.a]
+        after = ["missing"]
+        "#;
+        let got = preprocessor_names_for(unknown).unwrap_err().to_string();
+        assert!(got.contains("\"missing\""), "{}", got);
+
+        let not_a_list = r#"
+        [preprocessor.a]
+        after = "links"
+        "#;
+        assert!(preprocessor_names_for(not_a_list).is_err());
+    }
+
     struct BoolPreprocessor(bool);
     impl Preprocessor for BoolPreprocessor {
         fn name(&self) -> &str {
@@ -605,11 +1108,28 @@ mod tests {
         let html = HtmlHandlebars::new();
 
         let should_be = true;
-        let got = preprocessor_should_run(&BoolPreprocessor(should_be), &html, &cfg);
+        let got = preprocessor_should_run(&BoolPreprocessor(should_be), html.name(), &cfg);
         assert_eq!(got, should_be);
 
         let should_be = false;
-        let got = preprocessor_should_run(&BoolPreprocessor(should_be), &html, &cfg);
+        let got = preprocessor_should_run(&BoolPreprocessor(should_be), html.name(), &cfg);
         assert_eq!(got, should_be);
     }
+
+    #[test]
+    fn changes_are_only_preprocessed_on_their_own_when_every_preprocessor_supports_it() {
+        let temp = TempFileBuilder::new().prefix("book").tempdir().unwrap();
+        let cfg = Config::default();
+        let mut md =
+            MDBook::load_with_config_and_summary(temp.path(), cfg.clone(), Summary::default())
+                .unwrap();
+        assert!(md.can_preprocess_changes(&cfg, "html"));
+
+        // Preprocessors which don't run for the renderer don't matter
+        md.with_preprocessor(BoolPreprocessor(false));
+        assert!(md.can_preprocess_changes(&cfg, "html"));
+
+        md.with_preprocessor(BoolPreprocessor(true));
+        assert!(!md.can_preprocess_changes(&cfg, "html"));
+    }
 }
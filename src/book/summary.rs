@@ -37,10 +37,29 @@ use std::path::{Path, PathBuf};
 /// You can either use - or * to indicate a numbered chapter, the parser doesn't
 /// care but you'll probably want to stay consistent.
 ///
+/// **Part Title:** A level 1 heading amongst the numbered chapters starts a new
+/// part of the book. The part title is shown as a header in the table of
+/// contents. Section numbers keep counting across parts rather than starting
+/// again from 1.
+///
+/// ```markdown
+/// # Part I: Basics
+///
+/// - [Title of the Chapter](relative/path/to/markdown.md)
+/// ```
+///
 /// **Suffix Chapter:** After the numbered chapters you can add a couple of
 /// non-numbered chapters. They are the same as prefix chapters but come after
 /// the numbered chapters instead of before.
 ///
+/// **Draft Chapter:** A chapter with an empty link location is a draft. It is
+/// listed in the table of contents but doesn't have a backing file, so no page
+/// is generated for it.
+///
+/// ```markdown
+/// - [Title of the future chapter]()
+/// ```
+///
 /// All other elements are unsupported and will be ignored at best or result in
 /// an error.
 pub fn parse_summary(summary: &str) -> Result<Summary> {
@@ -70,8 +89,8 @@ pub struct Link {
     /// The name of the chapter.
     pub name: String,
     /// The location of the chapter's source file, taking the book's `src`
-    /// directory as the root.
-    pub location: PathBuf,
+    /// directory as the root. Draft chapters don't have a location.
+    pub location: Option<PathBuf>,
     /// The section number, if this chapter is in the numbered section.
     pub number: Option<SectionNumber>,
     /// Any nested items this chapter may contain.
@@ -83,7 +102,7 @@ impl Link {
     pub fn new<S: Into<String>, P: AsRef<Path>>(name: S, location: P) -> Link {
         Link {
             name: name.into(),
-            location: location.as_ref().to_path_buf(),
+            location: Some(location.as_ref().to_path_buf()),
             number: None,
             nested_items: Vec::new(),
         }
@@ -94,20 +113,23 @@ impl Default for Link {
     fn default() -> Self {
         Link {
             name: String::new(),
-            location: PathBuf::new(),
+            location: Some(PathBuf::new()),
             number: None,
             nested_items: Vec::new(),
         }
     }
 }
 
-/// An item in `SUMMARY.md` which could be either a separator or a `Link`.
+/// An item in `SUMMARY.md` which could be either a separator, a part title or
+/// a `Link`.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SummaryItem {
     /// A link to a chapter.
     Link(Link),
     /// A separator (`---`).
     Separator,
+    /// A part title (`# Part I`), grouping the numbered chapters after it.
+    PartTitle(String),
 }
 
 impl SummaryItem {
@@ -139,12 +161,14 @@ impl From<Link> for SummaryItem {
 ///                     | EPSILON
 /// prefix_chapters   ::= item*
 /// suffix_chapters   ::= item*
-/// numbered_chapters ::= dotted_item+
+/// numbered_chapters ::= part+
+/// part              ::= part_title? dotted_item+
+/// part_title        ::= "# " TEXT
 /// dotted_item       ::= INDENT* DOT_POINT item
 /// item              ::= link
 ///                     | separator
 /// separator         ::= "---"
-/// link              ::= "[" TEXT "]" "(" TEXT ")"
+/// link              ::= "[" TEXT "]" "(" TEXT? ")"
 /// DOT_POINT         ::= "-"
 ///                     | "*"
 /// ```
@@ -154,6 +178,9 @@ impl From<Link> for SummaryItem {
 struct SummaryParser<'a> {
     src: &'a str,
     stream: pulldown_cmark::Parser<'a>,
+    /// An event which has been put back with `back()` and should be returned
+    /// by the next call to `next_event()`.
+    back: Option<Event<'a>>,
 }
 
 /// Reads `Events` from the provided stream until the corresponding
@@ -201,6 +228,7 @@ impl<'a> SummaryParser<'a> {
         SummaryParser {
             src: text,
             stream: pulldown_parser,
+            back: None,
         }
     }
 
@@ -219,18 +247,35 @@ impl<'a> SummaryParser<'a> {
 
     /// Parse the text the `SummaryParser` was created with.
     fn parse(mut self) -> Result<Summary> {
-        let title = self.parse_title();
+        let mut title = self.parse_title();
 
         let prefix_chapters = self
             .parse_affix(true)
             .chain_err(|| "There was an error parsing the prefix chapters")?;
-        let numbered_chapters = self
+        let mut numbered_chapters = self
             .parse_numbered()
             .chain_err(|| "There was an error parsing the numbered chapters")?;
         let suffix_chapters = self
             .parse_affix(false)
             .chain_err(|| "There was an error parsing the suffix chapters")?;
 
+        // When the numbered chapters are split into parts, a heading which
+        // they follow straight away is the first part's title
+        let starts_with_a_chapter = match numbered_chapters.first() {
+            Some(SummaryItem::Link(_)) => prefix_chapters.is_empty(),
+            _ => false,
+        };
+        let has_parts = numbered_chapters.iter().any(|item| match *item {
+            SummaryItem::PartTitle(_) => true,
+            _ => false,
+        });
+        if starts_with_a_chapter && has_parts {
+            if let Some(part_title) = title.take() {
+                debug!("Found a part title: {}", part_title);
+                numbered_chapters.insert(0, SummaryItem::PartTitle(part_title));
+            }
+        }
+
         Ok(Summary {
             title,
             prefix_chapters,
@@ -239,8 +284,7 @@ impl<'a> SummaryParser<'a> {
         })
     }
 
-    /// Parse the affix chapters. This expects the first event (start of
-    /// paragraph) to have already been consumed by the previous parser.
+    /// Parse the affix chapters.
     fn parse_affix(&mut self, is_prefix: bool) -> Result<Vec<SummaryItem>> {
         let mut items = Vec::new();
         debug!(
@@ -250,15 +294,27 @@ impl<'a> SummaryParser<'a> {
 
         loop {
             match self.next_event() {
-                Some(Event::Start(Tag::List(..))) => {
+                Some(ev @ Event::Start(Tag::List(..))) => {
                     if is_prefix {
                         // we've finished prefix chapters and are at the start
                         // of the numbered section.
+                        self.back(ev);
                         break;
                     } else {
                         bail!(self.parse_error("Suffix chapters cannot be followed by a list"));
                     }
                 }
+                Some(ev @ Event::Start(Tag::Header(1))) => {
+                    if is_prefix {
+                        // the numbered section starts with a part title
+                        self.back(ev);
+                        break;
+                    } else {
+                        bail!(
+                            self.parse_error("Suffix chapters cannot be followed by a part title")
+                        );
+                    }
+                }
                 Some(Event::Start(Tag::Link(_type, href, _title))) => {
                     let link = self.parse_link(href.to_string())?;
                     items.push(SummaryItem::Link(link));
@@ -276,46 +332,51 @@ impl<'a> SummaryParser<'a> {
         let link_content = collect_events!(self.stream, end Tag::Link(..));
         let name = stringify_events(link_content);
 
-        if href.is_empty() {
-            Err(self.parse_error("You can't have an empty link."))
+        let location = if href.is_empty() {
+            None
         } else {
-            Ok(Link {
-                name,
-                location: PathBuf::from(href.to_string()),
-                number: None,
-                nested_items: Vec::new(),
-            })
-        }
+            Some(PathBuf::from(href))
+        };
+
+        Ok(Link {
+            name,
+            location,
+            number: None,
+            nested_items: Vec::new(),
+        })
     }
 
-    /// Parse the numbered chapters. This assumes the opening list tag has
-    /// already been consumed by a previous parser.
+    /// Parse the numbered chapters, including any part titles separating
+    /// them.
     fn parse_numbered(&mut self) -> Result<Vec<SummaryItem>> {
         let mut items = Vec::new();
         let mut root_items = 0;
         let root_number = SectionNumber::default();
 
-        // we need to do this funny loop-match-if-let dance because a rule will
-        // close off any currently running list. Therefore we try to read the
-        // list items before the rule, then if we encounter a rule we'll add a
-        // separator and try to resume parsing numbered chapters if we start a
-        // list immediately afterwards.
-        //
-        // If you can think of a better way to do this then please make a PR :)
+        // Section numbers keep counting across separators and part titles, so
+        // every list after the first one is numbered from 1 by
+        // `parse_nested_numbered()` and we need to manually go back and update
+        // its root sections.
 
         loop {
-            let mut bunch_of_items = self.parse_nested_numbered(&root_number)?;
+            match self.next_event() {
+                Some(Event::Start(Tag::List(..))) => {
+                    let mut bunch_of_items = self.parse_nested_numbered(&root_number)?;
 
-            // if we've resumed after something like a rule the root sections
-            // will be numbered from 1. We need to manually go back and update
-            // them
-            update_section_numbers(&mut bunch_of_items, 0, root_items);
-            root_items += bunch_of_items.len() as u32;
-            items.extend(bunch_of_items);
+                    update_section_numbers(&mut bunch_of_items, 0, root_items);
+                    root_items += bunch_of_items.len() as u32;
+                    items.extend(bunch_of_items);
+                }
+                Some(Event::Start(Tag::Header(1))) => {
+                    let tags = collect_events!(self.stream, end Tag::Header(1));
+                    let title = stringify_events(tags);
+                    debug!("Found a part title: {}", title);
 
-            match self.next_event() {
-                Some(Event::Start(Tag::Paragraph)) => {
+                    items.push(SummaryItem::PartTitle(title));
+                }
+                Some(ev @ Event::Start(Tag::Paragraph)) => {
                     // we're starting the suffix chapters
+                    self.back(ev);
                     break;
                 }
                 Some(Event::Start(other_tag)) => {
@@ -330,12 +391,6 @@ impl<'a> SummaryParser<'a> {
                             break;
                         }
                     }
-
-                    if let Some(Event::Start(Tag::List(..))) = self.next_event() {
-                        continue;
-                    } else {
-                        break;
-                    }
                 }
                 Some(_) => {
                     // something else... ignore
@@ -352,12 +407,19 @@ impl<'a> SummaryParser<'a> {
     }
 
     fn next_event(&mut self) -> Option<Event<'a>> {
-        let next = self.stream.next();
+        let next = self.back.take().or_else(|| self.stream.next());
         trace!("Next event: {:?}", next);
 
         next
     }
 
+    /// Put an event back so it is the next one returned by `next_event()`.
+    fn back(&mut self, ev: Event<'a>) {
+        assert!(self.back.is_none());
+        trace!("Back: {:?}", ev);
+        self.back = Some(ev);
+    }
+
     fn parse_nested_numbered(&mut self, parent: &SectionNumber) -> Result<Vec<SummaryItem>> {
         debug!("Parsing numbered chapters at level {}", parent);
         let mut items = Vec::new();
@@ -406,7 +468,10 @@ impl<'a> SummaryParser<'a> {
                         "Found chapter: {} {} ({})",
                         number,
                         link.name,
-                        link.location.display()
+                        link.location
+                            .as_ref()
+                            .map(|p| p.to_str().unwrap_or(""))
+                            .unwrap_or("[draft]")
                     );
 
                     link.number = Some(number);
@@ -431,13 +496,18 @@ impl<'a> SummaryParser<'a> {
 
     /// Try to parse the title line.
     fn parse_title(&mut self) -> Option<String> {
-        if let Some(Event::Start(Tag::Header(1))) = self.next_event() {
-            debug!("Found a h1 in the SUMMARY");
+        match self.next_event() {
+            Some(Event::Start(Tag::Header(1))) => {
+                debug!("Found a h1 in the SUMMARY");
 
-            let tags = collect_events!(self.stream, end Tag::Header(1));
-            Some(stringify_events(tags))
-        } else {
-            None
+                let tags = collect_events!(self.stream, end Tag::Header(1));
+                Some(stringify_events(tags))
+            }
+            Some(ev) => {
+                self.back(ev);
+                None
+            }
+            None => None,
         }
     }
 }
@@ -577,12 +647,12 @@ mod tests {
         let should_be = vec![
             SummaryItem::Link(Link {
                 name: String::from("First"),
-                location: PathBuf::from("./first.md"),
+                location: Some(PathBuf::from("./first.md")),
                 ..Default::default()
             }),
             SummaryItem::Link(Link {
                 name: String::from("Second"),
-                location: PathBuf::from("./second.md"),
+                location: Some(PathBuf::from("./second.md")),
                 ..Default::default()
             }),
         ];
@@ -621,7 +691,7 @@ mod tests {
         let src = "[First](./first.md)";
         let should_be = Link {
             name: String::from("First"),
-            location: PathBuf::from("./first.md"),
+            location: Some(PathBuf::from("./first.md")),
             ..Default::default()
         };
 
@@ -642,14 +712,13 @@ mod tests {
         let src = "- [First](./first.md)\n";
         let link = Link {
             name: String::from("First"),
-            location: PathBuf::from("./first.md"),
+            location: Some(PathBuf::from("./first.md")),
             number: Some(SectionNumber(vec![1])),
             ..Default::default()
         };
         let should_be = vec![SummaryItem::Link(link)];
 
         let mut parser = SummaryParser::new(src);
-        let _ = parser.stream.next();
 
         let got = parser.parse_numbered().unwrap();
 
@@ -663,25 +732,24 @@ mod tests {
         let should_be = vec![
             SummaryItem::Link(Link {
                 name: String::from("First"),
-                location: PathBuf::from("./first.md"),
+                location: Some(PathBuf::from("./first.md")),
                 number: Some(SectionNumber(vec![1])),
                 nested_items: vec![SummaryItem::Link(Link {
                     name: String::from("Nested"),
-                    location: PathBuf::from("./nested.md"),
+                    location: Some(PathBuf::from("./nested.md")),
                     number: Some(SectionNumber(vec![1, 1])),
                     nested_items: Vec::new(),
                 })],
             }),
             SummaryItem::Link(Link {
                 name: String::from("Second"),
-                location: PathBuf::from("./second.md"),
+                location: Some(PathBuf::from("./second.md")),
                 number: Some(SectionNumber(vec![2])),
                 nested_items: Vec::new(),
             }),
         ];
 
         let mut parser = SummaryParser::new(src);
-        let _ = parser.stream.next();
 
         let got = parser.parse_numbered().unwrap();
 
@@ -698,20 +766,19 @@ mod tests {
         let should_be = vec![
             SummaryItem::Link(Link {
                 name: String::from("First"),
-                location: PathBuf::from("./first.md"),
+                location: Some(PathBuf::from("./first.md")),
                 number: Some(SectionNumber(vec![1])),
                 nested_items: Vec::new(),
             }),
             SummaryItem::Link(Link {
                 name: String::from("Second"),
-                location: PathBuf::from("./second.md"),
+                location: Some(PathBuf::from("./second.md")),
                 number: Some(SectionNumber(vec![2])),
                 nested_items: Vec::new(),
             }),
         ];
 
         let mut parser = SummaryParser::new(src);
-        let _ = parser.stream.next();
 
         let got = parser.parse_numbered().unwrap();
 
@@ -719,13 +786,19 @@ mod tests {
     }
 
     #[test]
-    fn an_empty_link_location_is_an_error() {
+    fn an_empty_link_location_is_a_draft_chapter() {
         let src = "- [Empty]()\n";
         let mut parser = SummaryParser::new(src);
-        parser.stream.next();
 
-        let got = parser.parse_numbered();
-        assert!(got.is_err());
+        let got = parser.parse_numbered().unwrap();
+        let should_be = vec![SummaryItem::Link(Link {
+            name: String::from("Empty"),
+            location: None,
+            number: Some(SectionNumber(vec![1])),
+            nested_items: Vec::new(),
+        })];
+
+        assert_eq!(got, should_be);
     }
 
     /// Regression test for https://github.com/rust-lang-nursery/mdBook/issues/779
@@ -737,31 +810,119 @@ mod tests {
         let should_be = vec![
             SummaryItem::Link(Link {
                 name: String::from("First"),
-                location: PathBuf::from("./first.md"),
+                location: Some(PathBuf::from("./first.md")),
                 number: Some(SectionNumber(vec![1])),
                 nested_items: Vec::new(),
             }),
             SummaryItem::Separator,
             SummaryItem::Link(Link {
                 name: String::from("Second"),
-                location: PathBuf::from("./second.md"),
+                location: Some(PathBuf::from("./second.md")),
                 number: Some(SectionNumber(vec![2])),
                 nested_items: Vec::new(),
             }),
             SummaryItem::Separator,
             SummaryItem::Link(Link {
                 name: String::from("Third"),
-                location: PathBuf::from("./third.md"),
+                location: Some(PathBuf::from("./third.md")),
                 number: Some(SectionNumber(vec![3])),
                 nested_items: Vec::new(),
             }),
         ];
 
         let mut parser = SummaryParser::new(src);
-        let _ = parser.stream.next();
 
         let got = parser.parse_numbered().unwrap();
 
         assert_eq!(got, should_be);
     }
+
+    #[test]
+    fn parse_part_titles_between_numbered_chapters() {
+        let src = "# Part I\n\n- [First](./first.md)\n\n# Part II\n\n- [Second](./second.md)\n";
+        let should_be = vec![
+            SummaryItem::PartTitle(String::from("Part I")),
+            SummaryItem::Link(Link {
+                name: String::from("First"),
+                location: Some(PathBuf::from("./first.md")),
+                number: Some(SectionNumber(vec![1])),
+                nested_items: Vec::new(),
+            }),
+            SummaryItem::PartTitle(String::from("Part II")),
+            SummaryItem::Link(Link {
+                name: String::from("Second"),
+                location: Some(PathBuf::from("./second.md")),
+                number: Some(SectionNumber(vec![2])),
+                nested_items: Vec::new(),
+            }),
+        ];
+
+        let mut parser = SummaryParser::new(src);
+        let got = parser.parse_numbered().unwrap();
+
+        assert_eq!(got, should_be);
+    }
+
+    #[test]
+    fn part_titles_come_after_prefix_chapters_and_the_summary_title() {
+        let src = "# Summary\n\n[Intro](./intro.md)\n\n# Part I\n\n- [First](./first.md)\n\n[Outro](./outro.md)\n";
+
+        let got = parse_summary(src).unwrap();
+
+        assert_eq!(got.title, Some(String::from("Summary")));
+        assert_eq!(got.prefix_chapters.len(), 1);
+        assert_eq!(
+            got.numbered_chapters[0],
+            SummaryItem::PartTitle(String::from("Part I"))
+        );
+        assert_eq!(got.numbered_chapters.len(), 2);
+        assert_eq!(got.suffix_chapters.len(), 1);
+    }
+
+    #[test]
+    fn suffix_items_cannot_be_followed_by_a_part_title() {
+        let src = "- [First](./first.md)\n\n[Second](./second.md)\n\n# Part II\n";
+
+        let got = parse_summary(src);
+
+        assert!(got.is_err());
+    }
+
+    #[test]
+    fn a_summary_without_a_title_can_start_with_numbered_chapters() {
+        let src = "- [First](./first.md)\n";
+
+        let got = parse_summary(src).unwrap();
+
+        assert_eq!(got.title, None);
+        assert!(got.prefix_chapters.is_empty());
+        assert_eq!(got.numbered_chapters.len(), 1);
+    }
+
+    #[test]
+    fn a_leading_heading_before_parted_chapters_is_the_first_part_title() {
+        let src = "# Part I\n- [First](./first.md)\n\n# Part II\n- [Second](./second.md)\n";
+
+        let got = parse_summary(src).unwrap();
+
+        assert_eq!(got.title, None);
+        assert_eq!(
+            got.numbered_chapters[0],
+            SummaryItem::PartTitle(String::from("Part I"))
+        );
+        assert_eq!(
+            got.numbered_chapters[2],
+            SummaryItem::PartTitle(String::from("Part II"))
+        );
+    }
+
+    #[test]
+    fn a_leading_heading_before_unparted_chapters_is_the_summary_title() {
+        let src = "# Summary\n- [First](./first.md)\n";
+
+        let got = parse_summary(src).unwrap();
+
+        assert_eq!(got.title, Some(String::from("Summary")));
+        assert_eq!(got.numbered_chapters.len(), 1);
+    }
 }
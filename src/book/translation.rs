@@ -0,0 +1,143 @@
+use std::fs;
+use std::path::Path;
+
+use super::book::{load_book_with_fallback, Fallback};
+use super::summary::parse_summary;
+use super::Book;
+use crate::config::Config;
+use crate::errors::*;
+
+/// Shown at the top of untranslated chapters unless the language sets its own
+/// `untranslated-notice`.
+const UNTRANSLATED_NOTICE: &str = "This chapter hasn't been translated yet.";
+
+/// A multilingual book's translation into one of its other languages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Translation {
+    /// The language's code, which is also the name of its directory in `src`.
+    pub language: String,
+    /// The translated book. Chapters which haven't been translated yet are
+    /// taken from the default language, with a notice at the top.
+    pub book: Book,
+}
+
+/// Load every translation of a multilingual book from `src_dir`. Each
+/// language has its own directory, and one without a `SUMMARY.md` uses the
+/// default language's.
+pub(crate) fn load_translations(src_dir: &Path, config: &Config) -> Result<Vec<Translation>> {
+    if !config.book.multilingual {
+        return Ok(Vec::new());
+    }
+
+    let default_language = default_language(config)?;
+    let default_dir = src_dir.join(&default_language);
+    let mut translations = Vec::new();
+
+    for (language, language_config) in config.languages() {
+        if language == default_language {
+            continue;
+        }
+
+        let language_dir = src_dir.join(&language);
+        let mut summary_md = language_dir.join("SUMMARY.md");
+        if !summary_md.exists() {
+            summary_md = default_dir.join("SUMMARY.md");
+        }
+
+        let summary = fs::read_to_string(&summary_md)
+            .chain_err(|| format!("Couldn't open {}", summary_md.display()))?;
+        let summary = parse_summary(&summary)
+            .chain_err(|| format!("Summary parsing failed for the {} translation", language))?;
+
+        let notice = match language_config.untranslated_notice {
+            Some(ref notice) => notice,
+            None => UNTRANSLATED_NOTICE,
+        };
+        let fallback = Fallback {
+            src_dir: &default_dir,
+            notice,
+        };
+        let book = load_book_with_fallback(&summary, &language_dir, &config.build, Some(&fallback))
+            .chain_err(|| format!("Unable to load the {} translation", language))?;
+
+        translations.push(Translation { language, book });
+    }
+
+    Ok(translations)
+}
+
+/// The language a multilingual book falls back to, which it needs to have.
+pub(crate) fn default_language(config: &Config) -> Result<String> {
+    match config.default_language() {
+        Some(language) => Ok(language),
+        None => bail!(
+            "A multilingual book needs a default language, set `book.language` or add \
+             `default = true` to one of the `[language]` tables"
+        ),
+    }
+}
+
+/// The configuration one of a multilingual book's languages is preprocessed
+/// and rendered with. Its `src` is the language's directory, `book.language`
+/// is the language itself, and the default language is marked in the
+/// `[language]` tables so renderers can tell them apart.
+pub(crate) fn config_for_language(config: &Config, language: &str, default: &str) -> Config {
+    let language_config = config.languages().remove(language).unwrap_or_default();
+    let mut config = config.clone();
+
+    config.book.src = config.book.src.join(language);
+    config.book.language = Some(language.to_string());
+    if language_config.title.is_some() {
+        config.book.title = language_config.title;
+    }
+    if language_config.description.is_some() {
+        config.book.description = language_config.description;
+    }
+    config
+        .set(format!("language.{}.default", default), true)
+        .expect("A bool can always be stored in the config");
+
+    config
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::str::FromStr;
+
+    const CONFIG: &str = r#"
+        [book]
+        title = "A Book"
+        multilingual = true
+        language = "en"
+
+        [language.en]
+        name = "English"
+
+        [language.ja]
+        name = "日本語"
+        title = "本"
+        "#;
+
+    #[test]
+    fn each_language_is_read_from_its_own_directory() {
+        let config = Config::from_str(CONFIG).unwrap();
+
+        let got = config_for_language(&config, "ja", "en");
+
+        assert_eq!(got.book.src, PathBuf::from("src/ja"));
+        assert_eq!(got.book.language, Some(String::from("ja")));
+        assert_eq!(got.book.title, Some(String::from("本")));
+        assert_eq!(got.default_language(), Some(String::from("en")));
+    }
+
+    #[test]
+    fn languages_without_a_title_keep_the_books() {
+        let config = Config::from_str(CONFIG).unwrap();
+
+        let got = config_for_language(&config, "en", "en");
+
+        assert_eq!(got.book.title, Some(String::from("A Book")));
+    }
+}
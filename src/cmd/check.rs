@@ -0,0 +1,34 @@
+use crate::get_book_dir;
+use clap::{App, ArgMatches, SubCommand};
+use mdbook::errors::Result;
+use mdbook::MDBook;
+
+// Create clap subcommand arguments
+pub fn make_subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("check")
+        .about("Checks a book for broken links, missing anchors and missing images")
+        .arg_from_usage(
+            "[dir] 'Root directory for the book{n}\
+             (Defaults to the Current Directory when omitted)'",
+        )
+}
+
+// Check command implementation
+pub fn execute(args: &ArgMatches) -> Result<()> {
+    let book_dir = get_book_dir(args);
+    let book = MDBook::load(&book_dir)?;
+
+    let broken_links = book.check()?;
+
+    for broken in &broken_links {
+        error!("{}", broken);
+    }
+
+    if !broken_links.is_empty() {
+        return Err(format!("Found {} broken link(s)", broken_links.len()).into());
+    }
+
+    info!("No broken links found");
+
+    Ok(())
+}
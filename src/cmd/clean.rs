@@ -7,7 +7,7 @@ use std::fs;
 // Create clap subcommand arguments
 pub fn make_subcommand<'a, 'b>() -> App<'a, 'b> {
     SubCommand::with_name("clean")
-        .about("Deletes a built book")
+        .about("Deletes a built book and the preprocessor cache")
         .arg_from_usage(
             "-d, --dest-dir=[dest-dir] 'Output directory for the book{n}\
              Relative paths are interpreted relative to the book's root directory.{n}\
@@ -31,5 +31,10 @@ pub fn execute(args: &ArgMatches) -> mdbook::errors::Result<()> {
     };
     fs::remove_dir_all(&dir_to_remove).chain_err(|| "Unable to remove the build directory")?;
 
+    let cache_dir = book.cache_dir();
+    if cache_dir.exists() {
+        fs::remove_dir_all(&cache_dir).chain_err(|| "Unable to remove the preprocessor cache")?;
+    }
+
     Ok(())
 }
@@ -2,12 +2,34 @@
 use super::watch;
 use crate::{get_book_dir, open};
 use clap::{App, Arg, ArgMatches, SubCommand};
-use iron::{status, AfterMiddleware, Chain, Iron, IronError, IronResult, Request, Response, Set};
+use iron::headers::ContentType;
+use iron::modifiers::{Header, RedirectRaw};
+use iron::{
+    status, AfterMiddleware, Chain, Handler, Iron, IronError, IronResult, Request, Response, Set,
+};
+use mdbook::book::IncrementalBuild;
 use mdbook::errors::*;
 use mdbook::utils;
 use mdbook::MDBook;
+use mount::Mount;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Answers every failed request with a 404, showing the book's 404 page if
+/// it has one.
+struct ErrorRecover {
+    file_404: PathBuf,
+}
 
-struct ErrorRecover;
+/// Serves the book's files, but answers with a real redirect for the pages in
+/// `output.html.redirect` instead of serving their stub pages.
+struct Redirects {
+    redirects: BTreeMap<String, String>,
+    /// Where the book is mounted, which destinations inside it are under.
+    site_path: String,
+    files: staticfile::Static,
+}
 
 // Create clap subcommand arguments
 pub fn make_subcommand<'a, 'b>() -> App<'a, 'b> {
@@ -64,7 +86,6 @@ pub fn make_subcommand<'a, 'b>() -> App<'a, 'b> {
 // Watch command implementation
 pub fn execute(args: &ArgMatches) -> Result<()> {
     let book_dir = get_book_dir(args);
-    let mut book = MDBook::load(&book_dir)?;
 
     let port = args.value_of("port").unwrap();
     let ws_port = args.value_of("websocket-port").unwrap();
@@ -76,17 +97,46 @@ pub fn execute(args: &ArgMatches) -> Result<()> {
     let ws_address = format!("{}:{}", hostname, ws_port);
 
     let livereload_url = format!("ws://{}:{}", public_address, ws_port);
-    book.config
-        .set("output.html.livereload-url", &livereload_url)?;
-
-    if let Some(dest_dir) = args.value_of("dest-dir") {
-        book.config.build.build_dir = dest_dir.into();
-    }
-
-    book.build()?;
-
-    let mut chain = Chain::new(staticfile::Static::new(book.build_dir_for("html")));
-    chain.link_after(ErrorRecover);
+    let dest_dir = args.value_of("dest-dir").map(PathBuf::from);
+
+    #[cfg_attr(not(feature = "watch"), allow(unused_mut))]
+    let mut build = IncrementalBuild::new(move || {
+        let mut book = MDBook::load(&book_dir)?;
+        book.config
+            .set("output.html.livereload-url", &livereload_url)?;
+        if let Some(ref dest_dir) = dest_dir {
+            book.config.build.build_dir = dest_dir.clone();
+        }
+        Ok(book)
+    })?;
+
+    let html_config = build.book().config.html_config().unwrap_or_default();
+    // The book is served from the same path as where it is deployed
+    let site_path = html_config.site_path();
+    let redirects = html_config
+        .redirect
+        .into_iter()
+        .map(|(from, to)| (from.trim_start_matches('/').to_string(), to))
+        .collect();
+    let html_dir = build.book().build_dir_for("html");
+    // A multilingual book shows the 404 page of its default language
+    let config = &build.book().config;
+    let file_404 = match config.default_language() {
+        Some(ref language) if config.book.multilingual => html_dir.join(language).join("404.html"),
+        _ => html_dir.join("404.html"),
+    };
+    let files = staticfile::Static::new(html_dir);
+    let mut mount = Mount::new();
+    mount.mount(
+        &site_path,
+        Redirects {
+            redirects,
+            site_path: site_path.clone(),
+            files,
+        },
+    );
+    let mut chain = Chain::new(mount);
+    chain.link_after(ErrorRecover { file_404 });
     let _iron = Iron::new(chain)
         .http(&*address)
         .chain_err(|| "Unable to launch the server")?;
@@ -100,7 +150,7 @@ pub fn execute(args: &ArgMatches) -> Result<()> {
         ws_server.listen(&*ws_address).unwrap();
     });
 
-    let serving_url = format!("http://{}", address);
+    let serving_url = format!("http://{}{}", address, site_path);
     info!("Serving on: {}", serving_url);
 
     if open_browser {
@@ -108,21 +158,11 @@ pub fn execute(args: &ArgMatches) -> Result<()> {
     }
 
     #[cfg(feature = "watch")]
-    watch::trigger_on_change(&book, move |paths, book_dir| {
+    watch::trigger_on_change(&mut build, move |build, paths| {
         info!("Files changed: {:?}", paths);
         info!("Building book...");
 
-        // FIXME: This area is really ugly because we need to re-set livereload :(
-
-        let result = MDBook::load(&book_dir)
-            .and_then(|mut b| {
-                b.config
-                    .set("output.html.livereload-url", &livereload_url)?;
-                Ok(b)
-            })
-            .and_then(|b| b.build());
-
-        if let Err(e) = result {
+        if let Err(e) = build.rebuild(&paths) {
             error!("Unable to load the book");
             utils::log_backtrace(&e);
         } else {
@@ -137,8 +177,35 @@ impl AfterMiddleware for ErrorRecover {
     fn catch(&self, _: &mut Request, err: IronError) -> IronResult<Response> {
         match err.response.status {
             // each error will result in 404 response
-            Some(_) => Ok(err.response.set(status::NotFound)),
+            Some(_) => {
+                // The page is read for every request, since rebuilds change it
+                let response = match fs::read(&self.file_404) {
+                    Ok(page) => {
+                        Response::with((status::NotFound, Header(ContentType::html()), page))
+                    }
+                    Err(_) => err.response.set(status::NotFound),
+                };
+                Ok(response)
+            }
             _ => Err(err),
         }
     }
 }
+
+impl Handler for Redirects {
+    fn handle(&self, req: &mut Request) -> IronResult<Response> {
+        let path = req.url.path().join("/");
+        match self.redirects.get(&path) {
+            Some(to) => {
+                // Destinations inside the book are relative to its root
+                let location = if to.starts_with("//") || to.contains("://") {
+                    to.clone()
+                } else {
+                    format!("{}{}", self.site_path, to.trim_start_matches('/'))
+                };
+                Ok(Response::with((status::Found, RedirectRaw(location))))
+            }
+            None => self.files.handle(req),
+        }
+    }
+}
@@ -16,6 +16,10 @@ pub fn make_subcommand<'a, 'b>() -> App<'a, 'b> {
             "[dir] 'Root directory for the book{n}\
              (Defaults to the Current Directory when omitted)'",
         )
+        .arg_from_usage(
+            "-c, --chapter=[chapter] 'Only test the chapter with this name or path{n}\
+             Paths are interpreted relative to the book's source directory.'",
+        )
         .arg(Arg::with_name("library-path")
             .short("L")
             .long("library-path")
@@ -40,7 +44,7 @@ pub fn execute(args: &ArgMatches) -> Result<()> {
         book.config.build.build_dir = dest_dir.into();
     }
 
-    book.test(library_paths)?;
+    book.test_chapter(library_paths, args.value_of("chapter"))?;
 
     Ok(())
 }
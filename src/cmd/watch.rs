@@ -1,10 +1,11 @@
 use crate::{get_book_dir, open};
 use clap::{App, ArgMatches, SubCommand};
+use mdbook::book::IncrementalBuild;
 use mdbook::errors::Result;
 use mdbook::utils;
 use mdbook::MDBook;
 use notify::Watcher;
-use std::path::{Path, PathBuf};
+use std::path::PathBuf;
 use std::sync::mpsc::channel;
 use std::thread::sleep;
 use std::time::Duration;
@@ -28,18 +29,24 @@ pub fn make_subcommand<'a, 'b>() -> App<'a, 'b> {
 // Watch command implementation
 pub fn execute(args: &ArgMatches) -> Result<()> {
     let book_dir = get_book_dir(args);
-    let book = MDBook::load(&book_dir)?;
+    let dest_dir = args.value_of("dest-dir").map(PathBuf::from);
+
+    let mut build = IncrementalBuild::new(move || {
+        let mut book = MDBook::load(&book_dir)?;
+        if let Some(ref dest_dir) = dest_dir {
+            book.config.build.build_dir = dest_dir.clone();
+        }
+        Ok(book)
+    })?;
 
     if args.is_present("open") {
-        book.build()?;
-        open(book.build_dir_for("html").join("index.html"));
+        open(build.book().build_dir_for("html").join("index.html"));
     }
 
-    trigger_on_change(&book, |paths, book_dir| {
+    trigger_on_change(&mut build, |build, paths| {
         info!("Files changed: {:?}\nBuilding book...\n", paths);
-        let result = MDBook::load(&book_dir).and_then(|b| b.build());
 
-        if let Err(e) = result {
+        if let Err(e) = build.rebuild(&paths) {
             error!("Unable to build the book");
             utils::log_backtrace(&e);
         }
@@ -49,9 +56,9 @@ pub fn execute(args: &ArgMatches) -> Result<()> {
 }
 
 /// Calls the closure when a book source file is changed, blocking indefinitely.
-pub fn trigger_on_change<F>(book: &MDBook, closure: F)
+pub fn trigger_on_change<F>(build: &mut IncrementalBuild, mut closure: F)
 where
-    F: Fn(Vec<PathBuf>, &Path),
+    F: FnMut(&mut IncrementalBuild, Vec<PathBuf>),
 {
     use notify::DebouncedEvent::*;
     use notify::RecursiveMode::*;
@@ -67,6 +74,8 @@ where
         }
     };
 
+    let book = build.book();
+
     // Add the source directory to the watcher
     if let Err(e) = watcher.watch(book.source_dir(), Recursive) {
         error!("Error while watching {:?}:\n    {:?}", book.source_dir(), e);
@@ -98,6 +107,6 @@ where
             })
             .collect();
 
-        closure(paths, &book.root);
+        closure(build, paths);
     }
 }
@@ -0,0 +1,37 @@
+use crate::get_book_dir;
+use clap::{App, ArgMatches, SubCommand};
+use mdbook::errors::Result;
+use mdbook::utils;
+use mdbook::MDBook;
+use std::io::Write;
+
+// Create clap subcommand arguments
+pub fn make_subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("xgettext")
+        .about("Extracts the book's translatable messages into a gettext template")
+        .arg_from_usage(
+            "-o, --output=[output] 'Where to write the template{n}\
+             Relative paths are interpreted relative to the book's root directory.{n}\
+             If omitted, mdBook writes `messages.pot` to the gettext preprocessor's po-dir.'",
+        )
+        .arg_from_usage(
+            "[dir] 'Root directory for the book{n}\
+             (Defaults to the Current Directory when omitted)'",
+        )
+}
+
+// Xgettext command implementation
+pub fn execute(args: &ArgMatches) -> Result<()> {
+    let book_dir = get_book_dir(args);
+    let book = MDBook::load(&book_dir)?;
+
+    let output = match args.value_of("output") {
+        Some(output) => book_dir.join(output),
+        None => book.po_dir().join("messages.pot"),
+    };
+
+    info!("Writing the translation template to {}", output.display());
+    utils::fs::create_file(&output)?.write_all(book.translation_template().as_bytes())?;
+
+    Ok(())
+}
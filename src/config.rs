@@ -50,6 +50,7 @@
 #![deny(missing_docs)]
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::env;
 use std::fs::File;
 use std::io::Read;
@@ -171,6 +172,23 @@ impl Config {
         self.get_deserialized("output.html").ok()
     }
 
+    /// The languages of a multilingual book, from its `[language]` tables,
+    /// keyed by language code.
+    pub fn languages(&self) -> BTreeMap<String, LanguageConfig> {
+        self.get_deserialized("language").unwrap_or_default()
+    }
+
+    /// The language a multilingual book is written in first, which
+    /// translations fall back to for chapters they don't have yet. This is the
+    /// language marked with `default = true`, or else `book.language`.
+    pub fn default_language(&self) -> Option<String> {
+        self.languages()
+            .into_iter()
+            .find(|&(_, ref language)| language.default)
+            .map(|(code, _)| code)
+            .or_else(|| self.book.language.clone())
+    }
+
     /// Convenience function to fetch a value from the config and deserialize it
     /// into some arbitrary type.
     pub fn get_deserialized<'de, T: Deserialize<'de>, S: AsRef<str>>(&self, name: S) -> Result<T> {
@@ -388,6 +406,25 @@ impl Default for BookConfig {
     }
 }
 
+/// Configuration for one of the languages of a multilingual book, from its
+/// `[language.<code>]` table.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct LanguageConfig {
+    /// The language's name, as shown in the language switcher. Defaults to the
+    /// language code.
+    pub name: Option<String>,
+    /// Is this the language the book is written in first?
+    pub default: bool,
+    /// The book's title in this language.
+    pub title: Option<String>,
+    /// The book's description in this language.
+    pub description: Option<String>,
+    /// Shown at the top of chapters which haven't been translated into this
+    /// language yet.
+    pub untranslated_notice: Option<String>,
+}
+
 /// Configuration for the build procedure.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(default, rename_all = "kebab-case")]
@@ -400,6 +437,9 @@ pub struct BuildConfig {
     /// Should the default preprocessors always be used when they are
     /// compatible with the renderer?
     pub use_default_preprocessors: bool,
+    /// Should a block of YAML or TOML at the top of a chapter be parsed into
+    /// its metadata?
+    pub front_matter: bool,
 }
 
 impl Default for BuildConfig {
@@ -408,6 +448,7 @@ impl Default for BuildConfig {
             build_dir: PathBuf::from("book"),
             create_missing: true,
             use_default_preprocessors: true,
+            front_matter: false,
         }
     }
 }
@@ -450,6 +491,18 @@ pub struct HtmlConfig {
     /// FontAwesome icon class to use for the Git repository link.
     /// Defaults to `fa-github` if `None`.
     pub git_repository_icon: Option<String>,
+    /// The URL the book is published at, such as `https://example.com/book/`,
+    /// or just its path, such as `/book/`. Pages only get Open Graph and
+    /// Twitter card tags when this is an absolute URL, since those need one.
+    pub site_url: Option<String>,
+    /// Pages which moved, from their old path (e.g. `old/chapter.html`) to
+    /// where they are now, relative to the book's root or as an absolute URL.
+    /// A page which redirects there is written at the old path.
+    pub redirect: BTreeMap<String, String>,
+    /// The source file of the page shown for missing files, relative to the
+    /// source directory. Defaults to `404.md`, and a built-in page is used if
+    /// it doesn't exist.
+    pub input_404: Option<String>,
 }
 
 impl HtmlConfig {
@@ -461,6 +514,29 @@ impl HtmlConfig {
             None => root.join("theme"),
         }
     }
+
+    /// The path the book is served from, which is the path part of
+    /// `site-url`: `/book/` for `https://example.com/book`. Without a
+    /// `site-url` the book is assumed to be at the root, `/`. The path always
+    /// starts and ends with a slash.
+    pub fn site_path(&self) -> String {
+        let site_url = self.site_url.as_ref().map(String::as_str).unwrap_or("/");
+        // Drop the scheme and host of an absolute URL
+        let path = match site_url.find("://") {
+            Some(scheme_end) => {
+                let rest = &site_url[scheme_end + 3..];
+                rest.find('/').map_or("", |start| &rest[start..])
+            }
+            None => site_url,
+        };
+
+        let path = path.trim_matches('/');
+        if path.is_empty() {
+            String::from("/")
+        } else {
+            format!("/{}/", path)
+        }
+    }
 }
 
 /// Configuration for tweaking how the the HTML renderer handles the playpen.
@@ -514,6 +590,40 @@ pub struct Search {
     /// Copy JavaScript files for the search functionality to the output directory?
     /// Default: `true`.
     pub copy_js: bool,
+    /// How the search index is written out. Default: `single`.
+    pub index_format: SearchIndexFormat,
+    /// The language used to split the text into words and stem them, such as
+    /// `de`. Default: `book.language`, or else English.
+    pub language: Option<String>,
+    /// Glob patterns of the chapters to index, such as `guide/**`, relative to
+    /// the source directory. Default: every chapter.
+    pub include: Vec<String>,
+    /// Glob patterns of chapters to leave out of the index, such as
+    /// `CHANGELOG.md`. Default: none.
+    pub exclude: Vec<String>,
+    /// Multiplies the search result scores of the chapters matching each glob
+    /// pattern. A chapter matching several patterns gets all their boosts.
+    /// Default: none.
+    pub chapter_boost: BTreeMap<String, f32>,
+}
+
+/// The ways the search index can be written out.
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SearchIndexFormat {
+    /// A single `searchindex.json`, which is downloaded before the first
+    /// search.
+    Single,
+    /// A small `searchindex.json` manifest, with the rest of the index split
+    /// into shards in `searchindex/` which are downloaded as searches need
+    /// them.
+    Sharded,
+}
+
+impl Default for SearchIndexFormat {
+    fn default() -> SearchIndexFormat {
+        SearchIndexFormat::Single
+    }
 }
 
 impl Default for Search {
@@ -530,10 +640,39 @@ impl Default for Search {
             expand: true,
             heading_split_level: 3,
             copy_js: true,
+            index_format: SearchIndexFormat::default(),
+            language: None,
+            include: Vec::new(),
+            exclude: Vec::new(),
+            chapter_boost: BTreeMap::new(),
         }
     }
 }
 
+/// Configuration for the EPUB renderer.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct EpubConfig {
+    /// Use "smart quotes" instead of the usual `"` character.
+    pub curly_quotes: bool,
+    /// Additional CSS stylesheets to append to the theme's `css/epub.css`.
+    pub additional_css: Vec<PathBuf>,
+    /// The unique identifier stored in the EPUB's metadata. If `None`, one is
+    /// derived from the book's title.
+    pub identifier: Option<String>,
+}
+
+/// Configuration for the renderer which writes the whole book out as a single,
+/// print-ready HTML document.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default, rename_all = "kebab-case")]
+pub struct PrintConfig {
+    /// Use "smart quotes" instead of the usual `"` character.
+    pub curly_quotes: bool,
+    /// Additional CSS stylesheets to embed after the default styles.
+    pub additional_css: Vec<PathBuf>,
+}
+
 /// Allows you to "update" any arbitrary field in a struct by round-tripping via
 /// a `toml::Value`.
 ///
@@ -609,6 +748,7 @@ mod tests {
             build_dir: PathBuf::from("outputs"),
             create_missing: false,
             use_default_preprocessors: true,
+            front_matter: false,
         };
         let playpen_should_be = Playpen {
             editable: true,
@@ -714,6 +854,7 @@ mod tests {
             build_dir: PathBuf::from("my-book"),
             create_missing: true,
             use_default_preprocessors: true,
+            front_matter: false,
         };
 
         let html_should_be = HtmlConfig {
@@ -817,4 +958,25 @@ mod tests {
 
         assert_eq!(cfg.book.title, Some(should_be));
     }
+
+    #[test]
+    fn site_path_is_the_path_of_the_site_url() {
+        let site_paths = vec![
+            (None, "/"),
+            (Some("https://example.com"), "/"),
+            (Some("https://example.com/"), "/"),
+            (Some("https://example.com/book"), "/book/"),
+            (Some("https://example.com/docs/book/"), "/docs/book/"),
+            (Some("/book"), "/book/"),
+            (Some("book/"), "/book/"),
+        ];
+
+        for (site_url, should_be) in site_paths {
+            let html_config = HtmlConfig {
+                site_url: site_url.map(String::from),
+                ..Default::default()
+            };
+            assert_eq!(html_config.site_path(), should_be, "{:?}", site_url);
+        }
+    }
 }
@@ -100,6 +100,7 @@ extern crate pretty_assertions;
 
 pub mod book;
 pub mod config;
+pub mod plugin;
 pub mod preprocess;
 pub mod renderer;
 pub mod theme;
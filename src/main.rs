@@ -34,8 +34,10 @@ fn main() {
         )
         .subcommand(cmd::init::make_subcommand())
         .subcommand(cmd::build::make_subcommand())
+        .subcommand(cmd::check::make_subcommand())
         .subcommand(cmd::test::make_subcommand())
-        .subcommand(cmd::clean::make_subcommand());
+        .subcommand(cmd::clean::make_subcommand())
+        .subcommand(cmd::xgettext::make_subcommand());
 
     #[cfg(feature = "watch")]
     let app = app.subcommand(cmd::watch::make_subcommand());
@@ -46,12 +48,14 @@ fn main() {
     let res = match app.get_matches().subcommand() {
         ("init", Some(sub_matches)) => cmd::init::execute(sub_matches),
         ("build", Some(sub_matches)) => cmd::build::execute(sub_matches),
+        ("check", Some(sub_matches)) => cmd::check::execute(sub_matches),
         ("clean", Some(sub_matches)) => cmd::clean::execute(sub_matches),
         #[cfg(feature = "watch")]
         ("watch", Some(sub_matches)) => cmd::watch::execute(sub_matches),
         #[cfg(feature = "serve")]
         ("serve", Some(sub_matches)) => cmd::serve::execute(sub_matches),
         ("test", Some(sub_matches)) => cmd::test::execute(sub_matches),
+        ("xgettext", Some(sub_matches)) => cmd::xgettext::execute(sub_matches),
         (_, _) => unreachable!(),
     };
 
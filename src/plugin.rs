@@ -0,0 +1,631 @@
+//! The handshake between `mdbook` and third-party preprocessors and renderers.
+//!
+//! # Capabilities Handshake
+//!
+//! A plugin which has `capabilities = true` (or `persistent = true`) in its
+//! table in `book.toml` is asked for its capabilities before it is used for
+//! the first time. Other plugins aren't, because one which predates the
+//! handshake may take the extra argument for something else. `mdbook` runs
+//! the plugin's command with a single extra `capabilities` argument, and a
+//! plugin which understands the handshake prints a JSON [Capabilities]
+//! document to `stdout` and exits successfully, for example:
+//!
+//! ```json
+//! {
+//!   "protocol_versions": [1],
+//!   "renderers": ["html"],
+//!   "config": { "blow-up": "boolean" }
+//! }
+//! ```
+//!
+//! `mdbook` then refuses to use the plugin if they don't have a protocol
+//! version in common, or if its table in `book.toml` has a key of the wrong
+//! type. Plugins which exit unsuccessfully or don't print a JSON object are
+//! assumed to predate the handshake, and are used as before.
+//!
+//! # Persistent Plugins
+//!
+//! Plugins are normally started afresh for every build. A plugin which says it
+//! is `persistent` in its capabilities can instead be kept running by setting
+//! `persistent = true` in its table. `mdbook` starts it once as `$cmd rpc` and
+//! sends it one JSON-RPC 2.0 [Request] per job over `stdin`, which it answers
+//! with a [Response] on `stdout`. Each message is framed by a
+//! `Content-Length: <bytes>` header and an empty line, as written by
+//! [write_message()] and read by [read_message()]. The methods are:
+//!
+//! - `preprocess`, with the `[context, book]` a preprocessor is normally given
+//!   on `stdin`, answered with the processed book.
+//! - `supports`, with `[renderer]`, answered with `true` or `false`.
+//! - `render`, with `[context]` for a renderer, answered with `null`.
+//!
+//! Failures are answered with an error response rather than by exiting. The
+//! plugin should exit once `stdin` is closed. If it crashes, it is restarted.
+//!
+//! [Capabilities]: struct.Capabilities.html
+//! [Request]: struct.Request.html
+//! [Response]: struct.Response.html
+//! [write_message()]: fn.write_message.html
+//! [read_message()]: fn.read_message.html
+
+use crate::errors::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use shlex::Shlex;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+use std::sync::{Arc, Mutex};
+use toml::Value;
+
+/// The version of the plugin protocol spoken by this version of `mdbook`.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// What a plugin tells `mdbook` about itself in the capabilities handshake.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Capabilities {
+    /// The versions of the plugin protocol the plugin understands.
+    pub protocol_versions: Vec<u32>,
+    /// The renderers a preprocessor supports. If this is `None`, `mdbook`
+    /// asks the preprocessor with `supports $renderer` instead.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub renderers: Option<Vec<String>>,
+    /// The keys the plugin accepts in its table in `book.toml`, and the type
+    /// of each one's value. If this is `None`, the table isn't checked.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub config: Option<BTreeMap<String, ConfigType>>,
+    /// Whether the plugin can be kept running between builds, answering
+    /// requests with `$cmd rpc`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub persistent: bool,
+    /// Whether a preprocessor can be given just the chapters which changed
+    /// when `mdbook serve` or `mdbook watch` rebuilds a book.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub partial: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !*value
+}
+
+impl Capabilities {
+    /// The capabilities of a plugin which speaks the current protocol version,
+    /// and doesn't say anything else about itself.
+    pub fn new() -> Capabilities {
+        Capabilities {
+            protocol_versions: vec![PROTOCOL_VERSION],
+            renderers: None,
+            config: None,
+            persistent: false,
+            partial: false,
+        }
+    }
+
+    /// Whether the plugin supports `renderer`, if it said which ones it does.
+    pub fn supports_renderer(&self, renderer: &str) -> Option<bool> {
+        self.renderers
+            .as_ref()
+            .map(|renderers| renderers.iter().any(|r| r == renderer))
+    }
+
+    /// Check the plugin's `table` from `book.toml` against the config it
+    /// accepts. Keys which are read by `mdbook` itself, listed in
+    /// `mdbook_keys`, are left alone.
+    pub(crate) fn check_config(
+        &self,
+        name: &str,
+        table: Option<&Value>,
+        mdbook_keys: &[&str],
+    ) -> Result<()> {
+        let (schema, table) = match (&self.config, table.and_then(Value::as_table)) {
+            (&Some(ref schema), Some(table)) => (schema, table),
+            _ => return Ok(()),
+        };
+
+        for (key, value) in table {
+            if mdbook_keys.contains(&key.as_str()) {
+                continue;
+            }
+
+            match schema.get(key) {
+                Some(ty) if !ty.matches(value) => bail!(
+                    "The \"{}\" plugin expects `{}` to be a {}, but it is a {}",
+                    name,
+                    key,
+                    ty,
+                    value.type_str()
+                ),
+                Some(_) => {}
+                None => warn!(
+                    "The \"{}\" plugin doesn't accept `{}` in its config, it will be ignored",
+                    name, key
+                ),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for Capabilities {
+    fn default() -> Capabilities {
+        Capabilities::new()
+    }
+}
+
+/// The type of a value in a plugin's config.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConfigType {
+    /// A string.
+    String,
+    /// An integer.
+    Integer,
+    /// A floating point number, or an integer.
+    Float,
+    /// `true` or `false`.
+    Boolean,
+    /// An array of any values.
+    Array,
+    /// A table of any values.
+    Table,
+}
+
+impl ConfigType {
+    fn matches(self, value: &Value) -> bool {
+        match (self, value) {
+            (ConfigType::String, &Value::String(_))
+            | (ConfigType::Integer, &Value::Integer(_))
+            | (ConfigType::Float, &Value::Float(_))
+            | (ConfigType::Float, &Value::Integer(_))
+            | (ConfigType::Boolean, &Value::Boolean(_))
+            | (ConfigType::Array, &Value::Array(_))
+            | (ConfigType::Table, &Value::Table(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl fmt::Display for ConfigType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match *self {
+            ConfigType::String => "string",
+            ConfigType::Integer => "integer",
+            ConfigType::Float => "float",
+            ConfigType::Boolean => "boolean",
+            ConfigType::Array => "array",
+            ConfigType::Table => "table",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Asks a plugin for its capabilities the first time they are needed, and
+/// remembers the answer. Clones share the answer.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Handshake {
+    capabilities: Arc<Mutex<Option<Option<Capabilities>>>>,
+}
+
+impl Handshake {
+    /// The capabilities of the plugin run by `cmd`, or `None` if it doesn't
+    /// know the handshake.
+    pub(crate) fn capabilities(&self, name: &str, cmd: Command) -> Result<Option<Capabilities>> {
+        let mut cached = self
+            .capabilities
+            .lock()
+            .expect("The handshake is never poisoned");
+
+        if let Some(ref capabilities) = *cached {
+            return Ok(capabilities.clone());
+        }
+
+        let capabilities = ask_for_capabilities(name, cmd)?;
+        *cached = Some(capabilities.clone());
+        Ok(capabilities)
+    }
+}
+
+fn ask_for_capabilities(name: &str, mut cmd: Command) -> Result<Option<Capabilities>> {
+    debug!("Asking the \"{}\" plugin for its capabilities", name);
+
+    // Plugins which predate the handshake will complain about the argument
+    let output = match cmd
+        .arg("capabilities")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .output()
+    {
+        Ok(output) => output,
+        // Running the plugin for real reports why it can't be started
+        Err(_) => return Ok(None),
+    };
+
+    let document = serde_json::from_slice(&output.stdout).unwrap_or(serde_json::Value::Null);
+    if !output.status.success() || !document.is_object() {
+        debug!(
+            "The \"{}\" plugin doesn't support the capabilities handshake",
+            name
+        );
+        return Ok(None);
+    }
+
+    let capabilities: Capabilities = serde_json::from_value(document)
+        .chain_err(|| format!("The \"{}\" plugin answered with invalid capabilities", name))?;
+
+    if !capabilities.protocol_versions.contains(&PROTOCOL_VERSION) {
+        bail!(
+            "The \"{}\" plugin speaks versions {:?} of the plugin protocol, but this version \
+             of mdbook ({}) only speaks version {}",
+            name,
+            capabilities.protocol_versions,
+            crate::MDBOOK_VERSION,
+            PROTOCOL_VERSION
+        );
+    }
+
+    Ok(Some(capabilities))
+}
+
+/// Create the command for running a plugin from its command string.
+pub(crate) fn command(cmd: &str) -> Result<Command> {
+    let mut words = Shlex::new(cmd);
+    let executable = match words.next() {
+        Some(e) => e,
+        None => bail!("Command string was empty"),
+    };
+
+    let mut cmd = Command::new(executable);
+
+    for arg in words {
+        cmd.arg(arg);
+    }
+
+    Ok(cmd)
+}
+
+/// A request from `mdbook` to a persistent plugin.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Request {
+    /// Always `"2.0"`.
+    pub jsonrpc: String,
+    /// Identifies the request, which is answered with the same `id`.
+    pub id: u64,
+    /// What to do: `preprocess`, `supports` or `render`.
+    pub method: String,
+    /// The method's arguments, as an array.
+    pub params: serde_json::Value,
+}
+
+/// A persistent plugin's answer to a `Request`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Response {
+    /// Always `"2.0"`.
+    pub jsonrpc: String,
+    /// The `id` of the request this answers.
+    pub id: u64,
+    /// The result, if the request succeeded.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result: Option<serde_json::Value>,
+    /// Why the request failed, if it did.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<ResponseError>,
+}
+
+impl Response {
+    /// Answer the request with `id` with its `result`.
+    pub fn result(id: u64, result: serde_json::Value) -> Response {
+        Response {
+            jsonrpc: String::from("2.0"),
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    /// Answer the request with `id` with the reason it failed.
+    pub fn error<S: Into<String>>(id: u64, message: S) -> Response {
+        Response {
+            jsonrpc: String::from("2.0"),
+            id,
+            result: None,
+            error: Some(ResponseError {
+                code: ResponseError::INTERNAL_ERROR,
+                message: message.into(),
+            }),
+        }
+    }
+}
+
+/// Why a request failed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ResponseError {
+    /// A JSON-RPC error code.
+    pub code: i64,
+    /// A description of the failure, which is shown to the user.
+    pub message: String,
+}
+
+impl ResponseError {
+    /// The JSON-RPC code for a method which doesn't exist.
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    /// The JSON-RPC code for a failure while handling a request.
+    pub const INTERNAL_ERROR: i64 = -32603;
+}
+
+/// Write a message to the other end of a persistent plugin's connection.
+pub fn write_message<W: Write, T: Serialize>(mut writer: W, message: &T) -> Result<()> {
+    let body = serde_json::to_vec(message)?;
+    write!(writer, "Content-Length: {}\r\n\r\n", body.len())?;
+    writer.write_all(&body)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Read the next message written by `write_message()`, or `None` if the other
+/// end has hung up.
+pub fn read_message<R: BufRead, T: DeserializeOwned>(mut reader: R) -> Result<Option<T>> {
+    let mut content_length = None;
+    loop {
+        let mut line = String::new();
+        if reader.read_line(&mut line)? == 0 {
+            if content_length.is_none() {
+                return Ok(None);
+            }
+            bail!("The connection was closed in the middle of a message");
+        }
+
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let mut header = line.splitn(2, ':');
+        let name = header.next().unwrap_or("");
+        if name.trim().eq_ignore_ascii_case("content-length") {
+            let length = header.next().unwrap_or("").trim();
+            let length = length
+                .parse::<usize>()
+                .chain_err(|| format!("Invalid Content-Length, {:?}", length))?;
+            content_length = Some(length);
+        }
+    }
+
+    let content_length = match content_length {
+        Some(length) => length,
+        None => bail!("A message didn't have a Content-Length"),
+    };
+    let mut body = Vec::new();
+    reader.take(content_length as u64).read_to_end(&mut body)?;
+    ensure!(
+        body.len() == content_length,
+        "The connection was closed in the middle of a message"
+    );
+
+    serde_json::from_slice(&body)
+        .map(Some)
+        .chain_err(|| "Unable to parse the message")
+}
+
+/// A persistent plugin's command, and the directory it runs in.
+type ProcessKey = (String, Option<PathBuf>);
+
+lazy_static! {
+    /// The persistent plugins which have been started. They stay up for as
+    /// long as `mdbook` does, so they outlive reloads of the book.
+    static ref PROCESSES: Mutex<HashMap<ProcessKey, Arc<Mutex<PluginProcess>>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// A persistent plugin, which is started when it is first needed.
+pub(crate) struct PluginProcess {
+    name: String,
+    cmd: String,
+    cwd: Option<PathBuf>,
+    running: Option<Running>,
+    next_id: u64,
+}
+
+struct Running {
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl PluginProcess {
+    /// The process of the persistent plugin run by `cmd` in `cwd`, which has
+    /// to say it supports being persistent in its `capabilities`.
+    pub(crate) fn get(
+        name: &str,
+        cmd: &str,
+        cwd: Option<&Path>,
+        capabilities: Option<&Capabilities>,
+    ) -> Result<Arc<Mutex<PluginProcess>>> {
+        if !capabilities.map_or(false, |capabilities| capabilities.persistent) {
+            bail!(
+                "The \"{}\" plugin is configured to be persistent, but it doesn't support that",
+                name
+            );
+        }
+
+        let mut processes = PROCESSES
+            .lock()
+            .expect("The plugin processes are never poisoned");
+        let key = (cmd.to_string(), cwd.map(Path::to_path_buf));
+        let process = processes.entry(key).or_insert_with(|| {
+            Arc::new(Mutex::new(PluginProcess {
+                name: name.to_string(),
+                cmd: cmd.to_string(),
+                cwd: cwd.map(Path::to_path_buf),
+                running: None,
+                next_id: 0,
+            }))
+        });
+        Ok(Arc::clone(process))
+    }
+
+    /// Send the plugin a request, and wait for its result. If the plugin has
+    /// crashed, it is restarted and asked again.
+    pub(crate) fn call<P, R>(&mut self, method: &str, params: P) -> Result<R>
+    where
+        P: Serialize,
+        R: DeserializeOwned,
+    {
+        let params = serde_json::to_value(params)?;
+        let response = match self.send(method, &params) {
+            Ok(response) => response,
+            Err(e) => {
+                warn!(
+                    "The \"{}\" plugin stopped responding, restarting it",
+                    self.name
+                );
+                debug!("{}", e);
+                self.stop();
+                self.send(method, &params)
+                    .chain_err(|| format!("The \"{}\" plugin isn't responding", self.name))?
+            }
+        };
+
+        if let Some(error) = response.error {
+            bail!("The \"{}\" plugin failed, {}", self.name, error.message);
+        }
+        let result = response.result.unwrap_or(serde_json::Value::Null);
+        serde_json::from_value(result)
+            .chain_err(|| format!("Unable to parse the answer of the \"{}\" plugin", self.name))
+    }
+
+    fn send(&mut self, method: &str, params: &serde_json::Value) -> Result<Response> {
+        if self.running.is_none() {
+            self.running = Some(self.start()?);
+        }
+        let running = self.running.as_mut().expect("The plugin was just started");
+
+        let id = self.next_id;
+        self.next_id += 1;
+        let request = Request {
+            jsonrpc: String::from("2.0"),
+            id,
+            method: method.to_string(),
+            params: params.clone(),
+        };
+        write_message(&mut running.stdin, &request)?;
+
+        match read_message::<_, Response>(&mut running.stdout)? {
+            Some(ref response) if response.id != id => {
+                bail!(
+                    "The plugin answered request {} instead of {}",
+                    response.id,
+                    id
+                )
+            }
+            Some(response) => Ok(response),
+            None => bail!("The plugin exited"),
+        }
+    }
+
+    fn start(&self) -> Result<Running> {
+        debug!("Starting the persistent \"{}\" plugin", self.name);
+
+        let mut cmd = command(&self.cmd)?;
+        if let Some(ref cwd) = self.cwd {
+            cmd.current_dir(cwd);
+        }
+        let mut child = cmd
+            .arg("rpc")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .chain_err(|| {
+                format!(
+                    "Unable to start the \"{}\" plugin. Is it installed?",
+                    self.name
+                )
+            })?;
+
+        let stdin = child.stdin.take().expect("Child has stdin");
+        let stdout = BufReader::new(child.stdout.take().expect("Child has stdout"));
+        Ok(Running {
+            child,
+            stdin,
+            stdout,
+        })
+    }
+
+    fn stop(&mut self) {
+        if let Some(mut running) = self.running.take() {
+            let _ = running.child.kill();
+            let _ = running.child.wait();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn capabilities_with_config(config: &str) -> Capabilities {
+        let mut capabilities = Capabilities::new();
+        capabilities.config = Some(serde_json::from_str(config).unwrap());
+        capabilities
+    }
+
+    #[test]
+    fn config_is_checked_against_the_capabilities() {
+        let capabilities = capabilities_with_config(r#"{"level": "integer", "name": "string"}"#);
+        let table: Value = toml::from_str(
+            r#"
+            command = "mdbook-foo"
+            level = 3
+            unknown = true
+            "#,
+        )
+        .unwrap();
+        assert!(capabilities
+            .check_config("foo", Some(&table), &["command"])
+            .is_ok());
+
+        let table: Value = toml::from_str(r#"level = "high""#).unwrap();
+        assert!(capabilities
+            .check_config("foo", Some(&table), &["command"])
+            .is_err());
+
+        // Without a config schema anything goes
+        assert!(Capabilities::new()
+            .check_config("foo", Some(&table), &["command"])
+            .is_ok());
+    }
+
+    #[test]
+    fn messages_are_framed_by_their_length() {
+        let mut buffer = Vec::new();
+        write_message(&mut buffer, &Response::result(3, json!(["a", "b"]))).unwrap();
+        write_message(&mut buffer, &Response::error(4, "Boom")).unwrap();
+        assert!(buffer.starts_with(b"Content-Length: "));
+
+        let mut reader = buffer.as_slice();
+        let first: Response = read_message(&mut reader).unwrap().unwrap();
+        assert_eq!(first.id, 3);
+        assert_eq!(first.result, Some(json!(["a", "b"])));
+        let second: Response = read_message(&mut reader).unwrap().unwrap();
+        assert_eq!(second.error.unwrap().message, "Boom");
+        assert!(read_message::<_, Response>(&mut reader).unwrap().is_none());
+
+        let mut truncated = &buffer[..buffer.len() - 1];
+        let _: Response = read_message(&mut truncated).unwrap().unwrap();
+        assert!(read_message::<_, Response>(&mut truncated).is_err());
+    }
+
+    #[test]
+    fn capabilities_document_round_trips() {
+        let document =
+            r#"{"protocol_versions":[1],"renderers":["html"],"config":{"blow-up":"boolean"}}"#;
+        let capabilities: Capabilities = serde_json::from_str(document).unwrap();
+
+        assert_eq!(capabilities.supports_renderer("html"), Some(true));
+        assert_eq!(capabilities.supports_renderer("epub"), Some(false));
+        assert_eq!(serde_json::to_string(&capabilities).unwrap(), document);
+        assert_eq!(Capabilities::new().supports_renderer("html"), None);
+    }
+}
@@ -0,0 +1,140 @@
+use crate::errors::*;
+use crate::utils;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sha1::{Digest, Sha1};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Somewhere for preprocessors to keep their results between builds.
+///
+/// The cache lives in the `.mdbook-cache` directory of the book's root, and
+/// `mdbook clean` empties it. Results are stored per preprocessor in slots,
+/// such as one per renderer or chapter. A slot holds a single result, along
+/// with the key it was stored under, which is usually a hash of everything
+/// the result depends on (see [`key()`]). Storing a new result replaces the
+/// old one, so the cache doesn't grow as the book changes.
+///
+/// [`key()`]: #method.key
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreprocessorCache {
+    dir: PathBuf,
+}
+
+impl PreprocessorCache {
+    /// Create a cache which keeps its results in `dir`.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> PreprocessorCache {
+        PreprocessorCache { dir: dir.into() }
+    }
+
+    /// The directory the cache keeps its results in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Hash `inputs` into a key, by way of their JSON representation.
+    pub fn key<T: Serialize + ?Sized>(inputs: &T) -> Result<String> {
+        let json = serde_json::to_vec(inputs).chain_err(|| "Unable to hash the cache key")?;
+        Ok(format!("{:x}", Sha1::digest(&json)))
+    }
+
+    /// Look up what `preprocessor` stored in `slot`, if it was stored under
+    /// `key`.
+    ///
+    /// A result which can't be read is treated as missing.
+    pub fn get<T: DeserializeOwned>(&self, preprocessor: &str, slot: &str, key: &str) -> Option<T> {
+        let path = self.path(preprocessor, slot);
+        let content = fs::read(&path).ok()?;
+
+        match serde_json::from_slice::<(String, T)>(&content) {
+            Ok((stored_key, value)) => {
+                if stored_key == key {
+                    Some(value)
+                } else {
+                    None
+                }
+            }
+            Err(e) => {
+                debug!("Ignoring the unreadable {} ({})", path.display(), e);
+                None
+            }
+        }
+    }
+
+    /// Store `value` for `preprocessor` in `slot` under `key`, replacing
+    /// whatever was stored in the slot before.
+    ///
+    /// The slot can be a relative path, such as the path of a chapter.
+    pub fn put<T: Serialize + ?Sized>(
+        &self,
+        preprocessor: &str,
+        slot: &str,
+        key: &str,
+        value: &T,
+    ) -> Result<()> {
+        let path = self.path(preprocessor, slot);
+        let content = serde_json::to_vec(&(key, value))?;
+
+        utils::fs::create_file(&path)
+            .and_then(|mut f| f.write_all(&content).map_err(Into::into))
+            .chain_err(|| format!("Unable to write {} to the cache", path.display()))
+    }
+
+    fn path(&self, preprocessor: &str, slot: &str) -> PathBuf {
+        self.dir.join(preprocessor).join(format!("{}.json", slot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::Builder as TempFileBuilder;
+
+    #[test]
+    fn results_are_kept_per_preprocessor() {
+        let temp = TempFileBuilder::new().prefix("cache").tempdir().unwrap();
+        let cache = PreprocessorCache::new(temp.path());
+        let key = PreprocessorCache::key(&("some", "inputs")).unwrap();
+
+        assert_eq!(cache.get::<String>("first", "html", &key), None);
+
+        cache.put("first", "html", &key, "result").unwrap();
+
+        assert_eq!(
+            cache.get("first", "html", &key),
+            Some(String::from("result"))
+        );
+        assert_eq!(cache.get::<String>("first", "epub", &key), None);
+        assert_eq!(cache.get::<String>("second", "html", &key), None);
+    }
+
+    #[test]
+    fn new_results_replace_the_old_ones() {
+        let temp = TempFileBuilder::new().prefix("cache").tempdir().unwrap();
+        let cache = PreprocessorCache::new(temp.path());
+        let old = PreprocessorCache::key("old").unwrap();
+        let new = PreprocessorCache::key("new").unwrap();
+
+        cache.put("first", "html", &old, "old result").unwrap();
+        cache.put("first", "html", &new, "new result").unwrap();
+
+        assert_eq!(cache.get::<String>("first", "html", &old), None);
+        assert_eq!(
+            cache.get("first", "html", &new),
+            Some(String::from("new result"))
+        );
+        assert_eq!(fs::read_dir(temp.path().join("first")).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn keys_change_with_their_inputs() {
+        let first = PreprocessorCache::key(&("some", "inputs")).unwrap();
+        let second = PreprocessorCache::key(&("other", "inputs")).unwrap();
+
+        assert_eq!(first, PreprocessorCache::key(&("some", "inputs")).unwrap());
+        assert_ne!(first, second);
+        assert_eq!(first.len(), 40);
+    }
+}
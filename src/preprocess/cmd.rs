@@ -1,9 +1,10 @@
 use super::{Preprocessor, PreprocessorContext};
 use crate::book::Book;
 use crate::errors::*;
-use shlex::Shlex;
+use crate::plugin::{self, Capabilities, Handshake, PluginProcess};
 use std::io::{self, Read, Write};
 use std::process::{Child, Command, Stdio};
+use std::sync::{Arc, Mutex};
 
 /// A custom preprocessor which will shell out to a 3rd-party program.
 ///
@@ -24,20 +25,65 @@ use std::process::{Child, Command, Stdio};
 /// error. `stderr` is passed directly through to the user, so it can be used
 /// for logging or emitting warnings if desired.
 ///
+/// A preprocessor which has opted into the capabilities handshake with
+/// `with_capabilities()` is first asked for its capabilities with
+/// `$cmd capabilities`, as described in the [plugin] module. If it lists the
+/// renderers it supports, they are used instead of `$cmd supports $renderer`.
+/// A persistent preprocessor, which is always asked, is instead kept running,
+/// and sent its requests as described there too.
+///
+/// [plugin]: ../plugin/index.html
+///
 /// # Examples
 ///
 /// An example preprocessor is available in this project's `examples/`
 /// directory.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 pub struct CmdPreprocessor {
     name: String,
     cmd: String,
+    capabilities: bool,
+    persistent: bool,
+    handshake: Handshake,
 }
 
+/// The keys of a preprocessor's table which are read by `mdbook` itself.
+const MDBOOK_KEYS: &[&str] = &[
+    "command",
+    "renderers",
+    "capabilities",
+    "persistent",
+    "before",
+    "after",
+    "cache",
+];
+
 impl CmdPreprocessor {
     /// Create a new `CmdPreprocessor`.
     pub fn new(name: String, cmd: String) -> CmdPreprocessor {
-        CmdPreprocessor { name, cmd }
+        CmdPreprocessor {
+            name,
+            cmd,
+            capabilities: false,
+            persistent: false,
+            handshake: Handshake::default(),
+        }
+    }
+
+    /// Ask the preprocessor for its capabilities before using it. Only
+    /// preprocessors which understand the handshake should be asked, because
+    /// others may take `capabilities` for something else.
+    pub fn with_capabilities(mut self, capabilities: bool) -> CmdPreprocessor {
+        self.capabilities = capabilities;
+        self
+    }
+
+    /// Keep the preprocessor running between builds, rather than starting it
+    /// for every one. The preprocessor has to support this, so it is asked for
+    /// its capabilities too.
+    pub fn with_persistent(mut self, persistent: bool) -> CmdPreprocessor {
+        self.persistent = persistent;
+        self
     }
 
     /// A convenience function custom preprocessors can use to parse the input
@@ -71,19 +117,31 @@ impl CmdPreprocessor {
     }
 
     fn command(&self) -> Result<Command> {
-        let mut words = Shlex::new(&self.cmd);
-        let executable = match words.next() {
-            Some(e) => e,
-            None => bail!("Command string was empty"),
-        };
-
-        let mut cmd = Command::new(executable);
+        plugin::command(&self.cmd)
+    }
 
-        for arg in words {
-            cmd.arg(arg);
+    /// The preprocessor's capabilities, or `None` if it isn't asked for them
+    /// or doesn't know the handshake.
+    fn capabilities(&self) -> Result<Option<Capabilities>> {
+        if !self.capabilities && !self.persistent {
+            return Ok(None);
         }
+        self.handshake.capabilities(self.name(), self.command()?)
+    }
 
-        Ok(cmd)
+    /// The running process of a persistent preprocessor.
+    fn process(&self) -> Result<Arc<Mutex<PluginProcess>>> {
+        let capabilities = self.capabilities()?;
+        PluginProcess::get(self.name(), &self.cmd, None, capabilities.as_ref())
+    }
+}
+
+impl PartialEq for CmdPreprocessor {
+    fn eq(&self, other: &CmdPreprocessor) -> bool {
+        self.name == other.name
+            && self.cmd == other.cmd
+            && self.capabilities == other.capabilities
+            && self.persistent == other.persistent
     }
 }
 
@@ -93,6 +151,17 @@ impl Preprocessor for CmdPreprocessor {
     }
 
     fn run(&self, ctx: &PreprocessorContext, book: Book) -> Result<Book> {
+        if let Some(capabilities) = self.capabilities()? {
+            let table = ctx.config.get(&format!("preprocessor.{}", self.name()));
+            capabilities.check_config(self.name(), table, MDBOOK_KEYS)?;
+        }
+
+        if self.persistent {
+            let process = self.process()?;
+            let mut process = process.lock().expect("A plugin is never poisoned");
+            return process.call("preprocess", (ctx, &book));
+        }
+
         let mut cmd = self.command()?;
 
         let mut child = cmd
@@ -141,6 +210,27 @@ impl Preprocessor for CmdPreprocessor {
             }
         };
 
+        match self.capabilities() {
+            Ok(Some(capabilities)) => {
+                if let Some(supported) = capabilities.supports_renderer(renderer) {
+                    return supported;
+                }
+            }
+            Ok(None) => {}
+            // Let running the preprocessor report the problem, rather than
+            // skipping it without a word
+            Err(_) => return true,
+        }
+
+        if self.persistent {
+            let supported = self.process().and_then(|process| {
+                let mut process = process.lock().expect("A plugin is never poisoned");
+                process.call("supports", (renderer,))
+            });
+            // As above, running the preprocessor reports any problem
+            return supported.unwrap_or(true);
+        }
+
         let outcome = cmd
             .arg("supports")
             .arg(renderer)
@@ -162,6 +252,14 @@ impl Preprocessor for CmdPreprocessor {
 
         outcome.unwrap_or(false)
     }
+
+    fn supports_partial_books(&self) -> bool {
+        match self.capabilities() {
+            Ok(capabilities) => capabilities.map_or(false, |c| c.partial),
+            // The whole book is always safe to give it
+            Err(_) => false,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,487 @@
+use std::collections::HashMap;
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use pulldown_cmark::{Event, Tag};
+
+use super::{Preprocessor, PreprocessorContext};
+use crate::book::{Book, BookItem};
+use crate::config::Config;
+use crate::errors::*;
+use crate::utils;
+
+/// A preprocessor which translates the book with a gettext catalog.
+///
+/// Every chapter is split into messages: the text of each paragraph, heading,
+/// list item and table cell. Messages which have a translation in
+/// `po/<book.language>.po` are replaced with it, as are chapter names and part
+/// titles. Use `mdbook xgettext` to extract the messages into a template for
+/// translators.
+#[derive(Default)]
+pub struct GettextPreprocessor;
+
+impl GettextPreprocessor {
+    pub(crate) const NAME: &'static str = "gettext";
+
+    /// Create a new `GettextPreprocessor`.
+    pub fn new() -> Self {
+        GettextPreprocessor
+    }
+}
+
+impl Preprocessor for GettextPreprocessor {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn run(&self, ctx: &PreprocessorContext, mut book: Book) -> Result<Book> {
+        let language = match ctx.config.book.language {
+            Some(ref language) => language,
+            None => return Ok(book),
+        };
+
+        let po_file = po_dir(&ctx.root, &ctx.config).join(format!("{}.po", language));
+        if !po_file.exists() {
+            debug!("No translations found at {}", po_file.display());
+            return Ok(book);
+        }
+
+        let po = fs::read_to_string(&po_file)
+            .chain_err(|| format!("Unable to read {}", po_file.display()))?;
+        let catalog =
+            parse_po(&po).chain_err(|| format!("Unable to parse {}", po_file.display()))?;
+
+        book.for_each_mut(|item| match *item {
+            BookItem::Chapter(ref mut ch) => {
+                ch.name = translate_message(&ch.name, &catalog);
+                for name in &mut ch.parent_names {
+                    *name = translate_message(name, &catalog);
+                }
+                ch.content = translate(&ch.content, &catalog);
+            }
+            BookItem::PartTitle(ref mut title) => *title = translate_message(title, &catalog),
+            BookItem::Separator => {}
+        });
+
+        Ok(book)
+    }
+
+    fn supports_partial_books(&self) -> bool {
+        true
+    }
+}
+
+/// The directory holding the book's `.po` files, set with the `po-dir` key of
+/// the `[preprocessor.gettext]` table.
+pub(crate) fn po_dir(root: &Path, config: &Config) -> PathBuf {
+    let po_dir = config
+        .get_preprocessor(GettextPreprocessor::NAME)
+        .and_then(|table| table.get("po-dir"))
+        .and_then(|value| value.as_str())
+        .unwrap_or("po");
+
+    root.join(po_dir)
+}
+
+/// Write every message in the book to a gettext template (`.pot` file), with
+/// where each one was found. `src_dir` is the chapters' directory, relative to
+/// the book's root.
+pub(crate) fn template(book: &Book, config: &Config, src_dir: &Path) -> String {
+    let mut messages: Vec<(String, Vec<String>)> = Vec::new();
+    let mut positions = HashMap::new();
+    let summary = src_dir.join("SUMMARY.md").display().to_string();
+
+    let mut add = |text: &str, reference: String| {
+        let position = *positions.entry(text.to_string()).or_insert_with(|| {
+            messages.push((text.to_string(), Vec::new()));
+            messages.len() - 1
+        });
+        messages[position].1.push(reference);
+    };
+
+    for item in book.iter() {
+        match *item {
+            BookItem::Chapter(ref ch) => {
+                add(&ch.name, summary.clone());
+
+                if let Some(ref path) = ch.path {
+                    let path = src_dir.join(path);
+                    for message in messages_in(&ch.content) {
+                        let line = ch.content[..message.range.start].matches('\n').count() + 1;
+                        add(&message.text, format!("{}:{}", path.display(), line));
+                    }
+                }
+            }
+            BookItem::PartTitle(ref title) => add(title, summary.clone()),
+            BookItem::Separator => {}
+        }
+    }
+
+    let mut pot = String::new();
+    pot.push_str("msgid \"\"\nmsgstr \"\"\n");
+    if let Some(ref title) = config.book.title {
+        pot.push_str(&format!("\"Project-Id-Version: {}\\n\"\n", escape(title)));
+    }
+    pot.push_str("\"MIME-Version: 1.0\\n\"\n");
+    pot.push_str("\"Content-Type: text/plain; charset=UTF-8\\n\"\n");
+    pot.push_str("\"Content-Transfer-Encoding: 8bit\\n\"\n");
+
+    for (text, references) in messages {
+        pot.push('\n');
+        for reference in references {
+            pot.push_str(&format!("#: {}\n", reference));
+        }
+        write_string(&mut pot, "msgid", &text);
+        pot.push_str("msgstr \"\"\n");
+    }
+
+    pot
+}
+
+/// A translatable piece of a chapter.
+#[derive(Debug, PartialEq)]
+struct Message {
+    /// Where the message is in the chapter.
+    range: Range<usize>,
+    /// The message as translators see it, without the indentation or
+    /// blockquote markers at the start of its continuation lines.
+    text: String,
+}
+
+/// Split a chapter into messages. Each message is a run of inline markdown,
+/// i.e. the text of a paragraph, heading, list item or table cell. Code blocks
+/// and HTML blocks are left alone.
+fn messages_in(content: &str) -> Vec<Message> {
+    let mut messages = Vec::new();
+    let mut current: Option<Range<usize>> = None;
+    let mut verbatim = 0;
+
+    for (event, range) in utils::new_cmark_parser(content).into_offset_iter() {
+        let inline = match event {
+            Event::Start(Tag::CodeBlock(_)) | Event::Start(Tag::HtmlBlock) => {
+                verbatim += 1;
+                false
+            }
+            Event::End(Tag::CodeBlock(_)) | Event::End(Tag::HtmlBlock) => {
+                verbatim -= 1;
+                false
+            }
+            Event::Start(ref tag) | Event::End(ref tag) => match *tag {
+                Tag::Emphasis | Tag::Strong | Tag::Strikethrough => true,
+                Tag::Link(..) | Tag::Image(..) => true,
+                _ => false,
+            },
+            Event::Text(_)
+            | Event::Code(_)
+            | Event::InlineHtml(_)
+            | Event::FootnoteReference(_)
+            | Event::SoftBreak
+            | Event::HardBreak => verbatim == 0,
+            Event::Html(_) | Event::TaskListMarker(_) => false,
+        };
+
+        if inline {
+            current = Some(match current {
+                Some(run) => run.start.min(range.start)..run.end.max(range.end),
+                None => range,
+            });
+        } else if let Some(run) = current.take() {
+            push_message(content, run, &mut messages);
+        }
+    }
+
+    if let Some(run) = current {
+        push_message(content, run, &mut messages);
+    }
+
+    messages
+}
+
+fn push_message(content: &str, range: Range<usize>, messages: &mut Vec<Message>) {
+    let text: Vec<&str> = content[range.clone()]
+        .lines()
+        .enumerate()
+        .map(|(i, line)| match i {
+            0 => line,
+            _ => line.trim_start_matches(|c: char| c == '>' || c.is_whitespace()),
+        })
+        .collect();
+    let text = text.join("\n");
+
+    // There is nothing to translate in a lone `{{#include}}` and the like
+    let is_helper = text.starts_with("{{") && text.ends_with("}}") && !text[2..].contains("{{");
+    if !text.trim().is_empty() && !is_helper {
+        messages.push(Message { range, text });
+    }
+}
+
+/// Replace every message in a chapter which has a translation.
+fn translate(content: &str, catalog: &HashMap<String, String>) -> String {
+    let mut translated = String::with_capacity(content.len());
+    let mut last = 0;
+
+    for message in messages_in(content) {
+        let translation = match catalog.get(&message.text) {
+            Some(translation) => translation,
+            None => continue,
+        };
+
+        // Continuation lines line up with the start of the message, keeping
+        // any blockquote markers
+        let line_start = content[..message.range.start]
+            .rfind('\n')
+            .map(|i| i + 1)
+            .unwrap_or(0);
+        let indent: String = content[line_start..message.range.start]
+            .chars()
+            .map(|c| if c == '>' { '>' } else { ' ' })
+            .collect();
+
+        translated.push_str(&content[last..message.range.start]);
+        translated.push_str(&translation.replace('\n', &format!("\n{}", indent)));
+        last = message.range.end;
+    }
+
+    translated.push_str(&content[last..]);
+    translated
+}
+
+fn translate_message(text: &str, catalog: &HashMap<String, String>) -> String {
+    match catalog.get(text) {
+        Some(translation) => translation.clone(),
+        None => text.to_string(),
+    }
+}
+
+/// Read the translations in a PO file. Untranslated and fuzzy messages are
+/// skipped.
+fn parse_po(po: &str) -> Result<HashMap<String, String>> {
+    #[derive(Copy, Clone)]
+    enum Field {
+        Id,
+        Str,
+    }
+
+    #[derive(Default)]
+    struct Entry {
+        msgid: String,
+        msgstr: String,
+        fuzzy: bool,
+        /// The field continuation lines are appended to, if it's one we use.
+        field: Option<Field>,
+        has_msgstr: bool,
+    }
+
+    fn finish(entry: Entry, catalog: &mut HashMap<String, String>) {
+        if !entry.fuzzy && !entry.msgid.is_empty() && !entry.msgstr.is_empty() {
+            catalog.insert(entry.msgid, entry.msgstr);
+        }
+    }
+
+    let mut catalog = HashMap::new();
+    let mut entry = Entry::default();
+
+    for (number, line) in po.lines().enumerate() {
+        let line = line.trim();
+        let starts_entry = line.is_empty()
+            || line.starts_with('#')
+            || line.starts_with("msgctxt")
+            || line.starts_with("msgid ");
+        if starts_entry && entry.has_msgstr {
+            finish(entry, &mut catalog);
+            entry = Entry::default();
+        }
+
+        if line.is_empty() {
+            continue;
+        } else if line.starts_with("#,") {
+            entry.fuzzy |= line.contains("fuzzy");
+            continue;
+        } else if line.starts_with('#') {
+            continue;
+        }
+
+        let (keyword, string) = match line.find('"') {
+            Some(quote) => (line[..quote].trim(), &line[quote..]),
+            None => bail!("line {}: unable to understand `{}`", number + 1, line),
+        };
+        let string =
+            unescape(string).chain_err(|| format!("line {}: invalid string", number + 1))?;
+
+        match keyword {
+            "" => {}
+            "msgid" => entry.field = Some(Field::Id),
+            "msgstr" | "msgstr[0]" => {
+                entry.field = Some(Field::Str);
+                entry.has_msgstr = true;
+            }
+            "msgctxt" | "msgid_plural" => entry.field = None,
+            _ if keyword.starts_with("msgstr[") => entry.field = None,
+            _ => bail!("line {}: unknown keyword `{}`", number + 1, keyword),
+        }
+
+        match entry.field {
+            Some(Field::Id) => entry.msgid.push_str(&string),
+            Some(Field::Str) => entry.msgstr.push_str(&string),
+            None => {}
+        }
+    }
+
+    if entry.has_msgstr {
+        finish(entry, &mut catalog);
+    }
+
+    Ok(catalog)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+        .replace('\t', "\\t")
+}
+
+/// Read a quoted PO string, e.g. `"Hello\n"`.
+fn unescape(quoted: &str) -> Result<String> {
+    if quoted.len() < 2 || !quoted.starts_with('"') || !quoted.ends_with('"') {
+        bail!("`{}` isn't a quoted string", quoted);
+    }
+
+    let mut text = String::with_capacity(quoted.len());
+    let mut chars = quoted[1..quoted.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => text.push('\n'),
+            Some('t') => text.push('\t'),
+            Some('r') => text.push('\r'),
+            Some(c @ '"') | Some(c @ '\\') => text.push(c),
+            Some(c) => bail!("unknown escape sequence `\\{}`", c),
+            None => bail!("`{}` ends with a backslash", quoted),
+        }
+    }
+
+    Ok(text)
+}
+
+/// Write a PO field, putting each line of a multi-line string on its own line.
+fn write_string(po: &mut String, keyword: &str, text: &str) {
+    if !text.contains('\n') {
+        po.push_str(&format!("{} \"{}\"\n", keyword, escape(text)));
+        return;
+    }
+
+    po.push_str(&format!("{} \"\"\n", keyword));
+    let mut rest = text;
+    while !rest.is_empty() {
+        let end = rest.find('\n').map(|i| i + 1).unwrap_or_else(|| rest.len());
+        po.push_str(&format!("\"{}\"\n", escape(&rest[..end])));
+        rest = &rest[end..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(content: &str) -> Vec<String> {
+        messages_in(content).into_iter().map(|m| m.text).collect()
+    }
+
+    #[test]
+    fn chapters_are_split_into_paragraphs_headings_and_list_items() {
+        let content = "# The *Title*\n\nSome text\nover two lines.\n\n\
+                       - first\n- second with `code`\n  - nested\n\n\
+                       ```rust\nfn main() {}\n```\n\n\
+                       > quoted\n> text\n\n{{#include file.rs}}\n";
+
+        let got = texts(content);
+
+        let should_be = vec![
+            "The *Title*",
+            "Some text\nover two lines.",
+            "first",
+            "second with `code`",
+            "nested",
+            "quoted\ntext",
+        ];
+        assert_eq!(got, should_be);
+    }
+
+    #[test]
+    fn messages_are_replaced_by_their_translations() {
+        let content = "# Title\n\n- an item\n\n> some\n> text\n\nUntouched.\n";
+        let mut catalog = HashMap::new();
+        catalog.insert(String::from("Title"), String::from("Titel"));
+        catalog.insert(String::from("an item"), String::from("ein\nEintrag"));
+        catalog.insert(String::from("some\ntext"), String::from("etwas\nText"));
+
+        let got = translate(content, &catalog);
+
+        assert_eq!(
+            got,
+            "# Titel\n\n- ein\n  Eintrag\n\n> etwas\n> Text\n\nUntouched.\n"
+        );
+    }
+
+    #[test]
+    fn parse_a_po_file() {
+        let po = r#"
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+
+#: src/chapter_1.md:1
+msgid "Hello"
+msgstr "Hallo"
+
+#, fuzzy
+msgid "Fuzzy"
+msgstr "Flauschig"
+
+msgid "Untranslated"
+msgstr ""
+
+msgid ""
+"Two\n"
+"lines"
+msgstr "Zwei \"Zeilen\""
+"#;
+
+        let got = parse_po(po).unwrap();
+
+        let mut should_be = HashMap::new();
+        should_be.insert(String::from("Hello"), String::from("Hallo"));
+        should_be.insert(String::from("Two\nlines"), String::from("Zwei \"Zeilen\""));
+        assert_eq!(got, should_be);
+    }
+
+    #[test]
+    fn broken_po_files_are_an_error() {
+        assert!(parse_po("msgid \"Hello\"\nmsgstr Hallo\n").is_err());
+        assert!(parse_po("msgid \"Hello\\q\"\n").is_err());
+    }
+
+    #[test]
+    fn templates_list_each_message_once() {
+        let mut book = Book::new();
+        book.push_item(crate::book::Chapter::new(
+            "Intro",
+            String::from("# Intro\n\nSay \"hi\".\n\n# Intro\n"),
+            "intro.md",
+            Vec::new(),
+        ));
+
+        let got = template(&book, &Config::default(), Path::new("src"));
+
+        assert!(got.contains(
+            "#: src/SUMMARY.md\n#: src/intro.md:1\n#: src/intro.md:5\nmsgid \"Intro\"\n"
+        ));
+        assert!(got.contains("#: src/intro.md:3\nmsgid \"Say \\\"hi\\\".\"\nmsgstr \"\"\n"));
+    }
+}
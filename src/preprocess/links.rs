@@ -5,6 +5,7 @@ use std::fs;
 use std::ops::{Range, RangeFrom, RangeFull, RangeTo};
 use std::path::{Path, PathBuf};
 
+use super::xref::{self, CrossRefPreprocessor};
 use super::{Preprocessor, PreprocessorContext};
 use crate::book::{Book, BookItem};
 
@@ -32,25 +33,43 @@ impl Preprocessor for LinkPreprocessor {
 
     fn run(&self, ctx: &PreprocessorContext, mut book: Book) -> Result<Book> {
         let src_dir = ctx.root.join(&ctx.config.book.src);
+        // Escaped `{{#label}}` and `{{#ref}}` helpers are unescaped by the
+        // cross-reference preprocessor, which runs later
+        let keep_xref_escapes = ctx
+            .config
+            .get(&format!("preprocessor.{}", CrossRefPreprocessor::NAME))
+            .is_some();
 
         book.for_each_mut(|section: &mut BookItem| {
             if let BookItem::Chapter(ref mut ch) = *section {
-                let base = ch
-                    .path
-                    .parent()
-                    .map(|dir| src_dir.join(dir))
-                    .expect("All book items have a parent");
-
-                let content = replace_all(&ch.content, base, &ch.path, 0);
-                ch.content = content;
+                if let Some(ref chapter_path) = ch.path {
+                    let base = chapter_path
+                        .parent()
+                        .map(|dir| src_dir.join(dir))
+                        .expect("All book items have a parent");
+
+                    let content =
+                        replace_all(&ch.content, base, chapter_path, 0, keep_xref_escapes);
+                    ch.content = content;
+                }
             }
         });
 
         Ok(book)
     }
+
+    fn supports_partial_books(&self) -> bool {
+        true
+    }
 }
 
-fn replace_all<P1, P2>(s: &str, path: P1, source: P2, depth: usize) -> String
+fn replace_all<P1, P2>(
+    s: &str,
+    path: P1,
+    source: P2,
+    depth: usize,
+    keep_xref_escapes: bool,
+) -> String
 where
     P1: AsRef<Path>,
     P2: AsRef<Path>,
@@ -64,13 +83,26 @@ where
     let mut replaced = String::new();
 
     for link in find_links(s) {
+        if keep_xref_escapes
+            && link.link_type == LinkType::Escaped
+            && xref::is_escaped_directive(link.link_text)
+        {
+            continue;
+        }
+
         replaced.push_str(&s[previous_end_index..link.start_index]);
 
         match link.render_with_path(&path) {
             Ok(new_content) => {
                 if depth < MAX_LINK_NESTED_DEPTH {
                     if let Some(rel_path) = link.link_type.relative_path(path) {
-                        replaced.push_str(&replace_all(&new_content, rel_path, source, depth + 1));
+                        replaced.push_str(&replace_all(
+                            &new_content,
+                            rel_path,
+                            source,
+                            depth + 1,
+                            keep_xref_escapes,
+                        ));
                     } else {
                         replaced.push_str(&new_content);
                     }
@@ -99,6 +131,32 @@ where
     replaced
 }
 
+/// Every file read by the `{{#include}}` and `{{#playpen}}` helpers in
+/// `content`, including those in the files they include, resolved against
+/// `base` like `replace_all()` does.
+pub(crate) fn included_files(content: &str, base: &Path) -> Vec<PathBuf> {
+    let mut files = Vec::new();
+    collect_included_files(content, base, 0, &mut files);
+    files
+}
+
+fn collect_included_files(content: &str, base: &Path, depth: usize, files: &mut Vec<PathBuf>) {
+    for link in find_links(content) {
+        let target = match link.link_type.path() {
+            Some(path) => base.join(path),
+            None => continue,
+        };
+
+        if depth < MAX_LINK_NESTED_DEPTH {
+            if let Ok(included) = link.render_with_path(base) {
+                let dir = target.parent().expect("Included file should not be /");
+                collect_included_files(&included, dir, depth + 1, files);
+            }
+        }
+        files.push(target);
+    }
+}
+
 #[derive(PartialEq, Debug, Clone)]
 enum LinkType<'a> {
     Escaped,
@@ -111,6 +169,18 @@ enum LinkType<'a> {
 }
 
 impl<'a> LinkType<'a> {
+    fn path(&self) -> Option<&Path> {
+        match *self {
+            LinkType::Escaped => None,
+            LinkType::IncludeRange(ref p, _)
+            | LinkType::IncludeRangeFrom(ref p, _)
+            | LinkType::IncludeRangeTo(ref p, _)
+            | LinkType::IncludeRangeFull(ref p, _)
+            | LinkType::IncludeAnchor(ref p, _)
+            | LinkType::Playpen(ref p, _) => Some(p.as_path()),
+        }
+    }
+
     fn relative_path<P: AsRef<Path>>(self, base: P) -> Option<PathBuf> {
         let base = base.as_ref();
         match self {
@@ -354,7 +424,14 @@ mod tests {
         ```hbs
         {{#include file.rs}} << an escaped link!
         ```";
-        assert_eq!(replace_all(start, "", "", 0), end);
+        assert_eq!(replace_all(start, "", "", 0, false), end);
+    }
+
+    #[test]
+    fn test_replace_all_keeps_escaped_cross_references() {
+        let start = r"\{{#include file.rs}} \{{#ref some-label}}";
+        let end = r"{{#include file.rs}} \{{#ref some-label}}";
+        assert_eq!(replace_all(start, "", "", 0, true), end);
     }
 
     #[test]
@@ -614,4 +691,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn included_files_are_found_through_nested_includes() {
+        let temp = tempfile::Builder::new().prefix("book").tempdir().unwrap();
+        let listings = temp.path().join("listings");
+        fs::create_dir(&listings).unwrap();
+        fs::write(listings.join("outer.md"), "{{#include inner.rs}}").unwrap();
+        fs::write(listings.join("inner.rs"), "fn main() {}").unwrap();
+
+        let content = "{{#include listings/outer.md}} and \\{{#include escaped.rs}}";
+        let got = included_files(content, temp.path());
+
+        assert_eq!(
+            got,
+            vec![
+                listings.join("inner.rs"),
+                temp.path().join("listings/outer.md")
+            ]
+        );
+    }
 }
@@ -1,12 +1,18 @@
 //! Book preprocessing.
 
+pub use self::cache::PreprocessorCache;
 pub use self::cmd::CmdPreprocessor;
+pub use self::gettext::GettextPreprocessor;
 pub use self::index::IndexPreprocessor;
 pub use self::links::LinkPreprocessor;
+pub use self::xref::CrossRefPreprocessor;
 
+mod cache;
 mod cmd;
+pub(crate) mod gettext;
 mod index;
-mod links;
+pub(crate) mod links;
+mod xref;
 
 use crate::book::Book;
 use crate::config::Config;
@@ -26,6 +32,14 @@ pub struct PreprocessorContext {
     pub renderer: String,
     /// The calling `mdbook` version.
     pub mdbook_version: String,
+    /// Where preprocessors can keep results between builds, if anywhere.
+    #[serde(default)]
+    pub cache: Option<PreprocessorCache>,
+    /// Whether the book only holds the chapters which changed since the last
+    /// build, as when `mdbook serve` or `mdbook watch` rebuilds a book. This
+    /// is only ever the case for preprocessors which said they support it.
+    #[serde(default)]
+    pub partial: bool,
     #[serde(skip)]
     __non_exhaustive: (),
 }
@@ -38,9 +52,17 @@ impl PreprocessorContext {
             config,
             renderer,
             mdbook_version: crate::MDBOOK_VERSION.to_string(),
+            cache: None,
+            partial: false,
             __non_exhaustive: (),
         }
     }
+
+    /// Let preprocessors keep their results in `cache`.
+    pub(crate) fn with_cache(mut self, cache: PreprocessorCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
 }
 
 /// An operation which is run immediately after loading a book into memory and
@@ -60,4 +82,12 @@ pub trait Preprocessor {
     fn supports_renderer(&self, _renderer: &str) -> bool {
         true
     }
+
+    /// Whether this preprocessor can be given just the chapters which changed
+    /// since the last build, rather than the whole book.
+    ///
+    /// By default, returns `false`.
+    fn supports_partial_books(&self) -> bool {
+        false
+    }
 }
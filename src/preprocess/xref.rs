@@ -0,0 +1,524 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::{Component, Path, PathBuf};
+
+use pulldown_cmark::{html, Event, Tag};
+use regex::{CaptureMatches, Regex};
+
+use super::{Preprocessor, PreprocessorContext};
+use crate::book::{Book, BookItem, Chapter, SectionNumber};
+use crate::errors::*;
+use crate::utils;
+
+/// A preprocessor for cross-referencing sections by label, using the
+/// `{{#label name}}` and `{{#ref name}}` helpers.
+///
+/// A label belongs to the closest heading above it (or on the same line). If
+/// there is no heading above it, it refers to the whole chapter. Each
+/// reference is replaced with a markdown link to the labelled section, using
+/// the heading's text, or with `{{#ref name number}}`, the chapter's section
+/// number.
+#[derive(Default)]
+pub struct CrossRefPreprocessor;
+
+impl CrossRefPreprocessor {
+    pub(crate) const NAME: &'static str = "xref";
+
+    /// Create a new `CrossRefPreprocessor`.
+    pub fn new() -> Self {
+        CrossRefPreprocessor
+    }
+}
+
+impl Preprocessor for CrossRefPreprocessor {
+    fn name(&self) -> &str {
+        Self::NAME
+    }
+
+    fn run(&self, ctx: &PreprocessorContext, mut book: Book) -> Result<Book> {
+        // A reference can point to any chapter, and a changed chapter can
+        // change what references elsewhere should say
+        ensure!(
+            !ctx.partial,
+            "Cross-references can only be resolved in the whole book"
+        );
+
+        let mut problems = Vec::new();
+        let labels = collect_labels(&book, &mut problems);
+
+        book.for_each_mut(|section: &mut BookItem| {
+            if let BookItem::Chapter(ref mut ch) = *section {
+                if let Some(ref chapter_path) = ch.path {
+                    ch.content = replace_all(&ch.content, chapter_path, &labels, &mut problems);
+                }
+            }
+        });
+
+        if !problems.is_empty() {
+            bail!(
+                "Unable to resolve cross-references:\n{}",
+                problems.join("\n")
+            );
+        }
+
+        Ok(book)
+    }
+}
+
+/// The place a label points to.
+#[derive(Debug, Clone, PartialEq)]
+struct Target {
+    chapter: PathBuf,
+    /// The heading's id, or `None` for the top of the chapter.
+    anchor: Option<String>,
+    /// The heading's text, as markdown.
+    text: String,
+    number: Option<SectionNumber>,
+}
+
+fn collect_labels(book: &Book, problems: &mut Vec<String>) -> HashMap<String, Target> {
+    let mut labels: HashMap<String, Target> = HashMap::new();
+
+    for item in book.iter() {
+        let (ch, chapter_path) = match *item {
+            BookItem::Chapter(ref ch) => match ch.path {
+                Some(ref path) => (ch, path),
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        for (name, target) in labels_in(ch, chapter_path) {
+            if let Some(existing) = labels.get(name) {
+                problems.push(format!(
+                    "{}: the label `{}` is already defined in {}",
+                    chapter_path.display(),
+                    name,
+                    existing.chapter.display()
+                ));
+                continue;
+            }
+
+            labels.insert(name.to_string(), target);
+        }
+    }
+
+    labels
+}
+
+/// Find the labels in a chapter, along with what each of them points to.
+fn labels_in<'a>(ch: &'a Chapter, chapter_path: &Path) -> Vec<(&'a str, Target)> {
+    // Labels are removed before the headings are looked at, so one on the
+    // same line as a heading doesn't end up in its text
+    let mut stripped = String::with_capacity(ch.content.len());
+    let mut positions = Vec::new();
+    let mut previous_end_index = 0;
+
+    for directive in find_directives(&ch.content) {
+        if let DirectiveType::Label(name) = directive.directive_type {
+            stripped.push_str(&ch.content[previous_end_index..directive.range.start]);
+            positions.push((name, stripped.len()));
+            previous_end_index = directive.range.end;
+        }
+    }
+    stripped.push_str(&ch.content[previous_end_index..]);
+
+    let headings = headings_in(&stripped);
+
+    positions
+        .into_iter()
+        .map(|(name, position)| {
+            let heading = headings.iter().rev().find(|h| h.start <= position);
+            let target = Target {
+                chapter: chapter_path.to_path_buf(),
+                anchor: heading.map(|h| h.id.clone()),
+                text: heading.map_or_else(|| ch.name.clone(), |h| h.text.clone()),
+                number: ch.number.clone(),
+            };
+            (name, target)
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Heading {
+    /// Where the heading starts in the chapter's content.
+    start: usize,
+    text: String,
+    /// The id the HTML renderer will give the heading.
+    id: String,
+}
+
+fn headings_in(content: &str) -> Vec<Heading> {
+    let mut headings = Vec::new();
+    let mut id_counter = HashMap::new();
+    // The heading's start, its inner events, and where its text is
+    let mut current: Option<(usize, Vec<Event<'_>>, Option<Range<usize>>)> = None;
+
+    for (event, range) in utils::new_cmark_parser(content).into_offset_iter() {
+        match event {
+            Event::Start(Tag::Header(_)) => current = Some((range.start, Vec::new(), None)),
+            Event::End(Tag::Header(_)) => {
+                let (start, events, text_range) = match current.take() {
+                    Some(heading) => heading,
+                    None => continue,
+                };
+
+                let mut inner_html = String::new();
+                html::push_html(&mut inner_html, events.into_iter());
+
+                let raw_id = utils::id_from_content(&inner_html);
+                let id_count = id_counter.entry(raw_id.clone()).or_insert(0);
+                let id = match *id_count {
+                    0 => raw_id,
+                    other => format!("{}-{}", raw_id, other),
+                };
+                *id_count += 1;
+
+                let text = text_range.map_or("", |r| content[r].trim());
+                headings.push(Heading {
+                    start,
+                    text: text.to_string(),
+                    id,
+                });
+            }
+            other => {
+                if let Some((_, ref mut events, ref mut text_range)) = current {
+                    events.push(other);
+                    *text_range = Some(match text_range.take() {
+                        Some(r) => r.start.min(range.start)..r.end.max(range.end),
+                        None => range,
+                    });
+                }
+            }
+        }
+    }
+
+    headings
+}
+
+fn replace_all(
+    content: &str,
+    chapter_path: &Path,
+    labels: &HashMap<String, Target>,
+    problems: &mut Vec<String>,
+) -> String {
+    let mut previous_end_index = 0;
+    let mut replaced = String::with_capacity(content.len());
+
+    for directive in find_directives(content) {
+        replaced.push_str(&content[previous_end_index..directive.range.start]);
+        previous_end_index = directive.range.end;
+
+        match directive.directive_type {
+            // omit the escape char
+            DirectiveType::Escaped => replaced.push_str(&directive.text[1..]),
+            DirectiveType::Label(_) => {}
+            DirectiveType::Ref { label, number } => {
+                match render_ref(chapter_path, label, number, labels) {
+                    Ok(link) => replaced.push_str(&link),
+                    Err(problem) => {
+                        problems.push(format!("{}: {}", chapter_path.display(), problem));
+                        replaced.push_str(directive.text);
+                    }
+                }
+            }
+            DirectiveType::Invalid => {
+                problems.push(format!(
+                    "{}: unable to understand `{}`",
+                    chapter_path.display(),
+                    directive.text
+                ));
+                replaced.push_str(directive.text);
+            }
+        }
+    }
+
+    replaced.push_str(&content[previous_end_index..]);
+    replaced
+}
+
+fn render_ref(
+    chapter_path: &Path,
+    label: &str,
+    number: bool,
+    labels: &HashMap<String, Target>,
+) -> ::std::result::Result<String, String> {
+    let target = labels
+        .get(label)
+        .ok_or_else(|| format!("the label `{}` isn't defined", label))?;
+
+    let text = if number {
+        match target.number {
+            Some(ref number) => number.to_string(),
+            None => {
+                return Err(format!(
+                    "the label `{}` is in a chapter without a section number",
+                    label
+                ))
+            }
+        }
+    } else {
+        target.text.clone()
+    };
+
+    let mut href = if target.chapter == chapter_path && target.anchor.is_some() {
+        String::new()
+    } else {
+        relative_link(chapter_path, &target.chapter)
+    };
+    if let Some(ref anchor) = target.anchor {
+        href.push('#');
+        href.push_str(anchor);
+    }
+
+    Ok(format!("[{}]({})", text, href))
+}
+
+/// A link from one chapter to another, relative to the first one's
+/// directory.
+fn relative_link(from: &Path, to: &Path) -> String {
+    fn parts(path: &Path) -> Vec<String> {
+        path.components()
+            .filter_map(|c| match c {
+                Component::Normal(part) => Some(part.to_string_lossy().into_owned()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    let from_dir = parts(from.parent().unwrap_or_else(|| Path::new("")));
+    let to = parts(to);
+    let common = from_dir
+        .iter()
+        .zip(&to)
+        .take_while(|&(a, b)| a == b)
+        .count();
+
+    let mut link: Vec<String> = from_dir[common..]
+        .iter()
+        .map(|_| "..".to_string())
+        .collect();
+    link.extend(to[common..].iter().cloned());
+
+    link.join("/").replace(' ', "%20")
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum DirectiveType<'a> {
+    Escaped,
+    Label(&'a str),
+    Ref { label: &'a str, number: bool },
+    Invalid,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Directive<'a> {
+    range: Range<usize>,
+    directive_type: DirectiveType<'a>,
+    text: &'a str,
+}
+
+struct DirectiveIter<'a>(CaptureMatches<'a, 'a>);
+
+impl<'a> Iterator for DirectiveIter<'a> {
+    type Item = Directive<'a>;
+
+    fn next(&mut self) -> Option<Directive<'a>> {
+        let cap = self.0.next()?;
+        let mat = cap.get(0).expect("The whole match is always present");
+
+        let directive_type = match (cap.get(1), cap.get(2)) {
+            (Some(typ), Some(args)) => {
+                let args: Vec<&str> = args.as_str().split_whitespace().collect();
+                match (typ.as_str(), &args[..]) {
+                    ("label", &[name]) => DirectiveType::Label(name),
+                    ("ref", &[label]) => DirectiveType::Ref {
+                        label,
+                        number: false,
+                    },
+                    ("ref", &[label, "number"]) => DirectiveType::Ref {
+                        label,
+                        number: true,
+                    },
+                    _ => DirectiveType::Invalid,
+                }
+            }
+            _ => DirectiveType::Escaped,
+        };
+
+        Some(Directive {
+            range: mat.start()..mat.end(),
+            directive_type,
+            text: mat.as_str(),
+        })
+    }
+}
+
+fn find_directives(contents: &str) -> DirectiveIter<'_> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(
+            r"(?x)                       # insignificant whitespace mode
+            \\\{\{\s*\#(?:label|ref)\s[^}]*\}\}  # match escaped directive
+            |                            # or
+            \{\{\s*                      # opening parens and whitespace
+            \#(label|ref)                # directive type
+            \s+                          # separating whitespace
+            ([^}]*?)                     # label and options
+            \s*\}\}                      # whitespace and closing parens"
+        )
+        .unwrap();
+    }
+    DirectiveIter(RE.captures_iter(contents))
+}
+
+/// Does this escaped `{{#...}}` snippet belong to the cross-reference
+/// preprocessor?
+pub(crate) fn is_escaped_directive(text: &str) -> bool {
+    match find_directives(text).next() {
+        Some(directive) => {
+            directive.directive_type == DirectiveType::Escaped && directive.range.start == 0
+        }
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chapter(name: &str, content: &str, path: &str, number: Option<Vec<u32>>) -> Chapter {
+        let mut ch = Chapter::new(name, content.to_string(), path, Vec::new());
+        ch.number = number.map(SectionNumber);
+        ch
+    }
+
+    fn run(book: Book) -> Result<Book> {
+        let ctx =
+            PreprocessorContext::new(PathBuf::from("/"), Default::default(), String::from("html"));
+        CrossRefPreprocessor::new().run(&ctx, book)
+    }
+
+    fn contents(book: &Book) -> Vec<String> {
+        book.iter()
+            .filter_map(|item| match *item {
+                BookItem::Chapter(ref ch) => Some(ch.content.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn references_become_links_to_the_labelled_heading() {
+        let mut book = Book::new();
+        book.push_item(chapter(
+            "Ownership",
+            "# Ownership\n\n## What is `Ownership`? {{#label what-is}}\n\n\
+             text\n\n## Rules\n{{#label rules}}\n\nSee {{#ref what-is}}.",
+            "ch04/ownership.md",
+            Some(vec![4, 1]),
+        ));
+        book.push_item(chapter(
+            "Intro",
+            "{{#label intro}}\nRead {{#ref rules}} and {{ #ref rules number }}.\n\
+             Or {{#ref intro}}.",
+            "intro.md",
+            None,
+        ));
+
+        let got = contents(&run(book).unwrap());
+
+        assert_eq!(
+            got[0],
+            "# Ownership\n\n## What is `Ownership`? \n\ntext\n\n## Rules\n\n\n\
+             See [What is `Ownership`?](#what-is-ownership)."
+        );
+        assert_eq!(
+            got[1],
+            "\nRead [Rules](ch04/ownership.md#rules) and [4.1.](ch04/ownership.md#rules).\n\
+             Or [Intro](intro.md)."
+        );
+    }
+
+    #[test]
+    fn duplicate_headings_get_the_same_ids_as_in_the_html() {
+        let headings = headings_in("# Foo\n\n## Foo\n\nBar\n===\n");
+
+        let got: Vec<(&str, &str)> = headings
+            .iter()
+            .map(|h| (h.text.as_str(), h.id.as_str()))
+            .collect();
+        assert_eq!(got, vec![("Foo", "foo"), ("Foo", "foo-1"), ("Bar", "bar")]);
+    }
+
+    #[test]
+    fn escaped_directives_are_left_alone() {
+        let mut book = Book::new();
+        book.push_item(chapter(
+            "Docs",
+            "Write \\{{#label name}} and \\{{#ref name}}.",
+            "docs.md",
+            None,
+        ));
+
+        let got = contents(&run(book).unwrap());
+
+        assert_eq!(got[0], "Write {{#label name}} and {{#ref name}}.");
+        assert!(is_escaped_directive("\\{{#ref name}}"));
+        assert!(!is_escaped_directive("\\{{#include file.rs}}"));
+    }
+
+    #[test]
+    fn undefined_and_duplicate_labels_are_errors() {
+        let mut book = Book::new();
+        book.push_item(chapter(
+            "First",
+            "# First {{#label dup}}\n{{#ref missing}}",
+            "first.md",
+            None,
+        ));
+        book.push_item(chapter(
+            "Second",
+            "# Second {{#label dup}}\n{{#ref dup number}} {{#ref dup bogus}}",
+            "second.md",
+            None,
+        ));
+
+        let err = run(book).unwrap_err().to_string();
+
+        assert!(err.contains("second.md: the label `dup` is already defined in first.md"));
+        assert!(err.contains("first.md: the label `missing` isn't defined"));
+        assert!(err.contains("second.md: the label `dup` is in a chapter without a section number"));
+        assert!(err.contains("second.md: unable to understand `{{#ref dup bogus}}`"));
+    }
+
+    #[test]
+    fn partial_books_are_refused() {
+        let mut book = Book::new();
+        book.push_item(chapter(
+            "First",
+            "# First {{#label first}}",
+            "first.md",
+            None,
+        ));
+        let mut ctx =
+            PreprocessorContext::new(PathBuf::from("/"), Default::default(), String::from("html"));
+        ctx.partial = true;
+
+        assert!(CrossRefPreprocessor::new().run(&ctx, book).is_err());
+    }
+
+    #[test]
+    fn links_are_relative_to_the_referring_chapter() {
+        let tests = [
+            ("intro.md", "a/b.md", "a/b.md"),
+            ("a/b.md", "a/c.md", "c.md"),
+            ("a/b/c.md", "a/d/e.md", "../d/e.md"),
+            ("a/b.md", "my chapter.md", "../my%20chapter.md"),
+        ];
+
+        for &(from, to, should_be) in &tests {
+            assert_eq!(relative_link(Path::new(from), Path::new(to)), should_be);
+        }
+    }
+}
@@ -0,0 +1,537 @@
+//! Helpers shared by the renderers which package the whole book up as a single
+//! document, such as the EPUB and print renderers.
+
+use crate::book::{Book, BookItem, Chapter};
+use crate::config::Config;
+use crate::utils;
+
+use regex::{Captures, Regex};
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+/// The book's title, or an empty string if it doesn't have one.
+pub(crate) fn title(config: &Config) -> &str {
+    match config.book.title {
+        Some(ref title) => title,
+        None => "",
+    }
+}
+
+/// The book's language, defaulting to English.
+pub(crate) fn language(config: &Config) -> &str {
+    match config.book.language {
+        Some(ref language) => language,
+        None => "en",
+    }
+}
+
+/// A file name (without extension) for the generated document, derived from
+/// the book's title.
+pub(crate) fn file_stem(config: &Config) -> String {
+    let stem: String = title(config)
+        .trim()
+        .chars()
+        .filter_map(|c| match c {
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            c if c.is_whitespace() => Some('-'),
+            _ => None,
+        })
+        .collect();
+
+    if stem.is_empty() {
+        String::from("book")
+    } else {
+        stem
+    }
+}
+
+/// Escape text so it can be embedded in HTML or XML.
+pub(crate) fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// The media type of the images, fonts and stylesheets we know how to bundle
+/// with a document.
+pub(crate) fn media_type(path: &Path) -> Option<&'static str> {
+    let extension = path.extension()?.to_str()?.to_lowercase();
+
+    let media_type = match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "webp" => "image/webp",
+        "css" => "text/css",
+        "ttf" => "font/ttf",
+        "otf" => "font/otf",
+        "woff" => "font/woff",
+        "woff2" => "font/woff2",
+        _ => return None,
+    };
+
+    Some(media_type)
+}
+
+/// A single line in the table of contents.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TocEntry {
+    pub depth: usize,
+    pub label: String,
+    pub href: Option<String>,
+}
+
+/// Flatten the book into the entries of its table of contents, remembering
+/// how deeply each one is nested. Chapters following a part title are nested
+/// underneath it.
+///
+/// `href` is used to work out where each (non-draft) chapter can be found.
+pub(crate) fn toc_entries<F>(book: &Book, href: F) -> Vec<TocEntry>
+where
+    F: Fn(&Chapter) -> String,
+{
+    let mut entries = Vec::new();
+    let mut in_part = false;
+
+    for item in book.iter() {
+        match *item {
+            BookItem::PartTitle(ref title) => {
+                in_part = true;
+                entries.push(TocEntry {
+                    depth: 0,
+                    label: title.clone(),
+                    href: None,
+                });
+            }
+            BookItem::Chapter(ref ch) => {
+                let label = match ch.number {
+                    Some(ref number) => format!("{} {}", number, ch.name),
+                    None => {
+                        // unnumbered chapters after the parts are suffix chapters
+                        in_part = false;
+                        ch.name.clone()
+                    }
+                };
+                let nesting = ch.number.as_ref().map(|n| n.len().max(1) - 1).unwrap_or(0);
+
+                entries.push(TocEntry {
+                    depth: nesting + usize::from(in_part),
+                    label,
+                    href: ch.path.as_ref().map(|_| href(ch)),
+                });
+            }
+            BookItem::Separator => {}
+        }
+    }
+
+    entries
+}
+
+/// Turn the flattened entries back into nested `<ol>` lists.
+///
+/// Entries without a link (part titles and draft chapters) are only shown
+/// when they have children, so they are dropped otherwise.
+pub(crate) fn toc_list(entries: &[TocEntry]) -> String {
+    let entries: Vec<&TocEntry> = entries
+        .iter()
+        .enumerate()
+        .filter(|&(i, entry)| {
+            entry.href.is_some()
+                || entries
+                    .get(i + 1)
+                    .map(|next| next.depth > entry.depth)
+                    .unwrap_or(false)
+        })
+        .map(|(_, entry)| entry)
+        .collect();
+
+    let mut html = String::from("<ol>\n");
+    let mut depth = 0;
+
+    for (i, entry) in entries.iter().enumerate() {
+        if i > 0 {
+            if entry.depth > depth {
+                html.push_str("\n<ol>\n");
+                depth += 1;
+            } else {
+                html.push_str("</li>\n");
+                while depth > entry.depth {
+                    html.push_str("</ol>\n</li>\n");
+                    depth -= 1;
+                }
+            }
+        }
+
+        match entry.href {
+            Some(ref href) => html.push_str(&format!(
+                "<li><a href=\"{}\">{}</a>",
+                escape(href),
+                escape(&entry.label)
+            )),
+            None => html.push_str(&format!("<li><span>{}</span>", escape(&entry.label))),
+        }
+    }
+
+    if !entries.is_empty() {
+        html.push_str("</li>\n");
+    }
+    while depth > 0 {
+        html.push_str("</ol>\n</li>\n");
+        depth -= 1;
+    }
+    html.push_str("</ol>");
+
+    html
+}
+
+/// Works out which anchor each chapter gets when the whole book is rendered
+/// as one page, so links between chapters can be turned into links within
+/// the page.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct ChapterAnchors {
+    anchors: HashMap<PathBuf, String>,
+}
+
+impl ChapterAnchors {
+    pub fn new(book: &Book) -> ChapterAnchors {
+        let mut anchors = HashMap::new();
+        let mut taken = HashSet::new();
+
+        for item in book.iter() {
+            let path = match *item {
+                BookItem::Chapter(Chapter {
+                    path: Some(ref path),
+                    ..
+                }) => path,
+                _ => continue,
+            };
+
+            let components: Vec<_> = path
+                .with_extension("")
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            let base = utils::normalize_id(&components.join("-"));
+
+            let mut anchor = base.clone();
+            let mut counter = 1;
+            while !taken.insert(anchor.clone()) {
+                anchor = format!("{}-{}", base, counter);
+                counter += 1;
+            }
+
+            anchors.insert(path.clone(), anchor);
+        }
+
+        ChapterAnchors { anchors }
+    }
+
+    /// The anchor for the chapter with this source path.
+    pub fn get(&self, path: &Path) -> Option<&str> {
+        self.anchors.get(path).map(|anchor| anchor.as_str())
+    }
+
+    /// Where a link found in the chapter at `chapter_path` should point to on
+    /// the single page. Returns `None` for links which don't point to a
+    /// chapter, such as external links.
+    pub fn fix_link(&self, chapter_path: &Path, dest: &str) -> Option<String> {
+        let (anchor, fragment) = self.link_target(chapter_path, dest)?;
+
+        match fragment {
+            Some(fragment) => Some(format!("#{}", header_id(anchor, fragment))),
+            None => Some(format!("#{}", anchor)),
+        }
+    }
+
+    /// The anchor of the chapter which a link found in the chapter at
+    /// `chapter_path` points to, and the link's fragment if it has one.
+    /// Returns `None` for links which don't point to a chapter.
+    pub fn link_target<'a>(
+        &self,
+        chapter_path: &Path,
+        dest: &'a str,
+    ) -> Option<(&str, Option<&'a str>)> {
+        lazy_static! {
+            static ref SCHEME_LINK: Regex = Regex::new(r"^[a-z][a-z0-9+.-]*:").unwrap();
+        }
+
+        if SCHEME_LINK.is_match(dest) {
+            return None;
+        }
+
+        let mut parts = dest.splitn(2, '#');
+        let file = parts.next().unwrap_or("");
+        let fragment = parts.next().filter(|fragment| !fragment.is_empty());
+
+        let anchor = if file.is_empty() {
+            self.get(chapter_path)?
+        } else if file.ends_with(".md") && !file.starts_with('/') {
+            let parent = chapter_path.parent().unwrap_or_else(|| Path::new(""));
+            self.get(&normalize_path(&parent.join(file)))?
+        } else {
+            return None;
+        };
+
+        Some((anchor, fragment))
+    }
+}
+
+/// Resolve any `.` and `..` components in a relative path.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            Component::Normal(part) => normalized.push(part),
+            _ => {}
+        }
+    }
+
+    normalized
+}
+
+/// The id of a header in the chapter with this anchor, on a page which holds
+/// several chapters. Neither anchors nor header ids ever contain a `.`, so it
+/// can't be the same as another chapter's anchor or header id.
+pub(crate) fn header_id(anchor: &str, id: &str) -> String {
+    format!("{}.{}", anchor, id)
+}
+
+/// Give every header in a chapter's HTML an id, prefixed with the chapter's
+/// anchor so headers from different chapters can't collide.
+pub(crate) fn insert_header_ids(html: &str, anchor: &str) -> String {
+    lazy_static! {
+        static ref HEADER: Regex = Regex::new(r"<h(\d)>(.*?)</h\d>").unwrap();
+    }
+    let mut id_counter = HashMap::new();
+
+    HEADER
+        .replace_all(html, |caps: &Captures<'_>| {
+            let raw_id = utils::id_from_content(&caps[2]);
+            let id_count = id_counter.entry(raw_id.clone()).or_insert(0);
+            let id = match *id_count {
+                0 => raw_id,
+                other => format!("{}-{}", raw_id, other),
+            };
+            *id_count += 1;
+
+            format!(
+                r#"<h{level} id="{id}">{text}</h{level}>"#,
+                level = &caps[1],
+                id = header_id(anchor, &id),
+                text = &caps[2]
+            )
+        })
+        .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::book::SectionNumber;
+
+    fn chapter(name: &str, number: &[u32], path: Option<&str>) -> Chapter {
+        let mut ch = match path {
+            Some(path) => Chapter::new(name, String::new(), path, Vec::new()),
+            None => Chapter::new_draft(name, Vec::new()),
+        };
+        if !number.is_empty() {
+            ch.number = Some(SectionNumber(number.to_vec()));
+        }
+        ch
+    }
+
+    fn html_href(ch: &Chapter) -> String {
+        let path = ch.path.as_ref().unwrap();
+        path.with_extension("html").display().to_string()
+    }
+
+    #[test]
+    fn special_characters_are_escaped() {
+        assert_eq!(
+            escape(r#"<Tom & "Jerry's">"#),
+            "&lt;Tom &amp; &quot;Jerry&#39;s&quot;&gt;"
+        );
+    }
+
+    #[test]
+    fn the_file_name_is_derived_from_the_title() {
+        let mut config = Config::default();
+        assert_eq!(file_stem(&config), "book");
+
+        config.book.title = Some(String::from(" The Rust/Book: 2nd Edition "));
+        assert_eq!(file_stem(&config), "The-RustBook-2nd-Edition");
+    }
+
+    #[test]
+    fn nested_chapters_become_nested_lists() {
+        let mut nested = chapter("Nested", &[1, 1], Some("first/nested.md"));
+        nested.content = String::from("# Nested");
+        let mut first = chapter("First", &[1], Some("first/index.md"));
+        first.sub_items.push(BookItem::Chapter(nested));
+
+        let mut book = Book::new();
+        book.sections = vec![
+            BookItem::Chapter(chapter("Intro", &[], Some("intro.md"))),
+            BookItem::Chapter(first),
+            BookItem::Separator,
+            BookItem::Chapter(chapter("Second", &[2], Some("second.md"))),
+        ];
+
+        let should_be = "<ol>\n\
+                         <li><a href=\"intro.html\">Intro</a></li>\n\
+                         <li><a href=\"first/index.html\">1. First</a>\n\
+                         <ol>\n\
+                         <li><a href=\"first/nested.html\">1.1. Nested</a></li>\n\
+                         </ol>\n\
+                         </li>\n\
+                         <li><a href=\"second.html\">2. Second</a></li>\n\
+                         </ol>";
+
+        assert_eq!(toc_list(&toc_entries(&book, html_href)), should_be);
+    }
+
+    #[test]
+    fn chapters_are_grouped_under_part_titles() {
+        let mut book = Book::new();
+        book.sections = vec![
+            BookItem::PartTitle(String::from("Part I")),
+            BookItem::Chapter(chapter("First", &[1], Some("first.md"))),
+            BookItem::PartTitle(String::from("Empty Part")),
+            BookItem::PartTitle(String::from("Part II")),
+            BookItem::Chapter(chapter("Second", &[2], Some("second.md"))),
+            BookItem::Chapter(chapter("Appendix", &[], Some("appendix.md"))),
+        ];
+
+        let should_be = "<ol>\n\
+                         <li><span>Part I</span>\n\
+                         <ol>\n\
+                         <li><a href=\"first.html\">1. First</a></li>\n\
+                         </ol>\n\
+                         </li>\n\
+                         <li><span>Part II</span>\n\
+                         <ol>\n\
+                         <li><a href=\"second.html\">2. Second</a></li>\n\
+                         </ol>\n\
+                         </li>\n\
+                         <li><a href=\"appendix.html\">Appendix</a></li>\n\
+                         </ol>";
+
+        assert_eq!(toc_list(&toc_entries(&book, html_href)), should_be);
+    }
+
+    #[test]
+    fn draft_chapters_are_only_listed_when_they_have_children() {
+        let mut draft_with_children = chapter("Planned", &[2], None);
+        draft_with_children
+            .sub_items
+            .push(BookItem::Chapter(chapter(
+                "Written",
+                &[2, 1],
+                Some("written.md"),
+            )));
+
+        let mut book = Book::new();
+        book.sections = vec![
+            BookItem::Chapter(chapter("Later", &[1], None)),
+            BookItem::Chapter(draft_with_children),
+        ];
+
+        let should_be = "<ol>\n\
+                         <li><span>2. Planned</span>\n\
+                         <ol>\n\
+                         <li><a href=\"written.html\">2.1. Written</a></li>\n\
+                         </ol>\n\
+                         </li>\n\
+                         </ol>";
+
+        assert_eq!(toc_list(&toc_entries(&book, html_href)), should_be);
+    }
+
+    #[test]
+    fn links_between_chapters_become_links_to_anchors() {
+        let mut nested = chapter("Nested", &[1, 1], Some("first/nested.md"));
+        nested.content = String::from("# Nested");
+        let mut first = chapter("First", &[1], Some("first/index.md"));
+        first.sub_items.push(BookItem::Chapter(nested));
+
+        let mut book = Book::new();
+        book.sections = vec![
+            BookItem::Chapter(first),
+            BookItem::Chapter(chapter("Second", &[2], Some("second.md"))),
+        ];
+        let anchors = ChapterAnchors::new(&book);
+        let nested = Path::new("first/nested.md");
+
+        let fixes = vec![
+            ("#intro", Some("#first-nested.intro")),
+            ("index.md", Some("#first-index")),
+            ("../second.md#some-section", Some("#second.some-section")),
+            ("./index.md#", Some("#first-index")),
+            ("missing.md", None),
+            ("https://example.com/foo.md", None),
+            ("../images/picture.png", None),
+        ];
+
+        for (dest, should_be) in fixes {
+            assert_eq!(
+                anchors.fix_link(nested, dest),
+                should_be.map(String::from),
+                "{}",
+                dest
+            );
+        }
+    }
+
+    #[test]
+    fn duplicate_chapter_anchors_are_made_unique() {
+        let mut book = Book::new();
+        book.sections = vec![
+            BookItem::Chapter(chapter("One", &[1], Some("foo/bar.md"))),
+            BookItem::Chapter(chapter("Two", &[2], Some("foo-bar.md"))),
+        ];
+        let anchors = ChapterAnchors::new(&book);
+
+        assert_eq!(anchors.get(Path::new("foo/bar.md")), Some("foo-bar"));
+        assert_eq!(anchors.get(Path::new("foo-bar.md")), Some("foo-bar-1"));
+    }
+
+    #[test]
+    fn header_ids_cant_collide_with_chapter_anchors() {
+        let mut book = Book::new();
+        book.sections = vec![
+            BookItem::Chapter(chapter("Foo", &[1], Some("foo.md"))),
+            BookItem::Chapter(chapter("Bar", &[2], Some("foo/bar.md"))),
+        ];
+        let anchors = ChapterAnchors::new(&book);
+
+        let html = insert_header_ids("<h2>Bar</h2>", anchors.get(Path::new("foo.md")).unwrap());
+
+        assert_eq!(html, "<h2 id=\"foo.bar\">Bar</h2>");
+        assert_eq!(anchors.get(Path::new("foo/bar.md")), Some("foo-bar"));
+    }
+
+    #[test]
+    fn header_ids_are_prefixed_with_the_chapter_anchor() {
+        let html = "<h1>Intro</h1>\n<p>Text</p>\n<h2>Intro</h2>";
+        let should_be =
+            "<h1 id=\"ch.intro\">Intro</h1>\n<p>Text</p>\n<h2 id=\"ch.intro-1\">Intro</h2>";
+
+        assert_eq!(insert_header_ids(html, "ch"), should_be);
+    }
+}
@@ -0,0 +1,569 @@
+//! A renderer which packages the book up as an EPUB 3 publication.
+
+mod zip;
+
+use self::zip::ZipWriter;
+use crate::book::{Book, BookItem, Chapter};
+use crate::config::{Config, EpubConfig};
+use crate::errors::*;
+use crate::renderer::document::{
+    self, escape, file_stem, language, media_type, title, ChapterAnchors,
+};
+use crate::renderer::{RenderContext, Renderer};
+use crate::theme::Theme;
+use crate::utils;
+
+use chrono::{DateTime, TimeZone, Utc};
+use regex::{Captures, Regex};
+use std::env;
+use std::fs::{self, File};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// Every file belonging to the publication lives in this directory inside the
+/// archive.
+const CONTENT_DIR: &str = "OEBPS";
+const STYLESHEET: &str = "stylesheet.css";
+const NAV_DOCUMENT: &str = "nav.xhtml";
+
+/// The built-in EPUB renderer.
+#[derive(Default)]
+pub struct EpubRenderer;
+
+impl EpubRenderer {
+    /// Create a new `EpubRenderer`.
+    pub fn new() -> Self {
+        EpubRenderer
+    }
+}
+
+impl Renderer for EpubRenderer {
+    fn name(&self) -> &str {
+        "epub"
+    }
+
+    fn render(&self, ctx: &RenderContext) -> Result<()> {
+        let epub_config: EpubConfig = ctx
+            .config
+            .get_deserialized("output.epub")
+            .unwrap_or_default();
+        let src_dir = ctx.root.join(&ctx.config.book.src);
+        let theme_dir = ctx
+            .config
+            .html_config()
+            .unwrap_or_default()
+            .theme_dir(&ctx.root);
+        let theme = Theme::new(theme_dir);
+
+        let chapters: Vec<&Chapter> = ctx
+            .book
+            .iter()
+            .filter_map(|item| match *item {
+                BookItem::Chapter(ref ch) if !ch.is_draft_chapter() => Some(ch),
+                _ => None,
+            })
+            .collect();
+        let resources = find_resources(&src_dir, &ctx.destination)?;
+        let anchors = ChapterAnchors::new(&ctx.book);
+        let modified = modified_time(&src_dir, &ctx.destination)?;
+
+        let mut zip = ZipWriter::new(Vec::new());
+        // The mimetype must be the very first entry in the archive
+        zip.add_file("mimetype", b"application/epub+zip")?;
+        zip.add_file("META-INF/container.xml", container_xml().as_bytes())?;
+        zip.add_file(
+            &content_path("content.opf"),
+            content_opf(&ctx.config, &epub_config, &chapters, &resources, modified).as_bytes(),
+        )?;
+        zip.add_file(
+            &content_path(NAV_DOCUMENT),
+            nav_document(&ctx.config, &ctx.book).as_bytes(),
+        )?;
+        zip.add_file(
+            &content_path(STYLESHEET),
+            &stylesheet(&theme, &epub_config, &ctx.root)?,
+        )?;
+
+        for ch in &chapters {
+            debug!("Rendering {} for the EPUB", ch.name);
+            let path = chapter_href(ch);
+            zip.add_file(
+                &content_path(&path),
+                chapter_document(&ctx.config, &epub_config, ch, &anchors).as_bytes(),
+            )?;
+        }
+
+        for resource in &resources {
+            let mut data = Vec::new();
+            File::open(src_dir.join(&resource.path))?.read_to_end(&mut data)?;
+            zip.add_file(&content_path(&resource.href), &data)?;
+        }
+
+        let archive = zip.finish()?;
+        let filename = format!("{}.epub", file_stem(&ctx.config));
+        utils::fs::write_file(&ctx.destination, &filename, &archive)
+            .chain_err(|| "Unable to write the EPUB")?;
+        debug!("Creating {} ✓", filename);
+
+        Ok(())
+    }
+}
+
+/// A non-markdown file from the source directory which gets copied into the
+/// publication.
+#[derive(Debug, Clone, PartialEq)]
+struct Resource {
+    /// The path relative to the source directory.
+    path: PathBuf,
+    /// The same path, using forward slashes.
+    href: String,
+    media_type: &'static str,
+}
+
+/// Look for images, fonts and stylesheets in the source directory. Anything
+/// which an EPUB reader won't understand is skipped.
+fn find_resources(src_dir: &Path, destination: &Path) -> Result<Vec<Resource>> {
+    fn walk(
+        dir: &Path,
+        src_dir: &Path,
+        destination: &Path,
+        found: &mut Vec<Resource>,
+    ) -> Result<()> {
+        let mut entries = fs::read_dir(dir)?.collect::<::std::io::Result<Vec<_>>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+
+        for entry in entries {
+            let path = entry.path();
+            let hidden = entry.file_name().to_string_lossy().starts_with('.');
+
+            if hidden || path == destination {
+                continue;
+            }
+
+            if path.is_dir() {
+                walk(&path, src_dir, destination, found)?;
+            } else if let Some(media_type) = media_type(&path) {
+                let relative = path.strip_prefix(src_dir).expect("Always inside src_dir");
+                let href = relative
+                    .components()
+                    .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                    .collect::<Vec<_>>()
+                    .join("/");
+
+                found.push(Resource {
+                    path: relative.to_path_buf(),
+                    href,
+                    media_type,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    let mut found = Vec::new();
+    if src_dir.is_dir() {
+        walk(src_dir, src_dir, destination, &mut found)?;
+    }
+
+    Ok(found)
+}
+
+fn content_path(href: &str) -> String {
+    format!("{}/{}", CONTENT_DIR, href)
+}
+
+/// Where a chapter ends up inside the publication. We keep the `.html`
+/// extension so links between chapters keep working.
+fn chapter_href(ch: &Chapter) -> String {
+    let path = ch.path.as_ref().expect("Draft chapters are never rendered");
+
+    path.with_extension("html")
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn container_xml() -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<container version="1.0" xmlns="urn:oasis:names:tc:opendocument:xmlns:container">
+  <rootfiles>
+    <rootfile full-path="{}" media-type="application/oebps-package+xml"/>
+  </rootfiles>
+</container>
+"#,
+        content_path("content.opf")
+    )
+}
+
+fn content_opf(
+    config: &Config,
+    epub_config: &EpubConfig,
+    chapters: &[&Chapter],
+    resources: &[Resource],
+    modified: DateTime<Utc>,
+) -> String {
+    let title = title(config);
+    let identifier = match epub_config.identifier {
+        Some(ref id) => id.clone(),
+        None => format!("urn:mdbook:{}", file_stem(config)),
+    };
+
+    let mut metadata = String::new();
+    metadata.push_str(&format!(
+        "    <dc:identifier id=\"book-id\">{}</dc:identifier>\n",
+        escape(&identifier)
+    ));
+    metadata.push_str(&format!("    <dc:title>{}</dc:title>\n", escape(title)));
+    metadata.push_str(&format!(
+        "    <dc:language>{}</dc:language>\n",
+        escape(language(config))
+    ));
+    for author in &config.book.authors {
+        metadata.push_str(&format!(
+            "    <dc:creator>{}</dc:creator>\n",
+            escape(author)
+        ));
+    }
+    if let Some(ref description) = config.book.description {
+        metadata.push_str(&format!(
+            "    <dc:description>{}</dc:description>\n",
+            escape(description)
+        ));
+    }
+    metadata.push_str(&format!(
+        "    <meta property=\"dcterms:modified\">{}</meta>\n",
+        modified.format("%Y-%m-%dT%H:%M:%SZ")
+    ));
+
+    let mut manifest = String::new();
+    manifest.push_str(&format!(
+        "    <item id=\"nav\" href=\"{}\" media-type=\"application/xhtml+xml\" properties=\"nav\"/>\n",
+        NAV_DOCUMENT
+    ));
+    manifest.push_str(&format!(
+        "    <item id=\"stylesheet\" href=\"{}\" media-type=\"text/css\"/>\n",
+        STYLESHEET
+    ));
+    let mut spine = String::new();
+    for (i, ch) in chapters.iter().enumerate() {
+        manifest.push_str(&format!(
+            "    <item id=\"chapter-{}\" href=\"{}\" media-type=\"application/xhtml+xml\"/>\n",
+            i,
+            escape(&chapter_href(ch))
+        ));
+        spine.push_str(&format!("    <itemref idref=\"chapter-{}\"/>\n", i));
+    }
+    for (i, resource) in resources.iter().enumerate() {
+        manifest.push_str(&format!(
+            "    <item id=\"resource-{}\" href=\"{}\" media-type=\"{}\"/>\n",
+            i,
+            escape(&resource.href),
+            resource.media_type
+        ));
+    }
+
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<package xmlns="http://www.idpf.org/2007/opf" version="3.0" unique-identifier="book-id" xml:lang="{lang}">
+  <metadata xmlns:dc="http://purl.org/dc/elements/1.1/">
+{metadata}  </metadata>
+  <manifest>
+{manifest}  </manifest>
+  <spine>
+{spine}  </spine>
+</package>
+"#,
+        lang = escape(language(config)),
+        metadata = metadata,
+        manifest = manifest,
+        spine = spine
+    )
+}
+
+fn nav_document(config: &Config, book: &Book) -> String {
+    let title = title(config);
+
+    xhtml_document(
+        config,
+        title,
+        STYLESHEET,
+        &format!(
+            "<nav epub:type=\"toc\" id=\"toc\">\n<h1>{}</h1>\n{}\n</nav>",
+            escape(title),
+            document::toc_list(&document::toc_entries(book, chapter_href))
+        ),
+    )
+}
+
+/// Render a chapter as its own XHTML document. Header ids are prefixed with
+/// the chapter's anchor, as on the print page, and links to headers are
+/// changed to match.
+fn chapter_document(
+    config: &Config,
+    epub_config: &EpubConfig,
+    ch: &Chapter,
+    anchors: &ChapterAnchors,
+) -> String {
+    let path = ch.path.as_ref().expect("Draft chapters are never rendered");
+    let stylesheet = format!("{}{}", utils::fs::path_to_root(path), STYLESHEET);
+    let anchor = anchors.get(path).expect("Every chapter has an anchor");
+    let content = utils::render_markdown_with_link_fixer(
+        &ch.content,
+        epub_config.curly_quotes,
+        None,
+        |dest| fix_chapter_link(anchors, path, dest),
+    );
+    let content = document::insert_header_ids(&content, anchor);
+
+    xhtml_document(config, &ch.name, &stylesheet, &to_xhtml(&content))
+}
+
+/// Point a link to another chapter at the right header of its document.
+/// Returns `None` for links which don't point to a chapter, or which don't
+/// need changing.
+fn fix_chapter_link(anchors: &ChapterAnchors, chapter_path: &Path, dest: &str) -> Option<String> {
+    let (anchor, fragment) = anchors.link_target(chapter_path, dest)?;
+    let file = dest.splitn(2, '#').next().unwrap_or("");
+    let href = if file.is_empty() {
+        String::new()
+    } else {
+        format!("{}.html", &file[..file.len() - ".md".len()])
+    };
+
+    match fragment {
+        Some(fragment) => Some(format!(
+            "{}#{}",
+            href,
+            document::header_id(anchor, fragment)
+        )),
+        None if !href.is_empty() => Some(href),
+        None => None,
+    }
+}
+
+/// Make the HTML of a chapter well-formed enough for an XHTML document. Named
+/// character references which XML doesn't know are replaced by numeric ones
+/// (or escaped, if we don't know them either), and void elements in raw HTML
+/// are closed.
+fn to_xhtml(html: &str) -> String {
+    lazy_static! {
+        static ref ENTITY: Regex = Regex::new(r"&([A-Za-z][A-Za-z0-9]*);").unwrap();
+        static ref VOID_ELEMENT: Regex = Regex::new(
+            r"(?i)<(area|base|br|col|embed|hr|img|input|link|meta|param|source|track|wbr)\b([^>]*?)\s*/?>"
+        )
+        .unwrap();
+    }
+
+    let html = ENTITY.replace_all(html, |caps: &Captures<'_>| {
+        let name = &caps[1];
+        match name {
+            "amp" | "lt" | "gt" | "quot" | "apos" => caps[0].to_string(),
+            _ => match entity_code_point(name) {
+                Some(code) => format!("&#{};", code),
+                None => format!("&amp;{};", name),
+            },
+        }
+    });
+
+    VOID_ELEMENT
+        .replace_all(&html, |caps: &Captures<'_>| {
+            format!("<{}{} />", &caps[1], &caps[2])
+        })
+        .into_owned()
+}
+
+/// The code points of the named character references people tend to write
+/// by hand.
+fn entity_code_point(name: &str) -> Option<u32> {
+    let code = match name {
+        "nbsp" => 160,
+        "iexcl" => 161,
+        "cent" => 162,
+        "pound" => 163,
+        "yen" => 165,
+        "sect" => 167,
+        "copy" => 169,
+        "laquo" => 171,
+        "shy" => 173,
+        "reg" => 174,
+        "deg" => 176,
+        "plusmn" => 177,
+        "micro" => 181,
+        "para" => 182,
+        "middot" => 183,
+        "raquo" => 187,
+        "frac12" => 189,
+        "iquest" => 191,
+        "times" => 215,
+        "divide" => 247,
+        "ensp" => 8194,
+        "emsp" => 8195,
+        "thinsp" => 8201,
+        "zwnj" => 8204,
+        "zwj" => 8205,
+        "ndash" => 8211,
+        "mdash" => 8212,
+        "lsquo" => 8216,
+        "rsquo" => 8217,
+        "sbquo" => 8218,
+        "ldquo" => 8220,
+        "rdquo" => 8221,
+        "bdquo" => 8222,
+        "dagger" => 8224,
+        "Dagger" => 8225,
+        "bull" => 8226,
+        "hellip" => 8230,
+        "prime" => 8242,
+        "lsaquo" => 8249,
+        "rsaquo" => 8250,
+        "euro" => 8364,
+        "trade" => 8482,
+        "larr" => 8592,
+        "uarr" => 8593,
+        "rarr" => 8594,
+        "darr" => 8595,
+        "harr" => 8596,
+        "lArr" => 8656,
+        "rArr" => 8658,
+        "hArr" => 8660,
+        "minus" => 8722,
+        "infin" => 8734,
+        "ne" => 8800,
+        "le" => 8804,
+        "ge" => 8805,
+        _ => return None,
+    };
+
+    Some(code)
+}
+
+/// When the book was last modified, for the publication's metadata. This is
+/// `SOURCE_DATE_EPOCH` if it is set, so builds can be reproduced, or else the
+/// modification time of the newest file in the source directory.
+fn modified_time(src_dir: &Path, destination: &Path) -> Result<DateTime<Utc>> {
+    if let Ok(epoch) = env::var("SOURCE_DATE_EPOCH") {
+        let seconds = epoch
+            .trim()
+            .parse()
+            .chain_err(|| format!("SOURCE_DATE_EPOCH isn't a timestamp: {}", epoch))?;
+        return Ok(Utc.timestamp(seconds, 0));
+    }
+
+    fn newest(dir: &Path, destination: &Path, time: &mut Option<DateTime<Utc>>) -> Result<()> {
+        for entry in fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if entry.file_name().to_string_lossy().starts_with('.') || path == destination {
+                continue;
+            }
+
+            let metadata = entry.metadata()?;
+            if metadata.is_dir() {
+                newest(&path, destination, time)?;
+            } else {
+                let modified = DateTime::<Utc>::from(metadata.modified()?);
+                if time.map_or(true, |time| modified > time) {
+                    *time = Some(modified);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    let mut time = None;
+    if src_dir.is_dir() {
+        newest(src_dir, destination, &mut time)?;
+    }
+
+    Ok(time.unwrap_or_else(|| Utc.timestamp(0, 0)))
+}
+
+fn xhtml_document(config: &Config, title: &str, stylesheet: &str, body: &str) -> String {
+    format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE html>
+<html xmlns="http://www.w3.org/1999/xhtml" xmlns:epub="http://www.idpf.org/2007/ops" lang="{lang}" xml:lang="{lang}">
+<head>
+<meta charset="UTF-8"/>
+<title>{title}</title>
+<link rel="stylesheet" type="text/css" href="{stylesheet}"/>
+</head>
+<body>
+{body}
+</body>
+</html>
+"#,
+        lang = escape(language(config)),
+        title = escape(title),
+        stylesheet = escape(stylesheet),
+        body = body
+    )
+}
+
+/// The theme's `css/epub.css` followed by any additional stylesheets.
+fn stylesheet(theme: &Theme, epub_config: &EpubConfig, root: &Path) -> Result<Vec<u8>> {
+    let mut css = theme.epub_css.clone();
+
+    for path in &epub_config.additional_css {
+        let mut file = File::open(root.join(path))
+            .chain_err(|| format!("Unable to open additional CSS file {}", path.display()))?;
+        css.push(b'\n');
+        file.read_to_end(&mut css)?;
+    }
+
+    Ok(css)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links_to_headers_in_other_chapters_use_their_prefixed_ids() {
+        let mut book = Book::new();
+        book.sections = vec![
+            BookItem::Chapter(Chapter::new(
+                "First",
+                String::new(),
+                "first/index.md",
+                vec![],
+            )),
+            BookItem::Chapter(Chapter::new("Second", String::new(), "second.md", vec![])),
+        ];
+        let anchors = ChapterAnchors::new(&book);
+        let first = Path::new("first/index.md");
+
+        let fixes = vec![
+            ("#intro", Some("#first-index.intro")),
+            (
+                "../second.md#some-section",
+                Some("../second.html#second.some-section"),
+            ),
+            ("../second.md", Some("../second.html")),
+            ("missing.md#intro", None),
+            ("https://example.com/foo.md#intro", None),
+        ];
+
+        for (dest, should_be) in fixes {
+            assert_eq!(
+                fix_chapter_link(&anchors, first, dest),
+                should_be.map(String::from),
+                "{}",
+                dest
+            );
+        }
+    }
+
+    #[test]
+    fn raw_html_is_made_well_formed() {
+        let html = "<p>A&nbsp;B &amp; C&unknown; &#8212;</p>\n<br><img src=\"a.png\">\n<hr />";
+        let should_be =
+            "<p>A&#160;B &amp; C&amp;unknown; &#8212;</p>\n<br /><img src=\"a.png\" />\n<hr />";
+
+        assert_eq!(to_xhtml(html), should_be);
+    }
+}
@@ -0,0 +1,202 @@
+//! A tiny writer for uncompressed ("stored") ZIP archives.
+//!
+//! An EPUB is just a ZIP archive with a couple of extra rules, the most
+//! important one being that the `mimetype` file must be the first entry and
+//! must not be compressed. Storing every entry uncompressed is always valid,
+//! so we don't need to pull in a full compression library.
+
+use std::io::Write;
+
+use crate::errors::*;
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+/// ZIP version 2.0, the minimum needed to extract a stored file.
+const VERSION: u16 = 20;
+/// Bit 11 of the general purpose flags, meaning file names are UTF-8.
+const UTF8_NAMES: u16 = 1 << 11;
+/// The DOS representation of 1980-01-01 00:00, so archives are reproducible.
+const DOS_DATE: u16 = (1 << 5) | 1;
+const DOS_TIME: u16 = 0;
+
+lazy_static! {
+    static ref CRC_TABLE: [u32; 256] = {
+        let mut table = [0; 256];
+
+        for (i, entry) in table.iter_mut().enumerate() {
+            let mut crc = i as u32;
+            for _ in 0..8 {
+                crc = if crc & 1 == 1 {
+                    0xEDB8_8320 ^ (crc >> 1)
+                } else {
+                    crc >> 1
+                };
+            }
+            *entry = crc;
+        }
+
+        table
+    };
+}
+
+/// Calculate the CRC-32 checksum ZIP uses to detect corrupted entries.
+fn crc32(data: &[u8]) -> u32 {
+    let crc = data.iter().fold(0xFFFF_FFFF, |crc, &byte| {
+        CRC_TABLE[((crc ^ u32::from(byte)) & 0xFF) as usize] ^ (crc >> 8)
+    });
+
+    !crc
+}
+
+/// The bits of an entry we need to remember for the central directory.
+struct Entry {
+    name: String,
+    crc: u32,
+    size: u32,
+    offset: u32,
+}
+
+/// Writes files to a ZIP archive in the order they are added.
+pub struct ZipWriter<W: Write> {
+    inner: W,
+    entries: Vec<Entry>,
+    offset: u32,
+}
+
+impl<W: Write> ZipWriter<W> {
+    /// Start a new archive which will be written to `inner`.
+    pub fn new(inner: W) -> ZipWriter<W> {
+        ZipWriter {
+            inner,
+            entries: Vec::new(),
+            offset: 0,
+        }
+    }
+
+    /// Add a file to the archive.
+    pub fn add_file(&mut self, name: &str, data: &[u8]) -> Result<()> {
+        if data.len() > u32::max_value() as usize {
+            bail!("\"{}\" is too big to be added to a ZIP archive", name);
+        }
+
+        let entry = Entry {
+            name: name.to_string(),
+            crc: crc32(data),
+            size: data.len() as u32,
+            offset: self.offset,
+        };
+
+        let mut header = Vec::with_capacity(30 + name.len());
+        push_u32(&mut header, LOCAL_FILE_HEADER_SIGNATURE);
+        push_u16(&mut header, VERSION);
+        push_u16(&mut header, UTF8_NAMES);
+        push_u16(&mut header, 0); // stored, no compression
+        push_u16(&mut header, DOS_TIME);
+        push_u16(&mut header, DOS_DATE);
+        push_u32(&mut header, entry.crc);
+        push_u32(&mut header, entry.size); // compressed size
+        push_u32(&mut header, entry.size); // uncompressed size
+        push_u16(&mut header, name.len() as u16);
+        push_u16(&mut header, 0); // extra field length
+        header.extend_from_slice(name.as_bytes());
+
+        self.write(&header)?;
+        self.write(data)?;
+        self.entries.push(entry);
+
+        Ok(())
+    }
+
+    /// Write the central directory, finishing the archive.
+    pub fn finish(mut self) -> Result<W> {
+        let central_directory_offset = self.offset;
+        let mut directory = Vec::new();
+
+        for entry in &self.entries {
+            push_u32(&mut directory, CENTRAL_DIRECTORY_SIGNATURE);
+            push_u16(&mut directory, VERSION); // version made by
+            push_u16(&mut directory, VERSION); // version needed to extract
+            push_u16(&mut directory, UTF8_NAMES);
+            push_u16(&mut directory, 0); // stored, no compression
+            push_u16(&mut directory, DOS_TIME);
+            push_u16(&mut directory, DOS_DATE);
+            push_u32(&mut directory, entry.crc);
+            push_u32(&mut directory, entry.size);
+            push_u32(&mut directory, entry.size);
+            push_u16(&mut directory, entry.name.len() as u16);
+            push_u16(&mut directory, 0); // extra field length
+            push_u16(&mut directory, 0); // file comment length
+            push_u16(&mut directory, 0); // disk number start
+            push_u16(&mut directory, 0); // internal file attributes
+            push_u32(&mut directory, 0); // external file attributes
+            push_u32(&mut directory, entry.offset);
+            directory.extend_from_slice(entry.name.as_bytes());
+        }
+
+        let num_entries = self.entries.len() as u16;
+        let directory_size = directory.len() as u32;
+
+        push_u32(&mut directory, END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        push_u16(&mut directory, 0); // number of this disk
+        push_u16(&mut directory, 0); // disk where the central directory starts
+        push_u16(&mut directory, num_entries); // entries on this disk
+        push_u16(&mut directory, num_entries); // total entries
+        push_u32(&mut directory, directory_size);
+        push_u32(&mut directory, central_directory_offset);
+        push_u16(&mut directory, 0); // comment length
+
+        self.write(&directory)?;
+
+        Ok(self.inner)
+    }
+
+    fn write(&mut self, data: &[u8]) -> Result<()> {
+        self.inner.write_all(data)?;
+        self.offset = self
+            .offset
+            .checked_add(data.len() as u32)
+            .chain_err(|| "The ZIP archive is too big")?;
+
+        Ok(())
+    }
+}
+
+fn push_u16(buffer: &mut Vec<u8>, value: u16) {
+    buffer.push(value as u8);
+    buffer.push((value >> 8) as u8);
+}
+
+fn push_u32(buffer: &mut Vec<u8>, value: u32) {
+    push_u16(buffer, value as u16);
+    push_u16(buffer, (value >> 16) as u16);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc32_matches_the_reference_check_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(crc32(b""), 0);
+    }
+
+    #[test]
+    fn stored_entries_are_written_verbatim() {
+        let mut zip = ZipWriter::new(Vec::new());
+        zip.add_file("mimetype", b"application/epub+zip").unwrap();
+        zip.add_file("OEBPS/a.html", b"<p>Hello</p>").unwrap();
+        let archive = zip.finish().unwrap();
+
+        // the first entry's name and data start straight after the header
+        assert_eq!(&archive[..4], b"PK\x03\x04");
+        assert_eq!(&archive[30..38], b"mimetype");
+        assert_eq!(&archive[38..58], b"application/epub+zip");
+
+        // the end of central directory record says there are 2 entries
+        let end = &archive[archive.len() - 22..];
+        assert_eq!(&end[..4], b"PK\x05\x06");
+        assert_eq!(&end[10..12], &[2, 0]);
+    }
+}
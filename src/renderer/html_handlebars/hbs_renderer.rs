@@ -1,109 +1,202 @@
-use crate::book::{Book, BookItem};
+use crate::book::{Book, BookItem, Chapter};
 use crate::config::{Config, HtmlConfig, Playpen};
 use crate::errors::*;
+use crate::renderer::document::{escape, header_id, ChapterAnchors};
 use crate::renderer::html_handlebars::helpers;
-use crate::renderer::{RenderContext, Renderer};
+use crate::renderer::{Changes, RenderContext, Renderer};
 use crate::theme::{self, playpen_editor, Theme};
 use crate::utils;
 
 use std::collections::BTreeMap;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 
 use handlebars::Handlebars;
+use pulldown_cmark::{Event, Tag};
+use rayon::prelude::*;
 use regex::{Captures, Regex};
 
 #[derive(Default)]
-pub struct HtmlHandlebars;
+pub struct HtmlHandlebars {
+    /// Parts of the last render which can be reused for chapters that didn't
+    /// change.
+    cache: Mutex<RenderCache>,
+}
+
+#[derive(Default)]
+struct RenderCache {
+    /// Each chapter's section of the print page.
+    print_content: HashMap<PathBuf, String>,
+    #[cfg(feature = "search")]
+    search: super::search::SearchCache,
+}
 
 impl HtmlHandlebars {
     pub fn new() -> Self {
-        HtmlHandlebars
+        HtmlHandlebars::default()
     }
 
     fn render_item(
         &self,
-        item: &BookItem,
-        mut ctx: RenderItemContext<'_>,
-        print_content: &mut String,
+        ch: &Chapter,
+        chapter_path: &Path,
+        ctx: &RenderItemContext<'_>,
     ) -> Result<()> {
-        // FIXME: This should be made DRY-er and rely less on mutable state
-        if let BookItem::Chapter(ref ch) = *item {
-            let content = ch.content.clone();
-            let content = utils::render_markdown(&content, ctx.html_config.curly_quotes);
-
-            let fixed_content = utils::render_markdown_with_path(
-                &ch.content,
-                ctx.html_config.curly_quotes,
-                Some(&ch.path),
-            );
-            print_content.push_str(&fixed_content);
-
-            // Update the context with data for this file
-            let path = ch
-                .path
-                .to_str()
-                .chain_err(|| "Could not convert path to str")?;
-            let filepath = Path::new(&ch.path).with_extension("html");
-
-            // "print.html" is used for the print page.
-            if ch.path == Path::new("print.md") {
-                bail!(ErrorKind::ReservedFilenameError(ch.path.clone()));
-            };
+        let content = utils::render_markdown(&ch.content, ctx.html_config.curly_quotes);
 
-            // Non-lexical lifetimes needed :'(
-            let title: String;
-            {
-                let book_title = ctx
-                    .data
-                    .get("book_title")
-                    .and_then(serde_json::Value::as_str)
-                    .unwrap_or("");
-                title = ch.name.clone() + " - " + book_title;
-            }
+        // Update the context with data for this file
+        let path = chapter_path
+            .to_str()
+            .chain_err(|| "Could not convert path to str")?;
+        let filepath = chapter_path.with_extension("html");
 
-            ctx.data.insert("path".to_owned(), json!(path));
-            ctx.data.insert("content".to_owned(), json!(content));
-            ctx.data.insert("chapter_title".to_owned(), json!(ch.name));
-            ctx.data.insert("title".to_owned(), json!(title));
-            ctx.data.insert(
-                "path_to_root".to_owned(),
-                json!(utils::fs::path_to_root(&ch.path)),
-            );
+        // "print.html" is used for the print page.
+        if chapter_path == Path::new("print.md") {
+            bail!(ErrorKind::ReservedFilenameError(chapter_path.to_path_buf()));
+        };
 
-            // Render the handlebars template with the data
-            debug!("Render template");
-            let rendered = ctx.handlebars.render("index", &ctx.data)?;
+        let book_title = ctx
+            .data
+            .get("book_title")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("");
+        let title = ch.name.clone() + " - " + book_title;
+
+        let description = chapter_description(ch);
+        let page_url = ctx.site_url.map(|site_url| page_url(site_url, &filepath));
+
+        let mut data = ChapterData {
+            book: ctx.data,
+            path,
+            content: &content,
+            chapter_title: &ch.name,
+            chapter_meta: &ch.meta,
+            title: &title,
+            description: description.as_ref().map(String::as_str),
+            page_url: page_url.as_ref().map(String::as_str),
+            path_to_root: utils::fs::path_to_root(chapter_path),
+        };
 
-            let rendered = self.post_process(rendered, &ctx.html_config.playpen);
+        // Render the handlebars template with the data
+        debug!("Render template");
+        let rendered = ctx.handlebars.render("index", &data)?;
 
-            // Write to file
-            debug!("Creating {}", filepath.display());
-            utils::fs::write_file(&ctx.destination, &filepath, rendered.as_bytes())?;
+        let rendered = self.post_process(rendered, &ctx.html_config.playpen);
 
-            if ctx.is_index {
-                ctx.data.insert("path".to_owned(), json!("index.md"));
-                ctx.data.insert("path_to_root".to_owned(), json!(""));
-                let rendered_index = ctx.handlebars.render("index", &ctx.data)?;
-                let rendered_index = self.post_process(rendered_index, &ctx.html_config.playpen);
-                debug!("Creating index.html from {}", path);
-                utils::fs::write_file(&ctx.destination, "index.html", rendered_index.as_bytes())?;
-            }
+        // Write to file
+        debug!("Creating {}", filepath.display());
+        utils::fs::write_file(ctx.destination, &filepath, rendered.as_bytes())?;
+
+        if ctx.is_index {
+            data.path = "index.md";
+            data.path_to_root = String::new();
+            let rendered_index = ctx.handlebars.render("index", &data)?;
+            let rendered_index = self.post_process(rendered_index, &ctx.html_config.playpen);
+            debug!("Creating index.html from {}", path);
+            utils::fs::write_file(ctx.destination, "index.html", rendered_index.as_bytes())?;
         }
 
         Ok(())
     }
 
+    /// Render a chapter's section of the print page. Each chapter lives under
+    /// its own anchor, and links between chapters point to those anchors.
+    fn render_print_item(
+        &self,
+        ch: &Chapter,
+        chapter_path: &Path,
+        chapter_anchors: &ChapterAnchors,
+        html_config: &HtmlConfig,
+    ) -> String {
+        let anchor = chapter_anchors
+            .get(chapter_path)
+            .expect("Every chapter has an anchor");
+        let content = utils::render_markdown_with_link_fixer(
+            &ch.content,
+            html_config.curly_quotes,
+            Some(chapter_path),
+            |dest| chapter_anchors.fix_link(chapter_path, dest),
+        );
+        let content = build_header_links(&content, Some(anchor));
+
+        format!("<div id=\"{}\">{}</div>", anchor, content)
+    }
+
+    /// Render the page shown for missing files, from `output.html.input-404`
+    /// or else a built-in default. It can be shown at any URL, so everything
+    /// it links to is given from the root of the site.
+    fn render_404(
+        &self,
+        ctx: &RenderContext,
+        src_dir: &Path,
+        item_ctx: &RenderItemContext<'_>,
+    ) -> Result<()> {
+        let html_config = item_ctx.html_config;
+        let input_404 = html_config
+            .input_404
+            .as_ref()
+            .map(String::as_str)
+            .unwrap_or("404.md");
+        let file_404 = src_dir.join(input_404);
+        let content_404 = if file_404.is_file() {
+            fs::read_to_string(&file_404)
+                .chain_err(|| format!("Unable to read {}", file_404.display()))?
+        } else {
+            if html_config.input_404.is_some() {
+                warn!(
+                    "The 404 page {} doesn't exist, using the default one",
+                    file_404.display()
+                );
+            }
+            DEFAULT_404.to_string()
+        };
+        let content = utils::render_markdown(&content_404, html_config.curly_quotes);
+
+        let book_title = item_ctx
+            .data
+            .get("book_title")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or("");
+        let title = format!("Page not found - {}", book_title);
+
+        let data = ChapterData {
+            book: item_ctx.data,
+            path: "404.md",
+            content: &content,
+            chapter_title: "Page not found",
+            chapter_meta: &BTreeMap::new(),
+            title: &title,
+            description: None,
+            page_url: None,
+            path_to_root: site_path(&ctx.config, html_config),
+        };
+
+        let rendered = item_ctx.handlebars.render("index", &data)?;
+        let rendered = self.post_process(rendered, &html_config.playpen);
+
+        utils::fs::write_file(item_ctx.destination, "404.html", rendered.as_bytes())?;
+        debug!("Creating 404.html ✓");
+        Ok(())
+    }
+
     #[cfg_attr(feature = "cargo-clippy", allow(clippy::let_and_return))]
     fn post_process(&self, rendered: String, playpen_config: &Playpen) -> String {
-        let rendered = build_header_links(&rendered);
+        let rendered = build_header_links(&rendered, None);
         let rendered = fix_code_blocks(&rendered);
         let rendered = add_playpen_pre(&rendered, playpen_config);
 
         rendered
     }
 
+    /// Like `post_process`, but for the print page, whose headers were
+    /// already given links chapter by chapter.
+    fn post_process_print(&self, rendered: String, playpen_config: &Playpen) -> String {
+        let rendered = fix_code_blocks(&rendered);
+        add_playpen_pre(&rendered, playpen_config)
+    }
+
     fn copy_static_files(
         &self,
         destination: &Path,
@@ -277,6 +370,23 @@ impl Renderer for HtmlHandlebars {
     }
 
     fn render(&self, ctx: &RenderContext) -> Result<()> {
+        self.render_book(ctx, None)
+    }
+
+    fn render_changes(&self, ctx: &RenderContext, changes: &Changes) -> Result<()> {
+        // The theme is used by every page
+        if changes.theme {
+            self.render(ctx)
+        } else {
+            self.render_book(ctx, Some(changes))
+        }
+    }
+}
+
+impl HtmlHandlebars {
+    /// Render the book, or when given some `changes`, only the parts of it
+    /// which are affected by them.
+    fn render_book(&self, ctx: &RenderContext, changes: Option<&Changes>) -> Result<()> {
         let html_config = ctx.config.html_config().unwrap_or_default();
         let src_dir = ctx.root.join(&ctx.config.book.src);
         let destination = &ctx.destination;
@@ -313,25 +423,95 @@ impl Renderer for HtmlHandlebars {
 
         let mut data = make_data(&ctx.root, &book, &ctx.config, &html_config)?;
 
+        let mut cache = self
+            .cache
+            .lock()
+            .expect("The render cache is never poisoned");
+        if changes.is_none() {
+            *cache = RenderCache::default();
+        }
+
         // Print version
         let mut print_content = String::new();
 
         fs::create_dir_all(&destination)
             .chain_err(|| "Unexpected error when constructing destination path")?;
 
-        let mut is_index = true;
+        let chapter_anchors = ChapterAnchors::new(&book);
+        let mut jobs = Vec::new();
         for item in book.iter() {
-            let ctx = RenderItemContext {
-                handlebars: &handlebars,
-                destination: destination.to_path_buf(),
-                data: data.clone(),
-                is_index,
-                html_config: html_config.clone(),
+            // Draft chapters don't have a backing file, so there is no page to
+            // generate for them
+            let (ch, chapter_path) = match *item {
+                BookItem::Chapter(ref ch) => match ch.path {
+                    Some(ref path) => (ch, path),
+                    None => continue,
+                },
+                _ => continue,
+            };
+
+            let changed = match changes {
+                Some(changes) => changes.chapters.contains(chapter_path),
+                None => true,
             };
-            self.render_item(item, ctx, &mut print_content)?;
-            is_index = false;
+
+            jobs.push(ChapterJob {
+                ch,
+                chapter_path,
+                // Only the first chapter with a backing file becomes the index page
+                is_index: jobs.is_empty(),
+                render_page: changed,
+                render_print: changed || !cache.print_content.contains_key(chapter_path),
+            });
         }
 
+        // Chapters are rendered in parallel, but their sections of the print
+        // page are collected in order so the output is always the same
+        let site_url = site_url(ctx, &html_config);
+        let item_ctx = RenderItemContext {
+            handlebars: &handlebars,
+            destination,
+            data: &data,
+            is_index: false,
+            html_config: &html_config,
+            site_url: site_url.as_ref().map(String::as_str),
+        };
+        let print_sections = jobs
+            .par_iter()
+            .map(|job| {
+                if job.render_page {
+                    let ctx = RenderItemContext {
+                        is_index: job.is_index,
+                        ..item_ctx
+                    };
+                    self.render_item(job.ch, job.chapter_path, &ctx)?;
+                }
+
+                if job.render_print {
+                    let content = self.render_print_item(
+                        job.ch,
+                        job.chapter_path,
+                        &chapter_anchors,
+                        &html_config,
+                    );
+                    Ok(Some(content))
+                } else {
+                    Ok(None)
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        for (job, section) in jobs.iter().zip(print_sections) {
+            if let Some(section) = section {
+                cache
+                    .print_content
+                    .insert(job.chapter_path.clone(), section);
+            }
+            print_content.push_str(&cache.print_content[job.chapter_path]);
+        }
+
+        self.render_404(ctx, &src_dir, &item_ctx)?;
+
         // Print version
         self.configure_print_version(&mut data, &print_content);
         if let Some(ref title) = ctx.config.book.title {
@@ -342,33 +522,243 @@ impl Renderer for HtmlHandlebars {
         debug!("Render template");
         let rendered = handlebars.render("index", &data)?;
 
-        let rendered = self.post_process(rendered, &html_config.playpen);
+        let rendered = self.post_process_print(rendered, &html_config.playpen);
 
         utils::fs::write_file(&destination, "print.html", rendered.as_bytes())?;
         debug!("Creating print.html ✓");
 
-        debug!("Copy static files");
-        self.copy_static_files(&destination, &theme, &html_config)
-            .chain_err(|| "Unable to copy across static files")?;
-        self.copy_additional_css_and_js(&html_config, &ctx.root, &destination)
-            .chain_err(|| "Unable to copy across additional CSS and JS")?;
+        if let Some(ref site_url) = site_url {
+            let sitemap = make_sitemap(site_url, &src_dir, &jobs);
+            utils::fs::write_file(&destination, "sitemap.xml", sitemap.as_bytes())?;
+            debug!("Creating sitemap.xml ✓");
+        }
+
+        if changes.is_none() {
+            debug!("Copy static files");
+            self.copy_static_files(&destination, &theme, &html_config)
+                .chain_err(|| "Unable to copy across static files")?;
+            self.copy_additional_css_and_js(&html_config, &ctx.root, &destination)
+                .chain_err(|| "Unable to copy across additional CSS and JS")?;
+        }
 
         // Render search index
         #[cfg(feature = "search")]
         {
             let search = html_config.search.unwrap_or_default();
             if search.enable {
-                super::search::create_files(&search, &destination, &book)?;
+                let changed_chapters = changes.map(|changes| &changes.chapters[..]);
+                super::search::create_files(
+                    &search,
+                    ctx.config.book.language.as_ref().map(String::as_str),
+                    &destination,
+                    &book,
+                    &mut cache.search,
+                    changed_chapters,
+                )?;
             }
         }
 
         // Copy all remaining files
-        utils::fs::copy_files_except_ext(&src_dir, &destination, true, &["md"])?;
+        match changes {
+            Some(changes) => copy_changed_files(&src_dir, &destination, &changes.files)?,
+            None => {
+                if ctx.config.book.multilingual {
+                    prepare_multilingual_output(ctx, &src_dir)?;
+                }
+                utils::fs::copy_files_except_ext(&src_dir, &destination, true, &["md"])?;
+                write_redirects(&destination, &html_config.redirect, &jobs)?;
+            }
+        }
 
         Ok(())
     }
 }
 
+/// The absolute URL of the output directory, from `output.html.site-url`.
+/// Each language of a multilingual book is in its own directory under it.
+/// `site-url` can also be just a path, and then there is no absolute URL.
+fn site_url(ctx: &RenderContext, html_config: &HtmlConfig) -> Option<String> {
+    let site_url = html_config.site_url.as_ref()?.trim_end_matches('/');
+    if !is_external_url(site_url) {
+        return None;
+    }
+    match ctx.config.book.language {
+        Some(ref language) if ctx.config.book.multilingual => {
+            Some(format!("{}/{}", site_url, language))
+        }
+        _ => Some(site_url.to_string()),
+    }
+}
+
+/// The path of the output directory on the web server. Each language of a
+/// multilingual book is in its own directory under the book's site path.
+fn site_path(config: &Config, html_config: &HtmlConfig) -> String {
+    let mut site_path = html_config.site_path();
+    if let Some(ref language) = config.book.language {
+        if config.book.multilingual {
+            site_path.push_str(language);
+            site_path.push('/');
+        }
+    }
+    site_path
+}
+
+/// Lists the page of every chapter for search engines. When a chapter's source
+/// file was last modified is given as the page's last modification.
+fn make_sitemap(site_url: &str, src_dir: &Path, jobs: &[ChapterJob<'_>]) -> String {
+    let mut sitemap = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+
+    for job in jobs {
+        let url = page_url(site_url, &job.chapter_path.with_extension("html"));
+        sitemap.push_str("  <url>\n");
+        sitemap.push_str(&format!("    <loc>{}</loc>\n", escape(&url)));
+        let modified = fs::metadata(src_dir.join(job.chapter_path)).and_then(|m| m.modified());
+        if let Ok(modified) = modified {
+            let modified = chrono::DateTime::<chrono::Utc>::from(modified);
+            sitemap.push_str(&format!(
+                "    <lastmod>{}</lastmod>\n",
+                modified.format("%Y-%m-%d")
+            ));
+        }
+        sitemap.push_str("  </url>\n");
+    }
+
+    sitemap.push_str("</urlset>\n");
+    sitemap
+}
+
+/// A page which sends the browser on to `url`.
+fn redirect_page(url: &str) -> String {
+    format!(
+        "<!DOCTYPE HTML>\n\
+         <html>\n\
+         <head>\n\
+         <meta charset=\"UTF-8\">\n\
+         <meta http-equiv=\"refresh\" content=\"0; url={0}\">\n\
+         <link rel=\"canonical\" href=\"{0}\">\n\
+         <title>Redirecting...</title>\n\
+         </head>\n\
+         <body>\n\
+         <p>Redirecting to <a href=\"{0}\">{0}</a>...</p>\n\
+         </body>\n\
+         </html>\n",
+        escape(url)
+    )
+}
+
+/// Writes a page at the old path of every page in `output.html.redirect`,
+/// which redirects to where it is now. Each destination inside the book has
+/// to exist, so they are checked once everything else is written.
+fn write_redirects(
+    destination: &Path,
+    redirects: &BTreeMap<String, String>,
+    jobs: &[ChapterJob<'_>],
+) -> Result<()> {
+    let pages: HashSet<PathBuf> = jobs
+        .iter()
+        .map(|job| job.chapter_path.with_extension("html"))
+        .chain(vec![
+            PathBuf::from("index.html"),
+            PathBuf::from("print.html"),
+        ])
+        .collect();
+
+    for (from, to) in redirects {
+        let from = from.trim_start_matches('/');
+        if pages.contains(Path::new(from)) {
+            bail!(
+                "Not redirecting from {} to {}, because {} is a page of the book",
+                from,
+                to,
+                from
+            );
+        }
+
+        let url = if is_external_url(to) {
+            to.clone()
+        } else {
+            let target = to.trim_start_matches('/');
+            let target_path = target.split(|c| c == '#' || c == '?').next().unwrap_or("");
+            let target_file = destination.join(target_path);
+            if !target_file.is_file() && !target_file.join("index.html").is_file() {
+                bail!(
+                    "Redirecting from {} to {}, which isn't part of the book",
+                    from,
+                    to
+                );
+            }
+            format!("{}{}", utils::fs::path_to_root(from), target)
+        };
+
+        debug!("Creating a redirect from {} to {}", from, url);
+        utils::fs::write_file(destination, from, redirect_page(&url).as_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// Does `url` point outside of the book, like `https://example.com/`?
+fn is_external_url(url: &str) -> bool {
+    url.starts_with("//") || url.contains("://")
+}
+
+/// Each language of a multilingual book is rendered to its own directory. The
+/// default language adds an `index.html` next to them which redirects to it,
+/// and translations get the default language's files first, because their
+/// untranslated chapters may use them.
+fn prepare_multilingual_output(ctx: &RenderContext, src_dir: &Path) -> Result<()> {
+    let (language, default) = match (&ctx.config.book.language, ctx.config.default_language()) {
+        (&Some(ref language), Some(default)) => (language, default),
+        _ => return Ok(()),
+    };
+
+    if *language == default {
+        if let Some(parent) = ctx.destination.parent() {
+            let redirect = redirect_page(&format!("{}/index.html", language));
+            utils::fs::write_file(parent, "index.html", redirect.as_bytes())?;
+        }
+    } else {
+        let default_src_dir = src_dir.with_file_name(default);
+        utils::fs::copy_files_except_ext(&default_src_dir, &ctx.destination, true, &["md"])?;
+    }
+
+    Ok(())
+}
+
+/// Copy across the non-markdown files which changed since the last render.
+fn copy_changed_files(src_dir: &Path, destination: &Path, files: &[PathBuf]) -> Result<()> {
+    for file in files {
+        let input_location = src_dir.join(file);
+        if !input_location.is_file() || file.extension().map_or(false, |ext| ext == "md") {
+            continue;
+        }
+
+        let output_location = destination.join(file);
+        if let Some(parent) = output_location.parent() {
+            fs::create_dir_all(parent)
+                .chain_err(|| format!("Unable to create {}", parent.display()))?;
+        }
+        debug!(
+            "Copying {} -> {}",
+            input_location.display(),
+            output_location.display()
+        );
+
+        fs::copy(&input_location, &output_location).chain_err(|| {
+            format!(
+                "Unable to copy {} to {}",
+                input_location.display(),
+                output_location.display()
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
 fn make_data(
     root: &Path,
     book: &Book,
@@ -392,6 +782,9 @@ fn make_data(
         json!(config.book.description.clone().unwrap_or_default()),
     );
     data.insert("favicon".to_owned(), json!("favicon.png"));
+    if html_config.site_url.is_some() {
+        data.insert("site_url".to_owned(), json!(site_path(config, html_config)));
+    }
     if let Some(ref livereload) = html_config.livereload_url {
         data.insert("livereload".to_owned(), json!(livereload));
     }
@@ -447,6 +840,14 @@ fn make_data(
             "search_js".to_owned(),
             json!(search.enable && search.copy_js),
         );
+        #[cfg(feature = "search")]
+        data.insert(
+            "search_language_js".to_owned(),
+            json!(super::search::language_scripts(
+                &search,
+                config.book.language.as_ref().map(String::as_str)
+            )),
+        );
     } else if search.is_some() {
         warn!("mdBook compiled without search support, ignoring `output.html.search` table");
         warn!(
@@ -455,6 +856,19 @@ fn make_data(
         )
     }
 
+    if config.book.multilingual {
+        let current = config.book.language.clone().unwrap_or_default();
+        let languages: Vec<_> = config
+            .languages()
+            .into_iter()
+            .map(|(code, language)| {
+                let name = language.name.unwrap_or_else(|| code.clone());
+                json!({ "code": code, "name": name, "current": code == current })
+            })
+            .collect();
+        data.insert("languages".to_owned(), json!(languages));
+    }
+
     if let Some(ref git_repository_url) = html_config.git_repository_url {
         data.insert("git_repository_url".to_owned(), json!(git_repository_url));
     }
@@ -477,15 +891,19 @@ fn make_data(
                 }
 
                 chapter.insert("name".to_owned(), json!(ch.name));
-                let path = ch
-                    .path
-                    .to_str()
-                    .chain_err(|| "Could not convert path to str")?;
-                chapter.insert("path".to_owned(), json!(path));
+                if let Some(ref path) = ch.path {
+                    let p = path
+                        .to_str()
+                        .chain_err(|| "Could not convert path to str")?;
+                    chapter.insert("path".to_owned(), json!(p));
+                }
             }
             BookItem::Separator => {
                 chapter.insert("spacer".to_owned(), json!("_spacer_"));
             }
+            BookItem::PartTitle(ref title) => {
+                chapter.insert("part".to_owned(), json!(title));
+            }
         }
 
         chapters.push(chapter);
@@ -499,7 +917,10 @@ fn make_data(
 
 /// Goes through the rendered HTML, making sure all header tags have
 /// an anchor respectively so people can link to sections directly.
-fn build_header_links(html: &str) -> String {
+///
+/// On the print page `id_prefix` is the chapter's anchor, so headers from
+/// different chapters don't collide.
+fn build_header_links(html: &str, id_prefix: Option<&str>) -> String {
     let regex = Regex::new(r"<h(\d)>(.*?)</h\d>").unwrap();
     let mut id_counter = HashMap::new();
 
@@ -509,7 +930,7 @@ fn build_header_links(html: &str) -> String {
                 .parse()
                 .expect("Regex should ensure we only ever get numbers here");
 
-            insert_link_into_header(level, &caps[2], &mut id_counter)
+            insert_link_into_header(level, &caps[2], &mut id_counter, id_prefix)
         })
         .into_owned()
 }
@@ -520,6 +941,7 @@ fn insert_link_into_header(
     level: usize,
     content: &str,
     id_counter: &mut HashMap<String, usize>,
+    id_prefix: Option<&str>,
 ) -> String {
     let raw_id = utils::id_from_content(content);
 
@@ -532,6 +954,11 @@ fn insert_link_into_header(
 
     *id_count += 1;
 
+    let id = match id_prefix {
+        Some(prefix) => header_id(prefix, &id),
+        None => id,
+    };
+
     format!(
         r##"<h{level}><a class="header" href="#{id}" id="{id}">{text}</a></h{level}>"##,
         level = level,
@@ -624,12 +1051,107 @@ fn partition_source(s: &str) -> (String, String) {
     (before, after)
 }
 
+#[derive(Copy, Clone)]
 struct RenderItemContext<'a> {
     handlebars: &'a Handlebars,
-    destination: PathBuf,
-    data: serde_json::Map<String, serde_json::Value>,
+    destination: &'a Path,
+    data: &'a serde_json::Map<String, serde_json::Value>,
     is_index: bool,
-    html_config: HtmlConfig,
+    html_config: &'a HtmlConfig,
+    site_url: Option<&'a str>,
+}
+
+/// A chapter to be rendered, and which of its outputs need updating.
+struct ChapterJob<'a> {
+    ch: &'a Chapter,
+    chapter_path: &'a PathBuf,
+    is_index: bool,
+    render_page: bool,
+    render_print: bool,
+}
+
+/// The data used to render a chapter's page. The values shared by the whole
+/// book are borrowed rather than copied for every chapter.
+#[derive(Serialize)]
+struct ChapterData<'a> {
+    #[serde(flatten)]
+    book: &'a serde_json::Map<String, serde_json::Value>,
+    path: &'a str,
+    content: &'a str,
+    chapter_title: &'a str,
+    chapter_meta: &'a BTreeMap<String, serde_json::Value>,
+    title: &'a str,
+    /// Replaces the book's description, because it comes after `book`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<&'a str>,
+    /// The absolute URL of the page, if the book's `site-url` is known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page_url: Option<&'a str>,
+    path_to_root: String,
+}
+
+/// The content of the 404 page when the book doesn't have its own.
+const DEFAULT_404: &str = "# Document not found (404)\n\n\
+                           This URL is invalid, sorry. \
+                           Please use the navigation bar or search to continue.\n";
+
+/// The longest description generated from a chapter's text, in characters.
+const MAX_DESCRIPTION_LEN: usize = 160;
+
+/// The description of a chapter: the `description` from its front matter, or
+/// else the start of its first paragraph.
+fn chapter_description(ch: &Chapter) -> Option<String> {
+    if let Some(description) = ch
+        .meta
+        .get("description")
+        .and_then(serde_json::Value::as_str)
+    {
+        return Some(description.to_string());
+    }
+
+    let mut text = String::new();
+    let mut in_paragraph = false;
+    for event in utils::new_cmark_parser(&ch.content) {
+        match event {
+            Event::Start(Tag::Paragraph) => in_paragraph = true,
+            Event::End(Tag::Paragraph) => {
+                if !text.trim().is_empty() {
+                    break;
+                }
+                in_paragraph = false;
+            }
+            Event::Text(ref s) | Event::Code(ref s) if in_paragraph => text.push_str(s),
+            Event::SoftBreak | Event::HardBreak if in_paragraph => text.push(' '),
+            _ => {}
+        }
+    }
+
+    let text = utils::collapse_whitespace(text.trim());
+    if text.is_empty() {
+        return None;
+    }
+    if text.chars().count() <= MAX_DESCRIPTION_LEN {
+        return Some(text.into_owned());
+    }
+
+    // Cut at the last word which fits
+    let cut: String = text.chars().take(MAX_DESCRIPTION_LEN).collect();
+    let cut = match cut.rfind(' ') {
+        Some(end) => &cut[..end],
+        None => &cut[..],
+    };
+    Some(format!("{}…", cut))
+}
+
+/// The absolute URL of the page at `path`, relative to the output directory.
+fn page_url(site_url: &str, path: &Path) -> String {
+    let path = path
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+        .replace(' ', "%20");
+    format!("{}/{}", site_url.trim_end_matches('/'), path)
 }
 
 #[cfg(test)]
@@ -666,8 +1188,55 @@ mod tests {
         ];
 
         for (src, should_be) in inputs {
-            let got = build_header_links(&src);
+            let got = build_header_links(&src, None);
             assert_eq!(got, should_be);
         }
     }
+
+    #[test]
+    fn print_page_header_links_are_namespaced_by_chapter() {
+        let src = "<h1>Foo</h1><h2>Foo</h2>";
+        let should_be = r##"<h1><a class="header" href="#ch.foo" id="ch.foo">Foo</a></h1><h2><a class="header" href="#ch.foo-1" id="ch.foo-1">Foo</a></h2>"##;
+
+        assert_eq!(build_header_links(src, Some("ch")), should_be);
+    }
+
+    #[test]
+    fn chapter_descriptions() {
+        let mut ch = Chapter::new(
+            "Intro",
+            String::from("# Intro\n\n![](logo.png)\n\nThe `first`\nparagraph.\n\nThe second."),
+            "intro.md",
+            Vec::new(),
+        );
+        assert_eq!(
+            chapter_description(&ch).as_ref().map(String::as_str),
+            Some("The first paragraph.")
+        );
+
+        ch.meta
+            .insert(String::from("description"), json!("From the front matter"));
+        assert_eq!(
+            chapter_description(&ch).as_ref().map(String::as_str),
+            Some("From the front matter")
+        );
+
+        let long = Chapter::new("Long", "word ".repeat(100), "long.md", Vec::new());
+        let description = chapter_description(&long).unwrap();
+        assert!(description.ends_with("word…"));
+        assert!(description.chars().count() <= MAX_DESCRIPTION_LEN + 1);
+    }
+
+    #[test]
+    fn page_urls_are_absolute() {
+        let path = Path::new("first").join("index.html");
+        assert_eq!(
+            page_url("https://example.com/book/", &path),
+            "https://example.com/book/first/index.html"
+        );
+        assert_eq!(
+            page_url("https://example.com", &path),
+            "https://example.com/first/index.html"
+        );
+    }
 }
@@ -34,6 +34,12 @@ impl HelperDef for RenderToc {
             .as_str()
             .ok_or_else(|| RenderError::new("Type error for `path`, string expected"))?
             .replace("\"", "");
+        // Pages which aren't rendered at their own path, like the 404 page,
+        // say how to get back to the root themselves
+        let path_to_root = match rc.evaluate(ctx, "@root/path_to_root")?.as_json().as_str() {
+            Some(path_to_root) => path_to_root.to_string(),
+            None => utils::fs::path_to_root(&current),
+        };
 
         out.write("<ol class=\"chapter\">")?;
 
@@ -46,6 +52,21 @@ impl HelperDef for RenderToc {
                 continue;
             }
 
+            // Part title
+            if let Some(title) = item.get("part") {
+                // part titles always live at the top level of the TOC
+                while current_level > 1 {
+                    out.write("</ol>")?;
+                    out.write("</li>")?;
+                    current_level -= 1;
+                }
+
+                out.write("<li class=\"part-title\">")?;
+                out.write(&render_name(title))?;
+                out.write("</li>")?;
+                continue;
+            }
+
             let level = if let Some(s) = item.get("section") {
                 s.matches('.').count()
             } else {
@@ -87,7 +108,7 @@ impl HelperDef for RenderToc {
                         .replace("\\", "/");
 
                     // Add link
-                    out.write(&utils::fs::path_to_root(&current))?;
+                    out.write(&path_to_root)?;
                     out.write(&tmp)?;
                     out.write("\"")?;
 
@@ -114,20 +135,8 @@ impl HelperDef for RenderToc {
             }
 
             if let Some(name) = item.get("name") {
-                // Render only inline code blocks
-
-                // filter all events that are not inline code blocks
-                let parser = Parser::new(name).filter(|event| match *event {
-                    Event::Code(_) | Event::InlineHtml(_) | Event::Text(_) => true,
-                    _ => false,
-                });
-
-                // render markdown to html
-                let mut markdown_parsed_name = String::with_capacity(name.len() * 3 / 2);
-                html::push_html(&mut markdown_parsed_name, parser);
-
                 // write to the handlebars template
-                out.write(&markdown_parsed_name)?;
+                out.write(&render_name(name))?;
             }
 
             if path_exists {
@@ -146,3 +155,18 @@ impl HelperDef for RenderToc {
         Ok(())
     }
 }
+
+/// Render a chapter or part name, only keeping inline code blocks.
+fn render_name(name: &str) -> String {
+    // filter all events that are not inline code blocks
+    let parser = Parser::new(name).filter(|event| match *event {
+        Event::Code(_) | Event::InlineHtml(_) | Event::Text(_) => true,
+        _ => false,
+    });
+
+    // render markdown to html
+    let mut markdown_parsed_name = String::with_capacity(name.len() * 3 / 2);
+    html::push_html(&mut markdown_parsed_name, parser);
+
+    markdown_parsed_name
+}
@@ -1,81 +1,428 @@
 use std::borrow::Cow;
-use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::mem;
+use std::path::{Path, PathBuf};
 
-use elasticlunr::Index;
+use elasticlunr::{Index, Language, Pipeline};
 use pulldown_cmark::*;
+use rayon::prelude::*;
+use regex::Regex;
+use serde_json::{Map, Value};
 
-use crate::book::{Book, BookItem};
-use crate::config::Search;
+use crate::book::{Book, BookItem, Chapter};
+use crate::config::{Search, SearchIndexFormat};
 use crate::errors::*;
 use crate::theme::searcher;
 use crate::utils;
 
+/// The search documents extracted from each chapter, so the index can be
+/// updated without going through every chapter again.
+#[derive(Debug, Default)]
+pub struct SearchCache {
+    docs: HashMap<PathBuf, Vec<SearchDoc>>,
+}
+
+/// A section of a chapter, ready to be inserted into the search index.
+#[derive(Debug, Clone)]
+struct SearchDoc {
+    url: String,
+    /// The title, body and breadcrumbs.
+    fields: [String; 3],
+}
+
+/// How the text of the book is split into search terms, which depends on its
+/// language. `searcher.js` has to do the same to the search words, with the
+/// functions in the scripts of the language.
+#[derive(Debug)]
+struct SearchLanguage {
+    /// The ISO 639-1 code of the language, such as `de`.
+    code: String,
+    /// Chinese, Japanese and Korean text isn't (always) split into words by
+    /// spaces, so runs of those characters are split into overlapping pairs.
+    segment_cjk: bool,
+}
+
+impl SearchLanguage {
+    fn new(search_config: &Search, book_language: Option<&str>) -> SearchLanguage {
+        let code = search_config
+            .language
+            .as_ref()
+            .map(String::as_str)
+            .or(book_language)
+            .unwrap_or("en");
+        // Only the primary language subtag of a code like `pt-BR` matters
+        let code = code
+            .split(|c| c == '-' || c == '_')
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        let segment_cjk = ["ja", "ko", "zh"].contains(&code.as_str());
+
+        SearchLanguage { code, segment_cjk }
+    }
+
+    /// English is what elasticlunr.js supports out of the box, so its index
+    /// needs nothing extra.
+    fn is_english(&self) -> bool {
+        self.code == "en"
+    }
+
+    /// The trimmer, stop word filter and stemmer for the language, or just a
+    /// trimmer for languages which elasticlunr doesn't support.
+    fn pipeline(&self) -> Pipeline {
+        match Language::from_code(&self.code) {
+            Some(language) => language.make_pipeline(),
+            None => {
+                if !self.segment_cjk {
+                    warn!(
+                        "Search terms can't be stemmed for the language \"{}\"",
+                        self.code
+                    );
+                }
+                Pipeline {
+                    queue: vec![("trimmer-unicode".to_string(), trim_non_alphanumeric)],
+                }
+            }
+        }
+    }
+
+    /// Put spaces between the overlapping pairs of CJK characters, so they
+    /// are tokenized as words.
+    fn segment<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        if self.segment_cjk {
+            segment_cjk(text)
+        } else {
+            Cow::Borrowed(text)
+        }
+    }
+
+    /// The scripts which register the functions of the pipeline with
+    /// elasticlunr.js, by file name. English needs none.
+    fn scripts(&self) -> Vec<(String, &'static [u8])> {
+        if self.is_english() {
+            return Vec::new();
+        }
+
+        let mut scripts = vec![(
+            "lunr.stemmer.support.js".to_string(),
+            searcher::STEMMER_SUPPORT_JS,
+        )];
+        let suffix = Language::from_code(&self.code).and_then(|language| {
+            language
+                .make_pipeline()
+                .queue
+                .into_iter()
+                .find(|&(ref name, _)| name.starts_with("stemmer-"))
+                .map(|(name, _)| name["stemmer-".len()..].to_string())
+        });
+        if let Some(suffix) = suffix {
+            if let Some(&(_, js)) = searcher::LANGUAGES.iter().find(|&&(s, _)| s == suffix) {
+                scripts.push((format!("lunr.{}.js", suffix), js));
+            }
+        }
+        scripts
+    }
+}
+
+fn trim_non_alphanumeric(token: String) -> Option<String> {
+    let token = token.trim_matches(|c: char| !c.is_alphanumeric());
+    if token.is_empty() {
+        None
+    } else {
+        Some(token.to_string())
+    }
+}
+
+/// Is `c` a Chinese, Japanese or Korean character? Must match the ranges in
+/// `segmentCjk` in `searcher.js`.
+fn is_cjk(c: char) -> bool {
+    match c {
+        '\u{3040}'..='\u{30ff}'
+        | '\u{3400}'..='\u{4dbf}'
+        | '\u{4e00}'..='\u{9fff}'
+        | '\u{ac00}'..='\u{d7af}'
+        | '\u{f900}'..='\u{faff}'
+        | '\u{ff66}'..='\u{ff9f}' => true,
+        _ => false,
+    }
+}
+
+fn segment_cjk(text: &str) -> Cow<'_, str> {
+    if !text.chars().any(is_cjk) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut segmented = String::with_capacity(text.len() * 2);
+    let mut run = Vec::new();
+    for c in text.chars().chain(Some(' ')) {
+        if is_cjk(c) {
+            run.push(c);
+            continue;
+        }
+
+        if run.len() == 1 {
+            segmented.push(' ');
+            segmented.push(run[0]);
+            segmented.push(' ');
+        } else if run.len() > 1 {
+            for pair in run.windows(2) {
+                segmented.push(' ');
+                segmented.extend(pair);
+            }
+            segmented.push(' ');
+        }
+        run.clear();
+        segmented.push(c);
+    }
+    segmented.pop();
+
+    Cow::Owned(segmented)
+}
+
+/// Which chapters are indexed, and how much their results are boosted, from
+/// the glob patterns in the search config.
+#[derive(Debug)]
+struct ChapterRules {
+    include: Vec<Regex>,
+    exclude: Vec<Regex>,
+    boosts: Vec<(Regex, f32)>,
+}
+
+impl ChapterRules {
+    fn new(search_config: &Search) -> ChapterRules {
+        ChapterRules {
+            include: search_config
+                .include
+                .iter()
+                .map(|glob| glob_to_regex(glob))
+                .collect(),
+            exclude: search_config
+                .exclude
+                .iter()
+                .map(|glob| glob_to_regex(glob))
+                .collect(),
+            boosts: search_config
+                .chapter_boost
+                .iter()
+                .map(|(glob, &boost)| (glob_to_regex(glob), boost))
+                .collect(),
+        }
+    }
+
+    fn is_indexed(&self, path: &Path) -> bool {
+        let path = slash_path(path);
+        (self.include.is_empty() || self.include.iter().any(|glob| glob.is_match(&path)))
+            && !self.exclude.iter().any(|glob| glob.is_match(&path))
+    }
+
+    /// The boost for the chapter at `path`, if any pattern matches it.
+    fn boost(&self, path: &Path) -> Option<f32> {
+        let path = slash_path(path);
+        self.boosts
+            .iter()
+            .filter(|&&(ref glob, _)| glob.is_match(&path))
+            .fold(None, |total, &(_, boost)| {
+                Some(total.unwrap_or(1.0) * boost)
+            })
+    }
+}
+
+/// The path with `/` between its components, which is what the patterns use
+/// on every platform.
+fn slash_path(path: &Path) -> String {
+    path.iter()
+        .map(|component| component.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Turns a glob pattern into a regex which matches a whole path. `*` and `?`
+/// match any characters and any one character within a component, and `**`
+/// matches across components.
+fn glob_to_regex(glob: &str) -> Regex {
+    let mut regex = String::from("^");
+    let mut chars = glob.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                // `a/**/b` also matches `a/b`
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    regex.push_str("(?:.*/)?");
+                } else {
+                    regex.push_str(".*");
+                }
+            }
+            '*' => regex.push_str("[^/]*"),
+            '?' => regex.push_str("[^/]"),
+            c => regex.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    regex.push('$');
+
+    Regex::new(&regex).expect("Everything but the wildcards is escaped")
+}
+
+/// The scripts a page has to load, after `elasticlunr.min.js`, for searching
+/// a book in its language.
+pub fn language_scripts(search_config: &Search, book_language: Option<&str>) -> Vec<String> {
+    SearchLanguage::new(search_config, book_language)
+        .scripts()
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect()
+}
+
 /// Creates all files required for search.
-pub fn create_files(search_config: &Search, destination: &Path, book: &Book) -> Result<()> {
+///
+/// When `changed_chapters` is given, only those chapters are indexed again
+/// and the rest are taken from the `cache`. The static JavaScript files are
+/// only written for a full build.
+pub fn create_files(
+    search_config: &Search,
+    book_language: Option<&str>,
+    destination: &Path,
+    book: &Book,
+    cache: &mut SearchCache,
+    changed_chapters: Option<&[PathBuf]>,
+) -> Result<()> {
+    let language = SearchLanguage::new(search_config, book_language);
+    let rules = ChapterRules::new(search_config);
     let mut index = Index::new(&["title", "body", "breadcrumbs"]);
+    index.pipeline = language.pipeline();
+    let mut doc_boosts = BTreeMap::new();
     let mut doc_urls = Vec::with_capacity(book.sections.len());
 
-    for item in book.iter() {
-        render_item(&mut index, &search_config, &mut doc_urls, item)?;
+    if changed_chapters.is_none() {
+        cache.docs.clear();
     }
 
-    let index = write_to_json(index, &search_config, doc_urls)?;
-    debug!("Writing search index ✓");
-    if index.len() > 10_000_000 {
-        warn!("searchindex.json is very large ({} bytes)", index.len());
+    let chapters: Vec<(&Chapter, &PathBuf)> = book
+        .iter()
+        .filter_map(|item| match *item {
+            BookItem::Chapter(ref ch) if !ch.is_draft_chapter() => Some((
+                ch,
+                ch.path.as_ref().expect("Checked that path exists above"),
+            )),
+            _ => None,
+        })
+        .filter(|&(_, path)| rules.is_indexed(path))
+        .collect();
+
+    // Extract the documents in parallel, then add them to the index in order
+    // so the output is always the same
+    let stale: Vec<&(&Chapter, &PathBuf)> = chapters
+        .iter()
+        .filter(|&&(_, path)| {
+            let changed = match changed_chapters {
+                Some(changed) => changed.contains(path),
+                None => true,
+            };
+            changed || !cache.docs.contains_key(path)
+        })
+        .collect();
+    let fresh = stale
+        .par_iter()
+        .map(|&&(chapter, _)| render_item(search_config, chapter))
+        .collect::<Result<Vec<_>>>()?;
+    for (&&(_, path), docs) in stale.iter().zip(fresh) {
+        cache.docs.insert(path.clone(), docs);
+    }
+
+    for &(_, path) in &chapters {
+        let boost = rules.boost(path);
+        for doc in &cache.docs[path] {
+            let doc_ref = doc_urls.len().to_string();
+            doc_urls.push(doc.url.clone());
+            if let Some(boost) = boost {
+                doc_boosts.insert(doc_ref.clone(), boost);
+            }
+            if language.is_english() {
+                index.add_doc(&doc_ref, &doc.fields);
+                continue;
+            }
+
+            let fields: Vec<Cow<'_, str>> = doc
+                .fields
+                .iter()
+                .map(|field| language.segment(field))
+                .collect();
+            index.add_doc(&doc_ref, &fields);
+
+            // Show the text as it was written, not as it was segmented
+            if language.segment_cjk {
+                if let Some(stored) = index.document_store.docs.get_mut(&doc_ref) {
+                    for (field, text) in ["title", "body", "breadcrumbs"].iter().zip(&doc.fields) {
+                        stored.insert(field.to_string(), text.clone());
+                    }
+                }
+            }
+        }
     }
 
+    let docs_per_chapter: Vec<usize> = chapters
+        .iter()
+        .map(|&(_, path)| cache.docs[path].len())
+        .collect();
+    let index = write_to_json(index, &search_config, &language, doc_urls, doc_boosts)?;
+    debug!("Writing search index ✓");
+
     if search_config.copy_js {
-        utils::fs::write_file(destination, "searchindex.json", index.as_bytes())?;
-        utils::fs::write_file(
-            destination,
-            "searchindex.js",
-            format!("Object.assign(window.search, {});", index).as_bytes(),
-        )?;
+        match search_config.index_format {
+            SearchIndexFormat::Single => write_index(destination, &index)?,
+            SearchIndexFormat::Sharded => {
+                write_sharded_index(destination, index, &docs_per_chapter)?
+            }
+        }
+    }
+
+    if search_config.copy_js && changed_chapters.is_none() {
         utils::fs::write_file(destination, "searcher.js", searcher::JS)?;
         utils::fs::write_file(destination, "mark.min.js", searcher::MARK_JS)?;
         utils::fs::write_file(destination, "elasticlunr.min.js", searcher::ELASTICLUNR_JS)?;
+        for (name, js) in language.scripts() {
+            utils::fs::write_file(destination, name, js)?;
+        }
         debug!("Copying search files ✓");
     }
 
     Ok(())
 }
 
-/// Uses the given arguments to construct a search document, then inserts it to the given index.
+/// Uses the given arguments to construct a search document, then adds it to
+/// the chapter's documents.
 fn add_doc(
-    index: &mut Index,
-    doc_urls: &mut Vec<String>,
+    docs: &mut Vec<SearchDoc>,
     anchor_base: &str,
     section_id: &Option<String>,
-    items: &[&str],
+    items: [&str; 3],
 ) {
     let url = if let Some(ref id) = *section_id {
         Cow::Owned(format!("{}#{}", anchor_base, id))
     } else {
         Cow::Borrowed(anchor_base)
     };
-    let url = utils::collapse_whitespace(url.trim());
-    let doc_ref = doc_urls.len().to_string();
-    doc_urls.push(url.into());
+    let url = utils::collapse_whitespace(url.trim()).into_owned();
 
-    let items = items.iter().map(|&x| utils::collapse_whitespace(x.trim()));
-    index.add_doc(&doc_ref, items);
+    let [title, body, breadcrumbs] = items;
+    let fields = [
+        utils::collapse_whitespace(title.trim()).into_owned(),
+        utils::collapse_whitespace(body.trim()).into_owned(),
+        utils::collapse_whitespace(breadcrumbs.trim()).into_owned(),
+    ];
+    docs.push(SearchDoc { url, fields });
 }
 
-/// Renders markdown into flat unformatted text and adds it to the search index.
-fn render_item(
-    index: &mut Index,
-    search_config: &Search,
-    doc_urls: &mut Vec<String>,
-    item: &BookItem,
-) -> Result<()> {
-    let chapter = match *item {
-        BookItem::Chapter(ref ch) => ch,
-        _ => return Ok(()),
-    };
-
-    let filepath = Path::new(&chapter.path).with_extension("html");
+/// Renders a chapter's markdown into flat unformatted text, split into one
+/// search document per section.
+fn render_item(search_config: &Search, chapter: &Chapter) -> Result<Vec<SearchDoc>> {
+    let chapter_path = chapter
+        .path
+        .as_ref()
+        .expect("Checked that path exists above");
+    let filepath = Path::new(&chapter_path).with_extension("html");
     let filepath = filepath
         .to_str()
         .chain_err(|| "Could not convert HTML path to str")?;
@@ -91,6 +438,7 @@ fn render_item(
     let mut html_block = String::new();
     let mut breadcrumbs = chapter.parent_names.clone();
     let mut footnote_numbers = HashMap::new();
+    let mut docs = Vec::new();
 
     for event in p {
         match event {
@@ -99,11 +447,10 @@ fn render_item(
                     // Section finished, the next header is following now
                     // Write the data to the index, and clear it for the next section
                     add_doc(
-                        index,
-                        doc_urls,
+                        &mut docs,
                         &anchor_base,
                         &section_id,
-                        &[&heading, &body, &breadcrumbs.join(" » ")],
+                        [&heading, &body, &breadcrumbs.join(" » ")],
                     );
                     section_id = None;
                     heading.clear();
@@ -160,20 +507,153 @@ fn render_item(
     if !heading.is_empty() {
         // Make sure the last section is added to the index
         add_doc(
-            index,
-            doc_urls,
+            &mut docs,
             &anchor_base,
             &section_id,
-            &[&heading, &body, &breadcrumbs.join(" » ")],
+            [&heading, &body, &breadcrumbs.join(" » ")],
         );
     }
 
+    Ok(docs)
+}
+
+/// Write the whole index to `searchindex.json`, and to `searchindex.js` for
+/// when the book is opened from the file system and can't fetch it.
+fn write_index(destination: &Path, index: &Value) -> Result<()> {
+    let index = serde_json::to_string(index)?;
+    if index.len() > 10_000_000 {
+        warn!("searchindex.json is very large ({} bytes)", index.len());
+        info!("Setting `output.html.search.index-format` to \"sharded\" may help");
+    }
+
+    utils::fs::write_file(destination, "searchindex.json", index.as_bytes())?;
+    utils::fs::write_file(
+        destination,
+        "searchindex.js",
+        format!("Object.assign(window.search, {});", index).as_bytes(),
+    )?;
+
+    Ok(())
+}
+
+/// Split the index into shards which the searcher downloads as searches need
+/// them. There is a shard with the terms starting with each character, and a
+/// shard with the documents of each chapter (which are only needed to show
+/// results). What is left over is written as the manifest, `searchindex.json`,
+/// along with the names of the shards.
+fn write_sharded_index(
+    destination: &Path,
+    mut index: Value,
+    docs_per_chapter: &[usize],
+) -> Result<()> {
+    let shard_dir = destination.join("searchindex");
+    // Don't leave shards from a previous build lying around
+    if shard_dir.exists() {
+        fs::remove_dir_all(&shard_dir)
+            .chain_err(|| format!("Unable to remove {}", shard_dir.display()))?;
+    }
+
+    let mut term_shards: BTreeMap<String, Map<String, Value>> = BTreeMap::new();
+    if let Some(fields) = index
+        .pointer_mut("/index/index")
+        .and_then(Value::as_object_mut)
+    {
+        for (field, inverted_index) in fields.iter_mut() {
+            let root = match inverted_index
+                .get_mut("root")
+                .and_then(Value::as_object_mut)
+            {
+                Some(root) => root,
+                None => continue,
+            };
+
+            // Apart from `docs` and `df`, the root's keys are the first
+            // character of every term
+            let first_chars: Vec<String> = root
+                .keys()
+                .filter(|key| *key != "docs" && *key != "df")
+                .cloned()
+                .collect();
+            for first_char in first_chars {
+                let name = shard_name(&first_char);
+                let subtree = root.remove(&first_char).expect("The key was just listed");
+                let shard = term_shards.entry(name).or_insert_with(Map::new);
+                let field_terms = shard
+                    .entry(field.clone())
+                    .or_insert_with(|| Value::Object(Map::new()));
+                if let Value::Object(ref mut field_terms) = *field_terms {
+                    field_terms.insert(first_char, subtree);
+                }
+            }
+        }
+    }
+
+    for (name, shard) in &term_shards {
+        write_shard(&shard_dir, name, &Value::Object(shard.clone()))?;
+    }
+
+    let mut docs = match index.pointer_mut("/index/documentStore/docs") {
+        Some(docs) => mem::replace(docs, Value::Object(Map::new())),
+        None => Value::Object(Map::new()),
+    };
+    let mut doc_shards = Vec::new();
+    let mut first_ref = 0;
+    for &count in docs_per_chapter.iter().filter(|&&count| count > 0) {
+        let mut shard = Map::new();
+        for doc_ref in first_ref..first_ref + count {
+            let doc_ref = doc_ref.to_string();
+            if let Some(doc) = docs.get_mut(&doc_ref).map(Value::take) {
+                shard.insert(doc_ref, doc);
+            }
+        }
+
+        write_shard(
+            &shard_dir,
+            &format!("docs-{}", doc_shards.len()),
+            &Value::Object(shard),
+        )?;
+        doc_shards.push(first_ref);
+        first_ref += count;
+    }
+
+    index["shards"] = json!({
+        "terms": term_shards.keys().collect::<Vec<_>>(),
+        "docs": doc_shards,
+    });
+    write_index(destination, &index)
+}
+
+/// The name of the shard holding the terms which start with `first_char`.
+fn shard_name(first_char: &str) -> String {
+    let c = first_char.chars().next().unwrap_or_default();
+    format!("terms-{:x}", c as u32)
+}
+
+fn write_shard(shard_dir: &Path, name: &str, shard: &Value) -> Result<()> {
+    let shard = serde_json::to_string(shard)?;
+
+    utils::fs::write_file(shard_dir, format!("{}.json", name), shard.as_bytes())?;
+    utils::fs::write_file(
+        shard_dir,
+        format!("{}.js", name),
+        format!(
+            "(window.search.shard_contents = window.search.shard_contents || {{}})[{:?}] = {};",
+            name, shard
+        )
+        .as_bytes(),
+    )?;
+
     Ok(())
 }
 
-fn write_to_json(index: Index, search_config: &Search, doc_urls: Vec<String>) -> Result<String> {
+fn write_to_json(
+    index: Index,
+    search_config: &Search,
+    language: &SearchLanguage,
+    doc_urls: Vec<String>,
+    doc_boosts: BTreeMap<String, f32>,
+) -> Result<Value> {
     use elasticlunr::config::{SearchBool, SearchOptions, SearchOptionsField};
-    use std::collections::BTreeMap;
 
     #[derive(Serialize)]
     struct ResultsOptions {
@@ -181,6 +661,12 @@ fn write_to_json(index: Index, search_config: &Search, doc_urls: Vec<String>) ->
         teaser_word_count: u32,
     }
 
+    #[derive(Serialize)]
+    struct LanguageOptions {
+        /// Whether runs of CJK characters are split into pairs
+        segment_cjk: bool,
+    }
+
     #[derive(Serialize)]
     struct SearchindexJson {
         /// The options used for displaying search results
@@ -189,8 +675,15 @@ fn write_to_json(index: Index, search_config: &Search, doc_urls: Vec<String>) ->
         search_options: SearchOptions,
         /// Used to lookup a document's URL from an integer document ref.
         doc_urls: Vec<String>,
+        /// Multipliers for the scores of the documents of boosted chapters,
+        /// by document ref.
+        #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+        doc_boosts: BTreeMap<String, f32>,
         /// The index for elasticlunr.js
         index: elasticlunr::Index,
+        /// How to split search words, unless elasticlunr.js knows already
+        #[serde(skip_serializing_if = "Option::is_none")]
+        language: Option<LanguageOptions>,
     }
 
     let mut fields = BTreeMap::new();
@@ -221,13 +714,20 @@ fn write_to_json(index: Index, search_config: &Search, doc_urls: Vec<String>) ->
         results_options,
         search_options,
         doc_urls,
+        doc_boosts,
         index,
+        language: if language.is_english() {
+            None
+        } else {
+            Some(LanguageOptions {
+                segment_cjk: language.segment_cjk,
+            })
+        },
     };
 
     // By converting to serde_json::Value as an intermediary, we use a
     // BTreeMap internally and can force a stable ordering of map keys.
     let json_contents = serde_json::to_value(&json_contents)?;
-    let json_contents = serde_json::to_string(&json_contents)?;
 
     Ok(json_contents)
 }
@@ -251,3 +751,37 @@ fn clean_html(html: &str) -> String {
     }
     AMMONIA.clean(html).to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn globs_match_whole_paths() {
+        let tests = vec![
+            ("CHANGELOG.md", "CHANGELOG.md", true),
+            ("CHANGELOG.md", "old/CHANGELOG.md", false),
+            ("*.md", "intro.md", true),
+            ("*.md", "first/intro.md", false),
+            ("api/*", "api/index.md", true),
+            ("api/*", "api/v1/index.md", false),
+            ("api/**", "api/v1/index.md", true),
+            ("**/index.md", "index.md", true),
+            ("**/index.md", "api/v1/index.md", true),
+            ("api/**/index.md", "api/index.md", true),
+            ("chapter_?.md", "chapter_1.md", true),
+            ("chapter_?.md", "chapter_10.md", false),
+            ("a+b.md", "aab.md", false),
+        ];
+
+        for (glob, path, should_match) in tests {
+            assert_eq!(
+                glob_to_regex(glob).is_match(path),
+                should_match,
+                "{} matching {}",
+                glob,
+                path
+            );
+        }
+    }
+}
@@ -11,11 +11,15 @@
 //! [For Developers]: https://rust-lang-nursery.github.io/mdBook/for_developers/index.html
 //! [RenderContext]: struct.RenderContext.html
 
+pub use self::epub::EpubRenderer;
 pub use self::html_handlebars::HtmlHandlebars;
+pub use self::print::PrintRenderer;
 
+mod document;
+mod epub;
 mod html_handlebars;
+mod print;
 
-use shlex::Shlex;
 use std::fs;
 use std::io::{self, Read};
 use std::path::PathBuf;
@@ -24,6 +28,7 @@ use std::process::{Command, Stdio};
 use crate::book::Book;
 use crate::config::Config;
 use crate::errors::*;
+use crate::plugin::{self, Handshake, PluginProcess};
 
 /// An arbitrary `mdbook` backend.
 ///
@@ -44,6 +49,30 @@ pub trait Renderer {
     /// Invoke the `Renderer`, passing in all the necessary information for
     /// describing a book.
     fn render(&self, ctx: &RenderContext) -> Result<()>;
+
+    /// Bring the output of an earlier `render()` up to date after only part
+    /// of the book changed, as happens while running `mdbook watch` or
+    /// `mdbook serve`. The destination directory is *not* cleared first, and
+    /// `ctx.book` still contains every chapter.
+    ///
+    /// The default implementation simply renders the whole book again.
+    fn render_changes(&self, ctx: &RenderContext, changes: &Changes) -> Result<()> {
+        let _ = changes;
+        self.render(ctx)
+    }
+}
+
+/// The parts of a book which changed since it was last rendered.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Changes {
+    /// The chapters whose content changed, identified by their (preprocessed)
+    /// path.
+    pub chapters: Vec<PathBuf>,
+    /// Non-chapter files in the source directory which were created or
+    /// modified, relative to the source directory.
+    pub files: Vec<PathBuf>,
+    /// Whether anything in the theme directory changed.
+    pub theme: bool,
 }
 
 /// The context provided to all renderers.
@@ -118,32 +147,66 @@ impl RenderContext {
 ///
 /// If the subprocess wishes to indicate that rendering failed, it should exit
 /// with a non-zero return code.
-#[derive(Debug, Clone, PartialEq)]
+///
+/// A renderer which has opted into the capabilities handshake with
+/// `with_capabilities()` is asked for its capabilities with `cmd capabilities`
+/// before rendering for the first time, as described in the [plugin] module.
+/// A persistent renderer, which is always asked, is instead kept running, and
+/// sent a `render` request for every build as described there too. Its
+/// `stdout` is then used for answering requests.
+///
+/// [plugin]: ../plugin/index.html
+#[derive(Debug, Clone)]
 pub struct CmdRenderer {
     name: String,
     cmd: String,
+    capabilities: bool,
+    persistent: bool,
+    handshake: Handshake,
 }
 
+/// The keys of a renderer's table which are read by `mdbook` itself.
+const MDBOOK_KEYS: &[&str] = &["command", "capabilities", "persistent"];
+
 impl CmdRenderer {
     /// Create a new `CmdRenderer` which will invoke the provided `cmd` string.
     pub fn new(name: String, cmd: String) -> CmdRenderer {
-        CmdRenderer { name, cmd }
+        CmdRenderer {
+            name,
+            cmd,
+            capabilities: false,
+            persistent: false,
+            handshake: Handshake::default(),
+        }
     }
 
-    fn compose_command(&self) -> Result<Command> {
-        let mut words = Shlex::new(&self.cmd);
-        let executable = match words.next() {
-            Some(e) => e,
-            None => bail!("Command string was empty"),
-        };
+    /// Ask the renderer for its capabilities before using it. Only renderers
+    /// which understand the handshake should be asked, because others may take
+    /// `capabilities` for something else.
+    pub fn with_capabilities(mut self, capabilities: bool) -> CmdRenderer {
+        self.capabilities = capabilities;
+        self
+    }
 
-        let mut cmd = Command::new(executable);
+    /// Keep the renderer running between builds, rather than starting it for
+    /// every one. The renderer has to support this, so it is asked for its
+    /// capabilities too.
+    pub fn with_persistent(mut self, persistent: bool) -> CmdRenderer {
+        self.persistent = persistent;
+        self
+    }
 
-        for arg in words {
-            cmd.arg(arg);
-        }
+    fn compose_command(&self) -> Result<Command> {
+        plugin::command(&self.cmd)
+    }
+}
 
-        Ok(cmd)
+impl PartialEq for CmdRenderer {
+    fn eq(&self, other: &CmdRenderer) -> bool {
+        self.name == other.name
+            && self.cmd == other.cmd
+            && self.capabilities == other.capabilities
+            && self.persistent == other.persistent
     }
 }
 
@@ -157,6 +220,29 @@ impl Renderer for CmdRenderer {
 
         let _ = fs::create_dir_all(&ctx.destination);
 
+        let capabilities = if self.capabilities || self.persistent {
+            let mut handshake_cmd = self.compose_command()?;
+            handshake_cmd.current_dir(&ctx.destination);
+            self.handshake.capabilities(&self.name, handshake_cmd)?
+        } else {
+            None
+        };
+        if let Some(ref capabilities) = capabilities {
+            let table = ctx.config.get(&format!("output.{}", self.name));
+            capabilities.check_config(&self.name, table, MDBOOK_KEYS)?;
+        }
+
+        if self.persistent {
+            let process = PluginProcess::get(
+                &self.name,
+                &self.cmd,
+                Some(&ctx.destination),
+                capabilities.as_ref(),
+            )?;
+            let mut process = process.lock().expect("A plugin is never poisoned");
+            return process.call("render", (ctx,));
+        }
+
         let mut child = match self
             .compose_command()?
             .stdin(Stdio::piped())
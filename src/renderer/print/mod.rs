@@ -0,0 +1,236 @@
+//! A renderer which writes the whole book out as one self-contained HTML
+//! document, ready to be turned into a PDF by an external typesetter.
+
+use crate::book::{BookItem, Chapter};
+use crate::config::{Config, PrintConfig};
+use crate::errors::*;
+use crate::renderer::document::{
+    self, escape, file_stem, language, media_type, title, ChapterAnchors,
+};
+use crate::renderer::{RenderContext, Renderer};
+use crate::utils;
+
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+static DOCUMENT_CSS: &str = include_str!("document.css");
+
+/// Renders the book as a single, print-ready HTML document.
+#[derive(Default)]
+pub struct PrintRenderer;
+
+impl PrintRenderer {
+    /// Create a new `PrintRenderer`.
+    pub fn new() -> Self {
+        PrintRenderer
+    }
+}
+
+impl Renderer for PrintRenderer {
+    fn name(&self) -> &str {
+        "print"
+    }
+
+    fn render(&self, ctx: &RenderContext) -> Result<()> {
+        let print_config: PrintConfig = ctx
+            .config
+            .get_deserialized("output.print")
+            .unwrap_or_default();
+        let src_dir = ctx.root.join(&ctx.config.book.src);
+        let anchors = ChapterAnchors::new(&ctx.book);
+
+        let mut chapters = String::new();
+        for item in ctx.book.iter() {
+            if let BookItem::Chapter(ref ch) = *item {
+                if let Some(ref path) = ch.path {
+                    debug!("Rendering {} for the print document", ch.name);
+                    let anchor = anchors.get(path).expect("Every chapter has an anchor");
+                    chapters.push_str(&render_chapter(
+                        ch,
+                        path,
+                        anchor,
+                        &anchors,
+                        &src_dir,
+                        &print_config,
+                    ));
+                }
+            }
+        }
+
+        let toc = document::toc_list(&document::toc_entries(&ctx.book, |ch| {
+            let path = ch
+                .path
+                .as_ref()
+                .expect("Only called for non-draft chapters");
+            format!(
+                "#{}",
+                anchors.get(path).expect("Every chapter has an anchor")
+            )
+        }));
+
+        let css = stylesheet(&print_config, &ctx.root)?;
+        let rendered = print_document(&ctx.config, &css, &toc, &chapters);
+
+        let filename = format!("{}.html", file_stem(&ctx.config));
+        utils::fs::write_file(&ctx.destination, &filename, rendered.as_bytes())
+            .chain_err(|| "Unable to write the print document")?;
+        debug!("Creating {} ✓", filename);
+
+        Ok(())
+    }
+}
+
+/// Render a chapter as a `<section>`, with links to other chapters pointing
+/// at their anchors and images embedded in the document.
+fn render_chapter(
+    ch: &Chapter,
+    path: &Path,
+    anchor: &str,
+    anchors: &ChapterAnchors,
+    src_dir: &Path,
+    print_config: &PrintConfig,
+) -> String {
+    let content = utils::render_markdown_with_link_fixer(
+        &ch.content,
+        print_config.curly_quotes,
+        None,
+        |dest| {
+            anchors
+                .fix_link(path, dest)
+                .or_else(|| embed_image(src_dir, path, dest))
+        },
+    );
+    let content = document::insert_header_ids(&content, anchor);
+
+    format!(
+        "<section class=\"chapter\" id=\"{}\">\n{}</section>\n",
+        anchor, content
+    )
+}
+
+/// Turn a relative link to an image into a `data:` URI, so the document
+/// doesn't depend on any other files.
+fn embed_image(src_dir: &Path, chapter_path: &Path, dest: &str) -> Option<String> {
+    if dest.contains(':') || dest.starts_with('/') {
+        return None;
+    }
+
+    let parent = chapter_path.parent().unwrap_or_else(|| Path::new(""));
+    let image = src_dir.join(parent).join(dest);
+    let media_type = media_type(&image).filter(|ty| ty.starts_with("image/"))?;
+
+    let mut data = Vec::new();
+    if let Err(e) = File::open(&image).and_then(|mut f| f.read_to_end(&mut data)) {
+        warn!("Unable to embed {} ({})", image.display(), e);
+        return None;
+    }
+
+    Some(format!("data:{};base64,{}", media_type, base64(&data)))
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(data.len() * 4 / 3 + 4);
+
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).cloned().unwrap_or(0),
+            chunk.get(2).cloned().unwrap_or(0),
+        ];
+        let n = (u32::from(bytes[0]) << 16) | (u32::from(bytes[1]) << 8) | u32::from(bytes[2]);
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(ALPHABET[(n >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+/// The default styles followed by any additional stylesheets.
+fn stylesheet(print_config: &PrintConfig, root: &Path) -> Result<String> {
+    let mut css = String::from(DOCUMENT_CSS);
+
+    for path in &print_config.additional_css {
+        let mut file = File::open(root.join(path))
+            .chain_err(|| format!("Unable to open additional CSS file {}", path.display()))?;
+        css.push('\n');
+        file.read_to_string(&mut css)?;
+    }
+
+    Ok(css)
+}
+
+fn print_document(config: &Config, css: &str, toc: &str, chapters: &str) -> String {
+    let title = escape(title(config));
+    let authors = escape(&config.book.authors.join(", "));
+
+    let mut title_page = format!("<h1 class=\"book-title\">{}</h1>\n", title);
+    if !authors.is_empty() {
+        title_page.push_str(&format!("<p class=\"authors\">{}</p>\n", authors));
+    }
+    if let Some(ref description) = config.book.description {
+        title_page.push_str(&format!(
+            "<p class=\"description\">{}</p>\n",
+            escape(description)
+        ));
+    }
+
+    format!(
+        r#"<!DOCTYPE html>
+<html lang="{lang}">
+<head>
+<meta charset="UTF-8">
+<title>{title}</title>
+<meta name="author" content="{authors}">
+<style>
+{css}
+</style>
+</head>
+<body>
+<header class="title-page">
+{title_page}</header>
+<nav class="toc">
+<h1>Contents</h1>
+{toc}
+</nav>
+{chapters}</body>
+</html>
+"#,
+        lang = escape(language(config)),
+        title = title,
+        authors = authors,
+        css = css,
+        title_page = title_page,
+        toc = toc,
+        chapters = chapters
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base64_matches_the_rfc_test_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+
+        for &(input, should_be) in &vectors {
+            assert_eq!(base64(input.as_bytes()), should_be);
+        }
+    }
+}
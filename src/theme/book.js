@@ -432,6 +432,53 @@ function playpen_text(playpen) {
     });
 })();
 
+(function languages() {
+    var languageToggleButton = document.getElementById('language-toggle');
+    var languagePopup = document.getElementById('language-list');
+    if (!languageToggleButton) { return; }
+
+    // Each link goes to a translation's first page. Point it at this page in
+    // that language instead, which is at the same place relative to the root.
+    var languageRoot = new URL(path_to_root || './', window.location.href);
+    var page = window.location.pathname.slice(languageRoot.pathname.length);
+    Array.prototype.forEach.call(languagePopup.querySelectorAll('a'), function (link) {
+        link.href = new URL('../' + link.getAttribute('hreflang') + '/' + page, languageRoot).href;
+    });
+
+    function showLanguages() {
+        languagePopup.style.display = 'block';
+        languageToggleButton.setAttribute('aria-expanded', true);
+        languagePopup.querySelector('a').focus();
+    }
+
+    function hideLanguages() {
+        languagePopup.style.display = 'none';
+        languageToggleButton.setAttribute('aria-expanded', false);
+        languageToggleButton.focus();
+    }
+
+    languageToggleButton.addEventListener('click', function () {
+        if (languagePopup.style.display === 'block') {
+            hideLanguages();
+        } else {
+            showLanguages();
+        }
+    });
+
+    document.addEventListener('click', function (e) {
+        if (languagePopup.style.display === 'block' && !languageToggleButton.contains(e.target) && !languagePopup.contains(e.target)) {
+            hideLanguages();
+        }
+    });
+
+    document.addEventListener('keydown', function (e) {
+        if (e.key === 'Escape' && languagePopup.contains(e.target)) {
+            e.preventDefault();
+            hideLanguages();
+        }
+    });
+})();
+
 (function sidebar() {
     var html = document.querySelector("html");
     var sidebar = document.getElementById("sidebar");
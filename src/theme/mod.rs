@@ -17,6 +17,7 @@ pub static CHROME_CSS: &[u8] = include_bytes!("css/chrome.css");
 pub static GENERAL_CSS: &[u8] = include_bytes!("css/general.css");
 pub static PRINT_CSS: &[u8] = include_bytes!("css/print.css");
 pub static VARIABLES_CSS: &[u8] = include_bytes!("css/variables.css");
+pub static EPUB_CSS: &[u8] = include_bytes!("css/epub.css");
 pub static FAVICON: &[u8] = include_bytes!("favicon.png");
 pub static JS: &[u8] = include_bytes!("book.js");
 pub static HIGHLIGHT_JS: &[u8] = include_bytes!("highlight.js");
@@ -47,6 +48,7 @@ pub struct Theme {
     pub general_css: Vec<u8>,
     pub print_css: Vec<u8>,
     pub variables_css: Vec<u8>,
+    pub epub_css: Vec<u8>,
     pub favicon: Vec<u8>,
     pub js: Vec<u8>,
     pub highlight_css: Vec<u8>,
@@ -81,6 +83,7 @@ impl Theme {
                     theme_dir.join("css/variables.css"),
                     &mut theme.variables_css,
                 ),
+                (theme_dir.join("css/epub.css"), &mut theme.epub_css),
                 (theme_dir.join("favicon.png"), &mut theme.favicon),
                 (theme_dir.join("highlight.js"), &mut theme.highlight_js),
                 (theme_dir.join("clipboard.min.js"), &mut theme.clipboard_js),
@@ -119,6 +122,7 @@ impl Default for Theme {
             general_css: GENERAL_CSS.to_owned(),
             print_css: PRINT_CSS.to_owned(),
             variables_css: VARIABLES_CSS.to_owned(),
+            epub_css: EPUB_CSS.to_owned(),
             favicon: FAVICON.to_owned(),
             js: JS.to_owned(),
             highlight_css: HIGHLIGHT_CSS.to_owned(),
@@ -174,6 +178,7 @@ mod tests {
             "css/general.css",
             "css/print.css",
             "css/variables.css",
+            "css/epub.css",
             "book.js",
             "highlight.js",
             "tomorrow-night.css",
@@ -199,6 +204,7 @@ mod tests {
             general_css: Vec::new(),
             print_css: Vec::new(),
             variables_css: Vec::new(),
+            epub_css: Vec::new(),
             favicon: Vec::new(),
             js: Vec::new(),
             highlight_css: Vec::new(),
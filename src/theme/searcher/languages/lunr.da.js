@@ -0,0 +1,335 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for Danish,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball Danish stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("hed", -1, 1),
+        new Among("ethed", 0, 1),
+        new Among("ered", -1, 1),
+        new Among("e", -1, 1),
+        new Among("erede", 3, 1),
+        new Among("ende", 3, 1),
+        new Among("erende", 5, 1),
+        new Among("ene", 3, 1),
+        new Among("erne", 3, 1),
+        new Among("ere", 3, 1),
+        new Among("en", -1, 1),
+        new Among("heden", 10, 1),
+        new Among("eren", 10, 1),
+        new Among("er", -1, 1),
+        new Among("heder", 13, 1),
+        new Among("erer", 13, 1),
+        new Among("s", -1, 2),
+        new Among("heds", 16, 1),
+        new Among("es", 16, 1),
+        new Among("endes", 18, 1),
+        new Among("erendes", 19, 1),
+        new Among("enes", 18, 1),
+        new Among("ernes", 18, 1),
+        new Among("eres", 18, 1),
+        new Among("ens", 16, 1),
+        new Among("hedens", 24, 1),
+        new Among("erens", 24, 1),
+        new Among("ers", 16, 1),
+        new Among("ets", 16, 1),
+        new Among("erets", 28, 1),
+        new Among("et", -1, 1),
+        new Among("eret", 30, 1)
+    ];
+
+    var A_1 = [
+        new Among("gd", -1, -1),
+        new Among("dt", -1, -1),
+        new Among("gt", -1, -1),
+        new Among("kt", -1, -1)
+    ];
+
+    var A_2 = [
+        new Among("ig", -1, 1),
+        new Among("lig", 0, 1),
+        new Among("elig", 1, 1),
+        new Among("els", -1, 1),
+        new Among("l\u00F8st", -1, 2)
+    ];
+
+    var G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 128];
+
+    var G_s_ending = [239, 254, 42, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16];
+
+    function r_mark_regions(env, context) {
+        context.i_p1 = env.limit;
+        var v_1 = env.cursor;
+        var c = env.hop(3);
+        if (0 > c || c > env.limit) {
+            return false;
+        }
+        env.cursor = c;
+        context.i_x = env.cursor;
+        env.cursor = v_1;
+        golab0: while (true) {
+            var v_2 = env.cursor;
+            lab1: while (true) {
+                if (!env.in_grouping(G_v, 97, 248)) {
+                    break lab1;
+                }
+                env.cursor = v_2;
+                break golab0;
+            }
+            env.cursor = v_2;
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        golab2: while (true) {
+            lab3: while (true) {
+                if (!env.out_grouping(G_v, 97, 248)) {
+                    break lab3;
+                }
+                break golab2;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        lab4: while (true) {
+            if (!(context.i_p1 < context.i_x)) {
+                break lab4;
+            }
+            context.i_p1 = context.i_x;
+            break lab4;
+        }
+        return true;
+    }
+    function r_main_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_0, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_2;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.in_grouping_b(G_s_ending, 97, 229)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_consonant_pair(env, context) {
+        var v_1 = env.limit - env.cursor;
+        var v_2 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_3 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_2;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_1, context) == 0) {
+            env.limit_backward = v_3;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_3;
+        env.cursor = env.limit - v_1;
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        return true;
+    }
+    function r_other_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        lab0: while (true) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("st")) {
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (!env.eq_s_b("ig")) {
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        var v_2 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_3 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_2;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_2, context);
+        if (among_var == 0) {
+            env.limit_backward = v_3;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_3;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_4 = env.limit - env.cursor;
+            lab1: while (true) {
+                if (!r_consonant_pair(env, context)) {
+                    break lab1;
+                }
+                break lab1;
+            }
+            env.cursor = env.limit - v_4;
+        } else if (among_var == 2) {
+            if (!env.slice_from("l\u00F8s")) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_undouble(env, context) {
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (!env.out_grouping_b(G_v, 97, 248)) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        context.S_ch = env.slice_to();
+        if (context.S_ch.length == 0) {
+            return false;
+        }
+        env.limit_backward = v_2;
+        if (!env.eq_s_b(context.S_ch)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            i_x: 0,
+            i_p1: 0,
+            S_ch: "",
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_2 = env.limit - env.cursor;
+        lab1: while (true) {
+            if (!r_main_suffix(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            if (!r_consonant_pair(env, context)) {
+                break lab2;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_4 = env.limit - env.cursor;
+        lab3: while (true) {
+            if (!r_other_suffix(env, context)) {
+                break lab3;
+            }
+            break lab3;
+        }
+        env.cursor = env.limit - v_4;
+        var v_5 = env.limit - env.cursor;
+        lab4: while (true) {
+            if (!r_undouble(env, context)) {
+                break lab4;
+            }
+            break lab4;
+        }
+        env.cursor = env.limit - v_5;
+        env.cursor = env.limit_backward;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("da", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "ad", "af", "alle", "alt", "anden", "at", "blev", "blive", "bliver",
+            "da", "de", "dem", "den", "denne", "der", "deres", "det", "dette",
+            "dig", "din", "disse", "dog", "du", "efter", "eller", "en", "end", "er",
+            "et", "for", "fra", "ham", "han", "hans", "har", "havde", "have",
+            "hende", "hendes", "her", "hos", "hun", "hvad", "hvis", "hvor", "i",
+            "ikke", "ind", "jeg", "jer", "jo", "kunne", "man", "mange", "med",
+            "meget", "men", "mig", "min", "mine", "mit", "mod", "ned", "noget",
+            "nogle", "nu", "n\u00E5r", "og", "ogs\u00E5", "om", "op", "os", "over",
+            "p\u00E5", "selv", "sig", "sin", "sine", "sit", "skal", "skulle", "som",
+            "s\u00E5dan", "thi", "til", "ud", "under", "var", "vi", "vil", "ville",
+            "vor", "v\u00E6re", "v\u00E6ret"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
@@ -0,0 +1,565 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for German,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball German stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("", -1, 6),
+        new Among("U", 0, 2),
+        new Among("Y", 0, 1),
+        new Among("\u00E4", 0, 3),
+        new Among("\u00F6", 0, 4),
+        new Among("\u00FC", 0, 5)
+    ];
+
+    var A_1 = [
+        new Among("e", -1, 2),
+        new Among("em", -1, 1),
+        new Among("en", -1, 2),
+        new Among("ern", -1, 1),
+        new Among("er", -1, 1),
+        new Among("s", -1, 3),
+        new Among("es", 5, 2)
+    ];
+
+    var A_2 = [
+        new Among("en", -1, 1),
+        new Among("er", -1, 1),
+        new Among("st", -1, 2),
+        new Among("est", 2, 1)
+    ];
+
+    var A_3 = [
+        new Among("ig", -1, 1),
+        new Among("lich", -1, 1)
+    ];
+
+    var A_4 = [
+        new Among("end", -1, 1),
+        new Among("ig", -1, 2),
+        new Among("ung", -1, 1),
+        new Among("lich", -1, 3),
+        new Among("isch", -1, 2),
+        new Among("ik", -1, 2),
+        new Among("heit", -1, 3),
+        new Among("keit", -1, 4)
+    ];
+
+    var G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 32, 8];
+
+    var G_s_ending = [117, 30, 5];
+
+    var G_st_ending = [117, 30, 4];
+
+    function r_prelude(env, context) {
+        var v_1 = env.cursor;
+        replab0: while (true) {
+            var v_2 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                lab2: while (true) {
+                    var v_3 = env.cursor;
+                    lab3: while (true) {
+                        env.bra = env.cursor;
+                        if (!env.eq_s("\u00DF")) {
+                            break lab3;
+                        }
+                        env.ket = env.cursor;
+                        if (!env.slice_from("ss")) {
+                            return false;
+                        }
+                        break lab2;
+                    }
+                    env.cursor = v_3;
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                    break lab2;
+                }
+                continue replab0;
+            }
+            env.cursor = v_2;
+            break replab0;
+        }
+        env.cursor = v_1;
+        replab4: while (true) {
+            var v_4 = env.cursor;
+            lab5: for (var lab5_i = 0; lab5_i < 1; lab5_i++) {
+                golab6: while (true) {
+                    var v_5 = env.cursor;
+                    lab7: while (true) {
+                        if (!env.in_grouping(G_v, 97, 252)) {
+                            break lab7;
+                        }
+                        env.bra = env.cursor;
+                        lab8: while (true) {
+                            var v_6 = env.cursor;
+                            lab9: while (true) {
+                                if (!env.eq_s("u")) {
+                                    break lab9;
+                                }
+                                env.ket = env.cursor;
+                                if (!env.in_grouping(G_v, 97, 252)) {
+                                    break lab9;
+                                }
+                                if (!env.slice_from("U")) {
+                                    return false;
+                                }
+                                break lab8;
+                            }
+                            env.cursor = v_6;
+                            if (!env.eq_s("y")) {
+                                break lab7;
+                            }
+                            env.ket = env.cursor;
+                            if (!env.in_grouping(G_v, 97, 252)) {
+                                break lab7;
+                            }
+                            if (!env.slice_from("Y")) {
+                                return false;
+                            }
+                            break lab8;
+                        }
+                        env.cursor = v_5;
+                        break golab6;
+                    }
+                    env.cursor = v_5;
+                    if (env.cursor >= env.limit) {
+                        break lab5;
+                    }
+                    env.next_char();
+                }
+                continue replab4;
+            }
+            env.cursor = v_4;
+            break replab4;
+        }
+        return true;
+    }
+    function r_mark_regions(env, context) {
+        context.i_p1 = env.limit;
+        context.i_p2 = env.limit;
+        var v_1 = env.cursor;
+        var c = env.hop(3);
+        if (0 > c || c > env.limit) {
+            return false;
+        }
+        env.cursor = c;
+        context.i_x = env.cursor;
+        env.cursor = v_1;
+        golab0: while (true) {
+            lab1: while (true) {
+                if (!env.in_grouping(G_v, 97, 252)) {
+                    break lab1;
+                }
+                break golab0;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        golab2: while (true) {
+            lab3: while (true) {
+                if (!env.out_grouping(G_v, 97, 252)) {
+                    break lab3;
+                }
+                break golab2;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        lab4: while (true) {
+            if (!(context.i_p1 < context.i_x)) {
+                break lab4;
+            }
+            context.i_p1 = context.i_x;
+            break lab4;
+        }
+        golab5: while (true) {
+            lab6: while (true) {
+                if (!env.in_grouping(G_v, 97, 252)) {
+                    break lab6;
+                }
+                break golab5;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        golab7: while (true) {
+            lab8: while (true) {
+                if (!env.out_grouping(G_v, 97, 252)) {
+                    break lab8;
+                }
+                break golab7;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        context.i_p2 = env.cursor;
+        return true;
+    }
+    function r_postlude(env, context) {
+        var among_var;
+        replab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_0, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_from("y")) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_from("u")) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (!env.slice_from("a")) {
+                        return false;
+                    }
+                } else if (among_var == 4) {
+                    if (!env.slice_from("o")) {
+                        return false;
+                    }
+                } else if (among_var == 5) {
+                    if (!env.slice_from("u")) {
+                        return false;
+                    }
+                } else if (among_var == 6) {
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_1;
+            break replab0;
+        }
+        return true;
+    }
+    function r_R1(env, context) {
+        if (!(context.i_p1 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R2(env, context) {
+        if (!(context.i_p2 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_standard_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        lab0: while (true) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_1, context);
+            if (among_var == 0) {
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (!r_R1(env, context)) {
+                break lab0;
+            }
+            if (among_var == 0) {
+                break lab0;
+            } else if (among_var == 1) {
+                if (!env.slice_del()) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_2 = env.limit - env.cursor;
+                lab1: while (true) {
+                    env.ket = env.cursor;
+                    if (!env.eq_s_b("s")) {
+                        env.cursor = env.limit - v_2;
+                        break lab1;
+                    }
+                    env.bra = env.cursor;
+                    if (!env.eq_s_b("nis")) {
+                        env.cursor = env.limit - v_2;
+                        break lab1;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    break lab1;
+                }
+            } else if (among_var == 3) {
+                if (!env.in_grouping_b(G_s_ending, 98, 116)) {
+                    break lab0;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_2, context);
+            if (among_var == 0) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            if (!r_R1(env, context)) {
+                break lab2;
+            }
+            if (among_var == 0) {
+                break lab2;
+            } else if (among_var == 1) {
+                if (!env.slice_del()) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.in_grouping_b(G_st_ending, 98, 116)) {
+                    break lab2;
+                }
+                var c = env.hop(-3);
+                if (env.limit_backward > c || c > env.limit) {
+                    break lab2;
+                }
+                env.cursor = c;
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_4 = env.limit - env.cursor;
+        lab3: while (true) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_4, context);
+            if (among_var == 0) {
+                break lab3;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                break lab3;
+            }
+            if (among_var == 0) {
+                break lab3;
+            } else if (among_var == 1) {
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_5 = env.limit - env.cursor;
+                lab4: while (true) {
+                    env.ket = env.cursor;
+                    if (!env.eq_s_b("ig")) {
+                        env.cursor = env.limit - v_5;
+                        break lab4;
+                    }
+                    env.bra = env.cursor;
+                    var v_6 = env.limit - env.cursor;
+                    lab5: while (true) {
+                        if (!env.eq_s_b("e")) {
+                            break lab5;
+                        }
+                        env.cursor = env.limit - v_5;
+                        break lab4;
+                    }
+                    env.cursor = env.limit - v_6;
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_5;
+                        break lab4;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    break lab4;
+                }
+            } else if (among_var == 2) {
+                var v_7 = env.limit - env.cursor;
+                lab6: while (true) {
+                    if (!env.eq_s_b("e")) {
+                        break lab6;
+                    }
+                    break lab3;
+                }
+                env.cursor = env.limit - v_7;
+                if (!env.slice_del()) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_8 = env.limit - env.cursor;
+                lab7: while (true) {
+                    env.ket = env.cursor;
+                    lab8: while (true) {
+                        var v_9 = env.limit - env.cursor;
+                        lab9: while (true) {
+                            if (!env.eq_s_b("er")) {
+                                break lab9;
+                            }
+                            break lab8;
+                        }
+                        env.cursor = env.limit - v_9;
+                        if (!env.eq_s_b("en")) {
+                            env.cursor = env.limit - v_8;
+                            break lab7;
+                        }
+                        break lab8;
+                    }
+                    env.bra = env.cursor;
+                    if (!r_R1(env, context)) {
+                        env.cursor = env.limit - v_8;
+                        break lab7;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    break lab7;
+                }
+            } else if (among_var == 4) {
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_10 = env.limit - env.cursor;
+                lab10: while (true) {
+                    env.ket = env.cursor;
+                    among_var = env.find_among_b(A_3, context);
+                    if (among_var == 0) {
+                        env.cursor = env.limit - v_10;
+                        break lab10;
+                    }
+                    env.bra = env.cursor;
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_10;
+                        break lab10;
+                    }
+                    if (among_var == 0) {
+                        env.cursor = env.limit - v_10;
+                        break lab10;
+                    } else if (among_var == 1) {
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                    }
+                    break lab10;
+                }
+            }
+            break lab3;
+        }
+        env.cursor = env.limit - v_4;
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            i_x: 0,
+            i_p2: 0,
+            i_p1: 0,
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_prelude(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_2 = env.cursor;
+        lab1: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = v_2;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            if (!r_standard_suffix(env, context)) {
+                break lab2;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        env.cursor = env.limit_backward;
+        var v_4 = env.cursor;
+        lab3: while (true) {
+            if (!r_postlude(env, context)) {
+                break lab3;
+            }
+            break lab3;
+        }
+        env.cursor = v_4;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("de", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "aber", "alle", "allem", "allen", "aller", "alles", "als", "also",
+            "am", "an", "ander", "andere", "anderem", "anderen", "anderer",
+            "anderes", "anderm", "andern", "anderr", "anders", "auch", "auf", "aus",
+            "bei", "bin", "bis", "bist", "da", "damit", "dann", "das", "dasselbe",
+            "dazu", "da\u00DF", "dein", "deine", "deinem", "deinen", "deiner",
+            "deines", "dem", "demselben", "den", "denn", "denselben", "der",
+            "derer", "derselbe", "derselben", "des", "desselben", "dessen", "dich",
+            "die", "dies", "diese", "dieselbe", "dieselben", "diesem", "diesen",
+            "dieser", "dieses", "dir", "doch", "dort", "du", "durch", "ein", "eine",
+            "einem", "einen", "einer", "eines", "einig", "einige", "einigem",
+            "einigen", "einiger", "einiges", "einmal", "er", "es", "etwas", "euch",
+            "euer", "eure", "eurem", "euren", "eurer", "eures", "f\u00FCr", "gegen",
+            "gewesen", "hab", "habe", "haben", "hat", "hatte", "hatten", "hier",
+            "hin", "hinter", "ich", "ihm", "ihn", "ihnen", "ihr", "ihre", "ihrem",
+            "ihren", "ihrer", "ihres", "im", "in", "indem", "ins", "ist", "jede",
+            "jedem", "jeden", "jeder", "jedes", "jene", "jenem", "jenen", "jener",
+            "jenes", "jetzt", "kann", "kein", "keine", "keinem", "keinen", "keiner",
+            "keines", "k\u00F6nnen", "k\u00F6nnte", "machen", "man", "manche",
+            "manchem", "manchen", "mancher", "manches", "mein", "meine", "meinem",
+            "meinen", "meiner", "meines", "mich", "mir", "mit", "muss", "musste",
+            "nach", "nicht", "nichts", "noch", "nun", "nur", "ob", "oder", "ohne",
+            "sehr", "sein", "seine", "seinem", "seinen", "seiner", "seines",
+            "selbst", "sich", "sie", "sind", "so", "solche", "solchem", "solchen",
+            "solcher", "solches", "soll", "sollte", "sondern", "sonst", "um", "und",
+            "uns", "unse", "unsem", "unsen", "unser", "unses", "unter", "viel",
+            "vom", "von", "vor", "war", "waren", "warst", "was", "weg", "weil",
+            "weiter", "welche", "welchem", "welchen", "welcher", "welches", "wenn",
+            "werde", "werden", "wie", "wieder", "will", "wir", "wird", "wirst",
+            "wo", "wollen", "wollte", "w\u00E4hrend", "w\u00FCrde", "w\u00FCrden",
+            "zu", "zum", "zur", "zwar", "zwischen", "\u00FCber"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
@@ -0,0 +1,618 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for Dutch,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball Dutch stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("", -1, 6),
+        new Among("\u00E1", 0, 1),
+        new Among("\u00E4", 0, 1),
+        new Among("\u00E9", 0, 2),
+        new Among("\u00EB", 0, 2),
+        new Among("\u00ED", 0, 3),
+        new Among("\u00EF", 0, 3),
+        new Among("\u00F3", 0, 4),
+        new Among("\u00F6", 0, 4),
+        new Among("\u00FA", 0, 5),
+        new Among("\u00FC", 0, 5)
+    ];
+
+    var A_1 = [
+        new Among("", -1, 3),
+        new Among("I", 0, 2),
+        new Among("Y", 0, 1)
+    ];
+
+    var A_2 = [
+        new Among("dd", -1, -1),
+        new Among("kk", -1, -1),
+        new Among("tt", -1, -1)
+    ];
+
+    var A_3 = [
+        new Among("ene", -1, 2),
+        new Among("se", -1, 3),
+        new Among("en", -1, 2),
+        new Among("heden", 2, 1),
+        new Among("s", -1, 3)
+    ];
+
+    var A_4 = [
+        new Among("end", -1, 1),
+        new Among("ig", -1, 2),
+        new Among("ing", -1, 1),
+        new Among("lijk", -1, 3),
+        new Among("baar", -1, 4),
+        new Among("bar", -1, 5)
+    ];
+
+    var A_5 = [
+        new Among("aa", -1, -1),
+        new Among("ee", -1, -1),
+        new Among("oo", -1, -1),
+        new Among("uu", -1, -1)
+    ];
+
+    var G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128];
+
+    var G_v_I = [1, 0, 0, 17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128];
+
+    var G_v_j = [17, 67, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128];
+
+    function r_prelude(env, context) {
+        var among_var;
+        var v_1 = env.cursor;
+        replab0: while (true) {
+            var v_2 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_0, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_from("a")) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_from("e")) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (!env.slice_from("i")) {
+                        return false;
+                    }
+                } else if (among_var == 4) {
+                    if (!env.slice_from("o")) {
+                        return false;
+                    }
+                } else if (among_var == 5) {
+                    if (!env.slice_from("u")) {
+                        return false;
+                    }
+                } else if (among_var == 6) {
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_2;
+            break replab0;
+        }
+        env.cursor = v_1;
+        var v_3 = env.cursor;
+        lab2: while (true) {
+            env.bra = env.cursor;
+            if (!env.eq_s("y")) {
+                env.cursor = v_3;
+                break lab2;
+            }
+            env.ket = env.cursor;
+            if (!env.slice_from("Y")) {
+                return false;
+            }
+            break lab2;
+        }
+        replab3: while (true) {
+            var v_4 = env.cursor;
+            lab4: for (var lab4_i = 0; lab4_i < 1; lab4_i++) {
+                golab5: while (true) {
+                    var v_5 = env.cursor;
+                    lab6: while (true) {
+                        if (!env.in_grouping(G_v, 97, 232)) {
+                            break lab6;
+                        }
+                        env.bra = env.cursor;
+                        lab7: while (true) {
+                            var v_6 = env.cursor;
+                            lab8: while (true) {
+                                if (!env.eq_s("i")) {
+                                    break lab8;
+                                }
+                                env.ket = env.cursor;
+                                if (!env.in_grouping(G_v, 97, 232)) {
+                                    break lab8;
+                                }
+                                if (!env.slice_from("I")) {
+                                    return false;
+                                }
+                                break lab7;
+                            }
+                            env.cursor = v_6;
+                            if (!env.eq_s("y")) {
+                                break lab6;
+                            }
+                            env.ket = env.cursor;
+                            if (!env.slice_from("Y")) {
+                                return false;
+                            }
+                            break lab7;
+                        }
+                        env.cursor = v_5;
+                        break golab5;
+                    }
+                    env.cursor = v_5;
+                    if (env.cursor >= env.limit) {
+                        break lab4;
+                    }
+                    env.next_char();
+                }
+                continue replab3;
+            }
+            env.cursor = v_4;
+            break replab3;
+        }
+        return true;
+    }
+    function r_mark_regions(env, context) {
+        context.i_p1 = env.limit;
+        context.i_p2 = env.limit;
+        golab0: while (true) {
+            lab1: while (true) {
+                if (!env.in_grouping(G_v, 97, 232)) {
+                    break lab1;
+                }
+                break golab0;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        golab2: while (true) {
+            lab3: while (true) {
+                if (!env.out_grouping(G_v, 97, 232)) {
+                    break lab3;
+                }
+                break golab2;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        lab4: while (true) {
+            if (!(context.i_p1 < 3)) {
+                break lab4;
+            }
+            context.i_p1 = 3;
+            break lab4;
+        }
+        golab5: while (true) {
+            lab6: while (true) {
+                if (!env.in_grouping(G_v, 97, 232)) {
+                    break lab6;
+                }
+                break golab5;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        golab7: while (true) {
+            lab8: while (true) {
+                if (!env.out_grouping(G_v, 97, 232)) {
+                    break lab8;
+                }
+                break golab7;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        context.i_p2 = env.cursor;
+        return true;
+    }
+    function r_postlude(env, context) {
+        var among_var;
+        replab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_1, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_from("y")) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_from("i")) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_1;
+            break replab0;
+        }
+        return true;
+    }
+    function r_R1(env, context) {
+        if (!(context.i_p1 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R2(env, context) {
+        if (!(context.i_p2 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_undouble(env, context) {
+        var v_1 = env.limit - env.cursor;
+        if (env.find_among_b(A_2, context) == 0) {
+            return false;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        return true;
+    }
+    function r_e_ending(env, context) {
+        context.b_e_found = false;
+        env.ket = env.cursor;
+        if (!env.eq_s_b("e")) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (!r_R1(env, context)) {
+            return false;
+        }
+        var v_1 = env.limit - env.cursor;
+        if (!env.out_grouping_b(G_v, 97, 232)) {
+            return false;
+        }
+        env.cursor = env.limit - v_1;
+        if (!env.slice_del()) {
+            return false;
+        }
+        context.b_e_found = true;
+        if (!r_undouble(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_en_ending(env, context) {
+        if (!r_R1(env, context)) {
+            return false;
+        }
+        var v_1 = env.limit - env.cursor;
+        if (!env.out_grouping_b(G_v, 97, 232)) {
+            return false;
+        }
+        env.cursor = env.limit - v_1;
+        var v_2 = env.limit - env.cursor;
+        lab0: while (true) {
+            if (!env.eq_s_b("gem")) {
+                break lab0;
+            }
+            return false;
+        }
+        env.cursor = env.limit - v_2;
+        if (!env.slice_del()) {
+            return false;
+        }
+        if (!r_undouble(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_standard_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        lab0: while (true) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_3, context);
+            if (among_var == 0) {
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (among_var == 0) {
+                break lab0;
+            } else if (among_var == 1) {
+                if (!r_R1(env, context)) {
+                    break lab0;
+                }
+                if (!env.slice_from("heid")) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!r_en_ending(env, context)) {
+                    break lab0;
+                }
+            } else if (among_var == 3) {
+                if (!r_R1(env, context)) {
+                    break lab0;
+                }
+                if (!env.out_grouping_b(G_v_j, 97, 232)) {
+                    break lab0;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        var v_2 = env.limit - env.cursor;
+        lab1: while (true) {
+            if (!r_e_ending(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("heid")) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            if (!r_R2(env, context)) {
+                break lab2;
+            }
+            var v_4 = env.limit - env.cursor;
+            lab3: while (true) {
+                if (!env.eq_s_b("c")) {
+                    break lab3;
+                }
+                break lab2;
+            }
+            env.cursor = env.limit - v_4;
+            if (!env.slice_del()) {
+                return false;
+            }
+            env.ket = env.cursor;
+            if (!env.eq_s_b("en")) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            if (!r_en_ending(env, context)) {
+                break lab2;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_5 = env.limit - env.cursor;
+        lab4: while (true) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_4, context);
+            if (among_var == 0) {
+                break lab4;
+            }
+            env.bra = env.cursor;
+            if (among_var == 0) {
+                break lab4;
+            } else if (among_var == 1) {
+                if (!r_R2(env, context)) {
+                    break lab4;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                lab5: while (true) {
+                    var v_6 = env.limit - env.cursor;
+                    lab6: while (true) {
+                        env.ket = env.cursor;
+                        if (!env.eq_s_b("ig")) {
+                            break lab6;
+                        }
+                        env.bra = env.cursor;
+                        if (!r_R2(env, context)) {
+                            break lab6;
+                        }
+                        var v_7 = env.limit - env.cursor;
+                        lab7: while (true) {
+                            if (!env.eq_s_b("e")) {
+                                break lab7;
+                            }
+                            break lab6;
+                        }
+                        env.cursor = env.limit - v_7;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_6;
+                    if (!r_undouble(env, context)) {
+                        break lab4;
+                    }
+                    break lab5;
+                }
+            } else if (among_var == 2) {
+                if (!r_R2(env, context)) {
+                    break lab4;
+                }
+                var v_8 = env.limit - env.cursor;
+                lab8: while (true) {
+                    if (!env.eq_s_b("e")) {
+                        break lab8;
+                    }
+                    break lab4;
+                }
+                env.cursor = env.limit - v_8;
+                if (!env.slice_del()) {
+                    return false;
+                }
+            } else if (among_var == 3) {
+                if (!r_R2(env, context)) {
+                    break lab4;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                if (!r_e_ending(env, context)) {
+                    break lab4;
+                }
+            } else if (among_var == 4) {
+                if (!r_R2(env, context)) {
+                    break lab4;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            } else if (among_var == 5) {
+                if (!r_R2(env, context)) {
+                    break lab4;
+                }
+                if (!context.b_e_found) {
+                    break lab4;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab4;
+        }
+        env.cursor = env.limit - v_5;
+        var v_9 = env.limit - env.cursor;
+        lab9: while (true) {
+            if (!env.out_grouping_b(G_v_I, 73, 232)) {
+                break lab9;
+            }
+            var v_10 = env.limit - env.cursor;
+            if (env.find_among_b(A_5, context) == 0) {
+                break lab9;
+            }
+            if (!env.out_grouping_b(G_v, 97, 232)) {
+                break lab9;
+            }
+            env.cursor = env.limit - v_10;
+            env.ket = env.cursor;
+            if (env.cursor <= env.limit_backward) {
+                break lab9;
+            }
+            env.previous_char();
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab9;
+        }
+        env.cursor = env.limit - v_9;
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            i_p2: 0,
+            i_p1: 0,
+            b_e_found: false,
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_prelude(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_2 = env.cursor;
+        lab1: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = v_2;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            if (!r_standard_suffix(env, context)) {
+                break lab2;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        env.cursor = env.limit_backward;
+        var v_4 = env.cursor;
+        lab3: while (true) {
+            if (!r_postlude(env, context)) {
+                break lab3;
+            }
+            break lab3;
+        }
+        env.cursor = v_4;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("du", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "aan", "al", "alles", "als", "altijd", "andere", "ben", "bij",
+            "daar", "dan", "dat", "de", "der", "deze", "die", "dit", "doch", "doen",
+            "door", "dus", "een", "eens", "en", "er", "ge", "geen", "geweest",
+            "haar", "had", "heb", "hebben", "heeft", "hem", "het", "hier", "hij",
+            "hoe", "hun", "iemand", "iets", "ik", "in", "is", "ja", "je", "kan",
+            "kon", "kunnen", "maar", "me", "meer", "men", "met", "mij", "mijn",
+            "moet", "na", "naar", "niet", "niets", "nog", "nu", "of", "om", "omdat",
+            "onder", "ons", "ook", "op", "over", "reeds", "te", "tegen", "toch",
+            "toen", "tot", "u", "uit", "uw", "van", "veel", "voor", "want", "waren",
+            "was", "wat", "werd", "wezen", "wie", "wil", "worden", "wordt", "zal",
+            "ze", "zelf", "zich", "zij", "zijn", "zo", "zonder", "zou"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
@@ -0,0 +1,967 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for Spanish,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball Spanish stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("", -1, 6),
+        new Among("\u00E1", 0, 1),
+        new Among("\u00E9", 0, 2),
+        new Among("\u00ED", 0, 3),
+        new Among("\u00F3", 0, 4),
+        new Among("\u00FA", 0, 5)
+    ];
+
+    var A_1 = [
+        new Among("la", -1, -1),
+        new Among("sela", 0, -1),
+        new Among("le", -1, -1),
+        new Among("me", -1, -1),
+        new Among("se", -1, -1),
+        new Among("lo", -1, -1),
+        new Among("selo", 5, -1),
+        new Among("las", -1, -1),
+        new Among("selas", 7, -1),
+        new Among("les", -1, -1),
+        new Among("los", -1, -1),
+        new Among("selos", 10, -1),
+        new Among("nos", -1, -1)
+    ];
+
+    var A_2 = [
+        new Among("ando", -1, 6),
+        new Among("iendo", -1, 6),
+        new Among("yendo", -1, 7),
+        new Among("\u00E1ndo", -1, 2),
+        new Among("i\u00E9ndo", -1, 1),
+        new Among("ar", -1, 6),
+        new Among("er", -1, 6),
+        new Among("ir", -1, 6),
+        new Among("\u00E1r", -1, 3),
+        new Among("\u00E9r", -1, 4),
+        new Among("\u00EDr", -1, 5)
+    ];
+
+    var A_3 = [
+        new Among("ic", -1, -1),
+        new Among("ad", -1, -1),
+        new Among("os", -1, -1),
+        new Among("iv", -1, 1)
+    ];
+
+    var A_4 = [
+        new Among("able", -1, 1),
+        new Among("ible", -1, 1),
+        new Among("ante", -1, 1)
+    ];
+
+    var A_5 = [
+        new Among("ic", -1, 1),
+        new Among("abil", -1, 1),
+        new Among("iv", -1, 1)
+    ];
+
+    var A_6 = [
+        new Among("ica", -1, 1),
+        new Among("ancia", -1, 2),
+        new Among("encia", -1, 5),
+        new Among("adora", -1, 2),
+        new Among("osa", -1, 1),
+        new Among("ista", -1, 1),
+        new Among("iva", -1, 9),
+        new Among("anza", -1, 1),
+        new Among("log\u00EDa", -1, 3),
+        new Among("idad", -1, 8),
+        new Among("able", -1, 1),
+        new Among("ible", -1, 1),
+        new Among("ante", -1, 2),
+        new Among("mente", -1, 7),
+        new Among("amente", 13, 6),
+        new Among("aci\u00F3n", -1, 2),
+        new Among("uci\u00F3n", -1, 4),
+        new Among("ico", -1, 1),
+        new Among("ismo", -1, 1),
+        new Among("oso", -1, 1),
+        new Among("amiento", -1, 1),
+        new Among("imiento", -1, 1),
+        new Among("ivo", -1, 9),
+        new Among("ador", -1, 2),
+        new Among("icas", -1, 1),
+        new Among("ancias", -1, 2),
+        new Among("encias", -1, 5),
+        new Among("adoras", -1, 2),
+        new Among("osas", -1, 1),
+        new Among("istas", -1, 1),
+        new Among("ivas", -1, 9),
+        new Among("anzas", -1, 1),
+        new Among("log\u00EDas", -1, 3),
+        new Among("idades", -1, 8),
+        new Among("ables", -1, 1),
+        new Among("ibles", -1, 1),
+        new Among("aciones", -1, 2),
+        new Among("uciones", -1, 4),
+        new Among("adores", -1, 2),
+        new Among("antes", -1, 2),
+        new Among("icos", -1, 1),
+        new Among("ismos", -1, 1),
+        new Among("osos", -1, 1),
+        new Among("amientos", -1, 1),
+        new Among("imientos", -1, 1),
+        new Among("ivos", -1, 9)
+    ];
+
+    var A_7 = [
+        new Among("ya", -1, 1),
+        new Among("ye", -1, 1),
+        new Among("yan", -1, 1),
+        new Among("yen", -1, 1),
+        new Among("yeron", -1, 1),
+        new Among("yendo", -1, 1),
+        new Among("yo", -1, 1),
+        new Among("yas", -1, 1),
+        new Among("yes", -1, 1),
+        new Among("yais", -1, 1),
+        new Among("yamos", -1, 1),
+        new Among("y\u00F3", -1, 1)
+    ];
+
+    var A_8 = [
+        new Among("aba", -1, 2),
+        new Among("ada", -1, 2),
+        new Among("ida", -1, 2),
+        new Among("ara", -1, 2),
+        new Among("iera", -1, 2),
+        new Among("\u00EDa", -1, 2),
+        new Among("ar\u00EDa", 5, 2),
+        new Among("er\u00EDa", 5, 2),
+        new Among("ir\u00EDa", 5, 2),
+        new Among("ad", -1, 2),
+        new Among("ed", -1, 2),
+        new Among("id", -1, 2),
+        new Among("ase", -1, 2),
+        new Among("iese", -1, 2),
+        new Among("aste", -1, 2),
+        new Among("iste", -1, 2),
+        new Among("an", -1, 2),
+        new Among("aban", 16, 2),
+        new Among("aran", 16, 2),
+        new Among("ieran", 16, 2),
+        new Among("\u00EDan", 16, 2),
+        new Among("ar\u00EDan", 20, 2),
+        new Among("er\u00EDan", 20, 2),
+        new Among("ir\u00EDan", 20, 2),
+        new Among("en", -1, 1),
+        new Among("asen", 24, 2),
+        new Among("iesen", 24, 2),
+        new Among("aron", -1, 2),
+        new Among("ieron", -1, 2),
+        new Among("ar\u00E1n", -1, 2),
+        new Among("er\u00E1n", -1, 2),
+        new Among("ir\u00E1n", -1, 2),
+        new Among("ado", -1, 2),
+        new Among("ido", -1, 2),
+        new Among("ando", -1, 2),
+        new Among("iendo", -1, 2),
+        new Among("ar", -1, 2),
+        new Among("er", -1, 2),
+        new Among("ir", -1, 2),
+        new Among("as", -1, 2),
+        new Among("abas", 39, 2),
+        new Among("adas", 39, 2),
+        new Among("idas", 39, 2),
+        new Among("aras", 39, 2),
+        new Among("ieras", 39, 2),
+        new Among("\u00EDas", 39, 2),
+        new Among("ar\u00EDas", 45, 2),
+        new Among("er\u00EDas", 45, 2),
+        new Among("ir\u00EDas", 45, 2),
+        new Among("es", -1, 1),
+        new Among("ases", 49, 2),
+        new Among("ieses", 49, 2),
+        new Among("abais", -1, 2),
+        new Among("arais", -1, 2),
+        new Among("ierais", -1, 2),
+        new Among("\u00EDais", -1, 2),
+        new Among("ar\u00EDais", 55, 2),
+        new Among("er\u00EDais", 55, 2),
+        new Among("ir\u00EDais", 55, 2),
+        new Among("aseis", -1, 2),
+        new Among("ieseis", -1, 2),
+        new Among("asteis", -1, 2),
+        new Among("isteis", -1, 2),
+        new Among("\u00E1is", -1, 2),
+        new Among("\u00E9is", -1, 1),
+        new Among("ar\u00E9is", 64, 2),
+        new Among("er\u00E9is", 64, 2),
+        new Among("ir\u00E9is", 64, 2),
+        new Among("ados", -1, 2),
+        new Among("idos", -1, 2),
+        new Among("amos", -1, 2),
+        new Among("\u00E1bamos", 70, 2),
+        new Among("\u00E1ramos", 70, 2),
+        new Among("i\u00E9ramos", 70, 2),
+        new Among("\u00EDamos", 70, 2),
+        new Among("ar\u00EDamos", 74, 2),
+        new Among("er\u00EDamos", 74, 2),
+        new Among("ir\u00EDamos", 74, 2),
+        new Among("emos", -1, 1),
+        new Among("aremos", 78, 2),
+        new Among("eremos", 78, 2),
+        new Among("iremos", 78, 2),
+        new Among("\u00E1semos", 78, 2),
+        new Among("i\u00E9semos", 78, 2),
+        new Among("imos", -1, 2),
+        new Among("ar\u00E1s", -1, 2),
+        new Among("er\u00E1s", -1, 2),
+        new Among("ir\u00E1s", -1, 2),
+        new Among("\u00EDs", -1, 2),
+        new Among("ar\u00E1", -1, 2),
+        new Among("er\u00E1", -1, 2),
+        new Among("ir\u00E1", -1, 2),
+        new Among("ar\u00E9", -1, 2),
+        new Among("er\u00E9", -1, 2),
+        new Among("ir\u00E9", -1, 2),
+        new Among("i\u00F3", -1, 2)
+    ];
+
+    var A_9 = [
+        new Among("a", -1, 1),
+        new Among("e", -1, 2),
+        new Among("o", -1, 1),
+        new Among("os", -1, 1),
+        new Among("\u00E1", -1, 1),
+        new Among("\u00E9", -1, 2),
+        new Among("\u00ED", -1, 1),
+        new Among("\u00F3", -1, 1)
+    ];
+
+    var G_v = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 17, 4, 10];
+
+    function r_mark_regions(env, context) {
+        context.i_pV = env.limit;
+        context.i_p1 = env.limit;
+        context.i_p2 = env.limit;
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            lab1: while (true) {
+                var v_2 = env.cursor;
+                lab2: while (true) {
+                    if (!env.in_grouping(G_v, 97, 252)) {
+                        break lab2;
+                    }
+                    lab3: while (true) {
+                        var v_3 = env.cursor;
+                        lab4: while (true) {
+                            if (!env.out_grouping(G_v, 97, 252)) {
+                                break lab4;
+                            }
+                            golab5: while (true) {
+                                lab6: while (true) {
+                                    if (!env.in_grouping(G_v, 97, 252)) {
+                                        break lab6;
+                                    }
+                                    break golab5;
+                                }
+                                if (env.cursor >= env.limit) {
+                                    break lab4;
+                                }
+                                env.next_char();
+                            }
+                            break lab3;
+                        }
+                        env.cursor = v_3;
+                        if (!env.in_grouping(G_v, 97, 252)) {
+                            break lab2;
+                        }
+                        golab7: while (true) {
+                            lab8: while (true) {
+                                if (!env.out_grouping(G_v, 97, 252)) {
+                                    break lab8;
+                                }
+                                break golab7;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab2;
+                            }
+                            env.next_char();
+                        }
+                        break lab3;
+                    }
+                    break lab1;
+                }
+                env.cursor = v_2;
+                if (!env.out_grouping(G_v, 97, 252)) {
+                    break lab0;
+                }
+                lab9: while (true) {
+                    var v_6 = env.cursor;
+                    lab10: while (true) {
+                        if (!env.out_grouping(G_v, 97, 252)) {
+                            break lab10;
+                        }
+                        golab11: while (true) {
+                            lab12: while (true) {
+                                if (!env.in_grouping(G_v, 97, 252)) {
+                                    break lab12;
+                                }
+                                break golab11;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab10;
+                            }
+                            env.next_char();
+                        }
+                        break lab9;
+                    }
+                    env.cursor = v_6;
+                    if (!env.in_grouping(G_v, 97, 252)) {
+                        break lab0;
+                    }
+                    if (env.cursor >= env.limit) {
+                        break lab0;
+                    }
+                    env.next_char();
+                    break lab9;
+                }
+                break lab1;
+            }
+            context.i_pV = env.cursor;
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_8 = env.cursor;
+        lab13: while (true) {
+            golab14: while (true) {
+                lab15: while (true) {
+                    if (!env.in_grouping(G_v, 97, 252)) {
+                        break lab15;
+                    }
+                    break golab14;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            golab16: while (true) {
+                lab17: while (true) {
+                    if (!env.out_grouping(G_v, 97, 252)) {
+                        break lab17;
+                    }
+                    break golab16;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            context.i_p1 = env.cursor;
+            golab18: while (true) {
+                lab19: while (true) {
+                    if (!env.in_grouping(G_v, 97, 252)) {
+                        break lab19;
+                    }
+                    break golab18;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            golab20: while (true) {
+                lab21: while (true) {
+                    if (!env.out_grouping(G_v, 97, 252)) {
+                        break lab21;
+                    }
+                    break golab20;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            context.i_p2 = env.cursor;
+            break lab13;
+        }
+        env.cursor = v_8;
+        return true;
+    }
+    function r_postlude(env, context) {
+        var among_var;
+        replab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_0, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_from("a")) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_from("e")) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (!env.slice_from("i")) {
+                        return false;
+                    }
+                } else if (among_var == 4) {
+                    if (!env.slice_from("o")) {
+                        return false;
+                    }
+                } else if (among_var == 5) {
+                    if (!env.slice_from("u")) {
+                        return false;
+                    }
+                } else if (among_var == 6) {
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_1;
+            break replab0;
+        }
+        return true;
+    }
+    function r_RV(env, context) {
+        if (!(context.i_pV <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R1(env, context) {
+        if (!(context.i_p1 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R2(env, context) {
+        if (!(context.i_p2 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_attached_pronoun(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_1, context) == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        among_var = env.find_among_b(A_2, context);
+        if (among_var == 0) {
+            return false;
+        }
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            env.bra = env.cursor;
+            if (!env.slice_from("iendo")) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            env.bra = env.cursor;
+            if (!env.slice_from("ando")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            env.bra = env.cursor;
+            if (!env.slice_from("ar")) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            env.bra = env.cursor;
+            if (!env.slice_from("er")) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            env.bra = env.cursor;
+            if (!env.slice_from("ir")) {
+                return false;
+            }
+        } else if (among_var == 6) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 7) {
+            if (!env.eq_s_b("u")) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_standard_suffix(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_6, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_1 = env.limit - env.cursor;
+            lab0: while (true) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("ic")) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab0;
+            }
+        } else if (among_var == 3) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("log")) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("u")) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("ente")) {
+                return false;
+            }
+        } else if (among_var == 6) {
+            if (!r_R1(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_2 = env.limit - env.cursor;
+            lab1: while (true) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_3, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                } else if (among_var == 1) {
+                    env.ket = env.cursor;
+                    if (!env.eq_s_b("at")) {
+                        env.cursor = env.limit - v_2;
+                        break lab1;
+                    }
+                    env.bra = env.cursor;
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_2;
+                        break lab1;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                }
+                break lab1;
+            }
+        } else if (among_var == 7) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_3 = env.limit - env.cursor;
+            lab2: while (true) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_4, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_3;
+                    break lab2;
+                }
+                env.bra = env.cursor;
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_3;
+                    break lab2;
+                } else if (among_var == 1) {
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_3;
+                        break lab2;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                }
+                break lab2;
+            }
+        } else if (among_var == 8) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_4 = env.limit - env.cursor;
+            lab3: while (true) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_5, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_4;
+                    break lab3;
+                }
+                env.bra = env.cursor;
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_4;
+                    break lab3;
+                } else if (among_var == 1) {
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_4;
+                        break lab3;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                }
+                break lab3;
+            }
+        } else if (among_var == 9) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_5 = env.limit - env.cursor;
+            lab4: while (true) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("at")) {
+                    env.cursor = env.limit - v_5;
+                    break lab4;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_5;
+                    break lab4;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab4;
+            }
+        }
+        return true;
+    }
+    function r_y_verb_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_pV) {
+            return false;
+        }
+        env.cursor = context.i_pV;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_7, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_2;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.eq_s_b("u")) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_verb_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_pV) {
+            return false;
+        }
+        env.cursor = context.i_pV;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_8, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_2;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            var v_3 = env.limit - env.cursor;
+            lab0: while (true) {
+                if (!env.eq_s_b("u")) {
+                    env.cursor = env.limit - v_3;
+                    break lab0;
+                }
+                var v_4 = env.limit - env.cursor;
+                if (!env.eq_s_b("g")) {
+                    env.cursor = env.limit - v_3;
+                    break lab0;
+                }
+                env.cursor = env.limit - v_4;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_residual_suffix(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_9, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_1 = env.limit - env.cursor;
+            lab0: while (true) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("u")) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                env.bra = env.cursor;
+                var v_2 = env.limit - env.cursor;
+                if (!env.eq_s_b("g")) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                env.cursor = env.limit - v_2;
+                if (!r_RV(env, context)) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab0;
+            }
+        }
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            i_p2: 0,
+            i_p1: 0,
+            i_pV: 0,
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_2 = env.limit - env.cursor;
+        lab1: while (true) {
+            if (!r_attached_pronoun(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            lab3: while (true) {
+                var v_4 = env.limit - env.cursor;
+                lab4: while (true) {
+                    if (!r_standard_suffix(env, context)) {
+                        break lab4;
+                    }
+                    break lab3;
+                }
+                env.cursor = env.limit - v_4;
+                lab5: while (true) {
+                    if (!r_y_verb_suffix(env, context)) {
+                        break lab5;
+                    }
+                    break lab3;
+                }
+                env.cursor = env.limit - v_4;
+                if (!r_verb_suffix(env, context)) {
+                    break lab2;
+                }
+                break lab3;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_5 = env.limit - env.cursor;
+        lab6: while (true) {
+            if (!r_residual_suffix(env, context)) {
+                break lab6;
+            }
+            break lab6;
+        }
+        env.cursor = env.limit - v_5;
+        env.cursor = env.limit_backward;
+        var v_6 = env.cursor;
+        lab7: while (true) {
+            if (!r_postlude(env, context)) {
+                break lab7;
+            }
+            break lab7;
+        }
+        env.cursor = v_6;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("es", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "a", "al", "algo", "algunas", "algunos", "ante", "antes", "como",
+            "con", "contra", "cual", "cuando", "de", "del", "desde", "donde",
+            "durante", "e", "el", "ella", "ellas", "ellos", "en", "entre", "era",
+            "erais", "eran", "eras", "eres", "es", "esa", "esas", "ese", "eso",
+            "esos", "esta", "estaba", "estabais", "estaban", "estabas", "estad",
+            "estada", "estadas", "estado", "estados", "estamos", "estando", "estar",
+            "estaremos", "estar\u00E1", "estar\u00E1n", "estar\u00E1s",
+            "estar\u00E9", "estar\u00E9is", "estar\u00EDa", "estar\u00EDais",
+            "estar\u00EDamos", "estar\u00EDan", "estar\u00EDas", "estas", "este",
+            "estemos", "esto", "estos", "estoy", "estuve", "estuviera",
+            "estuvierais", "estuvieran", "estuvieras", "estuvieron", "estuviese",
+            "estuvieseis", "estuviesen", "estuvieses", "estuvimos", "estuviste",
+            "estuvisteis", "estuvi\u00E9ramos", "estuvi\u00E9semos", "estuvo",
+            "est\u00E1", "est\u00E1bamos", "est\u00E1is", "est\u00E1n",
+            "est\u00E1s", "est\u00E9", "est\u00E9is", "est\u00E9n", "est\u00E9s",
+            "fue", "fuera", "fuerais", "fueran", "fueras", "fueron", "fuese",
+            "fueseis", "fuesen", "fueses", "fui", "fuimos", "fuiste", "fuisteis",
+            "fu\u00E9ramos", "fu\u00E9semos", "ha", "habida", "habidas", "habido",
+            "habidos", "habiendo", "habremos", "habr\u00E1", "habr\u00E1n",
+            "habr\u00E1s", "habr\u00E9", "habr\u00E9is", "habr\u00EDa",
+            "habr\u00EDais", "habr\u00EDamos", "habr\u00EDan", "habr\u00EDas",
+            "hab\u00E9is", "hab\u00EDa", "hab\u00EDais", "hab\u00EDamos",
+            "hab\u00EDan", "hab\u00EDas", "han", "has", "hasta", "hay", "haya",
+            "hayamos", "hayan", "hayas", "hay\u00E1is", "he", "hemos", "hube",
+            "hubiera", "hubierais", "hubieran", "hubieras", "hubieron", "hubiese",
+            "hubieseis", "hubiesen", "hubieses", "hubimos", "hubiste", "hubisteis",
+            "hubi\u00E9ramos", "hubi\u00E9semos", "hubo", "la", "las", "le", "les",
+            "lo", "los", "me", "mi", "mis", "mucho", "muchos", "muy", "m\u00E1s",
+            "m\u00ED", "m\u00EDa", "m\u00EDas", "m\u00EDo", "m\u00EDos", "nada",
+            "ni", "no", "nos", "nosotras", "nosotros", "nuestra", "nuestras",
+            "nuestro", "nuestros", "o", "os", "otra", "otras", "otro", "otros",
+            "para", "pero", "poco", "por", "porque", "que", "quien", "quienes",
+            "qu\u00E9", "se", "sea", "seamos", "sean", "seas", "seremos",
+            "ser\u00E1", "ser\u00E1n", "ser\u00E1s", "ser\u00E9", "ser\u00E9is",
+            "ser\u00EDa", "ser\u00EDais", "ser\u00EDamos", "ser\u00EDan",
+            "ser\u00EDas", "se\u00E1is", "sido", "siendo", "sin", "sobre", "sois",
+            "somos", "son", "soy", "su", "sus", "suya", "suyas", "suyo", "suyos",
+            "s\u00ED", "tambi\u00E9n", "tanto", "te", "tendremos", "tendr\u00E1",
+            "tendr\u00E1n", "tendr\u00E1s", "tendr\u00E9", "tendr\u00E9is",
+            "tendr\u00EDa", "tendr\u00EDais", "tendr\u00EDamos", "tendr\u00EDan",
+            "tendr\u00EDas", "tened", "tenemos", "tenga", "tengamos", "tengan",
+            "tengas", "tengo", "teng\u00E1is", "tenida", "tenidas", "tenido",
+            "tenidos", "teniendo", "ten\u00E9is", "ten\u00EDa", "ten\u00EDais",
+            "ten\u00EDamos", "ten\u00EDan", "ten\u00EDas", "ti", "tiene", "tienen",
+            "tienes", "todo", "todos", "tu", "tus", "tuve", "tuviera", "tuvierais",
+            "tuvieran", "tuvieras", "tuvieron", "tuviese", "tuvieseis", "tuviesen",
+            "tuvieses", "tuvimos", "tuviste", "tuvisteis", "tuvi\u00E9ramos",
+            "tuvi\u00E9semos", "tuvo", "tuya", "tuyas", "tuyo", "tuyos", "t\u00FA",
+            "un", "una", "uno", "unos", "vosotras", "vosotros", "vuestra",
+            "vuestras", "vuestro", "vuestros", "y", "ya", "yo", "\u00E9l",
+            "\u00E9ramos"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
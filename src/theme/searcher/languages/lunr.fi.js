@@ -0,0 +1,797 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for Finnish,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball Finnish stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("pa", -1, 1),
+        new Among("sti", -1, 2),
+        new Among("kaan", -1, 1),
+        new Among("han", -1, 1),
+        new Among("kin", -1, 1),
+        new Among("h\u00E4n", -1, 1),
+        new Among("k\u00E4\u00E4n", -1, 1),
+        new Among("ko", -1, 1),
+        new Among("p\u00E4", -1, 1),
+        new Among("k\u00F6", -1, 1)
+    ];
+
+    var A_1 = [
+        new Among("lla", -1, -1),
+        new Among("na", -1, -1),
+        new Among("ssa", -1, -1),
+        new Among("ta", -1, -1),
+        new Among("lta", 3, -1),
+        new Among("sta", 3, -1)
+    ];
+
+    var A_2 = [
+        new Among("ll\u00E4", -1, -1),
+        new Among("n\u00E4", -1, -1),
+        new Among("ss\u00E4", -1, -1),
+        new Among("t\u00E4", -1, -1),
+        new Among("lt\u00E4", 3, -1),
+        new Among("st\u00E4", 3, -1)
+    ];
+
+    var A_3 = [
+        new Among("lle", -1, -1),
+        new Among("ine", -1, -1)
+    ];
+
+    var A_4 = [
+        new Among("nsa", -1, 3),
+        new Among("mme", -1, 3),
+        new Among("nne", -1, 3),
+        new Among("ni", -1, 2),
+        new Among("si", -1, 1),
+        new Among("an", -1, 4),
+        new Among("en", -1, 6),
+        new Among("\u00E4n", -1, 5),
+        new Among("ns\u00E4", -1, 3)
+    ];
+
+    var A_5 = [
+        new Among("aa", -1, -1),
+        new Among("ee", -1, -1),
+        new Among("ii", -1, -1),
+        new Among("oo", -1, -1),
+        new Among("uu", -1, -1),
+        new Among("\u00E4\u00E4", -1, -1),
+        new Among("\u00F6\u00F6", -1, -1)
+    ];
+
+    var A_6 = [
+        new Among("a", -1, 8),
+        new Among("lla", 0, -1),
+        new Among("na", 0, -1),
+        new Among("ssa", 0, -1),
+        new Among("ta", 0, -1),
+        new Among("lta", 4, -1),
+        new Among("sta", 4, -1),
+        new Among("tta", 4, 9),
+        new Among("lle", -1, -1),
+        new Among("ine", -1, -1),
+        new Among("ksi", -1, -1),
+        new Among("n", -1, 7),
+        new Among("han", 11, 1),
+        new Among("den", 11, -1, r_VI),
+        new Among("seen", 11, -1, r_LONG),
+        new Among("hen", 11, 2),
+        new Among("tten", 11, -1, r_VI),
+        new Among("hin", 11, 3),
+        new Among("siin", 11, -1, r_VI),
+        new Among("hon", 11, 4),
+        new Among("h\u00E4n", 11, 5),
+        new Among("h\u00F6n", 11, 6),
+        new Among("\u00E4", -1, 8),
+        new Among("ll\u00E4", 22, -1),
+        new Among("n\u00E4", 22, -1),
+        new Among("ss\u00E4", 22, -1),
+        new Among("t\u00E4", 22, -1),
+        new Among("lt\u00E4", 26, -1),
+        new Among("st\u00E4", 26, -1),
+        new Among("tt\u00E4", 26, 9)
+    ];
+
+    var A_7 = [
+        new Among("eja", -1, -1),
+        new Among("mma", -1, 1),
+        new Among("imma", 1, -1),
+        new Among("mpa", -1, 1),
+        new Among("impa", 3, -1),
+        new Among("mmi", -1, 1),
+        new Among("immi", 5, -1),
+        new Among("mpi", -1, 1),
+        new Among("impi", 7, -1),
+        new Among("ej\u00E4", -1, -1),
+        new Among("mm\u00E4", -1, 1),
+        new Among("imm\u00E4", 10, -1),
+        new Among("mp\u00E4", -1, 1),
+        new Among("imp\u00E4", 12, -1)
+    ];
+
+    var A_8 = [
+        new Among("i", -1, -1),
+        new Among("j", -1, -1)
+    ];
+
+    var A_9 = [
+        new Among("mma", -1, 1),
+        new Among("imma", 0, -1)
+    ];
+
+    var G_AEI = [17, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8];
+
+    var G_V1 = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 32];
+
+    var G_V2 = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 32];
+
+    var G_particle_end = [17, 97, 24, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 32];
+
+    function r_mark_regions(env, context) {
+        context.i_p1 = env.limit;
+        context.i_p2 = env.limit;
+        golab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: while (true) {
+                if (!env.in_grouping(G_V1, 97, 246)) {
+                    break lab1;
+                }
+                env.cursor = v_1;
+                break golab0;
+            }
+            env.cursor = v_1;
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        golab2: while (true) {
+            lab3: while (true) {
+                if (!env.out_grouping(G_V1, 97, 246)) {
+                    break lab3;
+                }
+                break golab2;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        golab4: while (true) {
+            var v_3 = env.cursor;
+            lab5: while (true) {
+                if (!env.in_grouping(G_V1, 97, 246)) {
+                    break lab5;
+                }
+                env.cursor = v_3;
+                break golab4;
+            }
+            env.cursor = v_3;
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        golab6: while (true) {
+            lab7: while (true) {
+                if (!env.out_grouping(G_V1, 97, 246)) {
+                    break lab7;
+                }
+                break golab6;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        context.i_p2 = env.cursor;
+        return true;
+    }
+    function r_R2(env, context) {
+        if (!(context.i_p2 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_particle_etc(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_0, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_2;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.in_grouping_b(G_particle_end, 97, 246)) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        return true;
+    }
+    function r_possessive(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_4, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_2;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            var v_3 = env.limit - env.cursor;
+            lab0: while (true) {
+                if (!env.eq_s_b("k")) {
+                    break lab0;
+                }
+                return false;
+            }
+            env.cursor = env.limit - v_3;
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_del()) {
+                return false;
+            }
+            env.ket = env.cursor;
+            if (!env.eq_s_b("kse")) {
+                return false;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_from("ksi")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (env.find_among_b(A_1, context) == 0) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            if (env.find_among_b(A_2, context) == 0) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 6) {
+            if (env.find_among_b(A_3, context) == 0) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_LONG(env, context) {
+        if (env.find_among_b(A_5, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_VI(env, context) {
+        if (!env.eq_s_b("i")) {
+            return false;
+        }
+        if (!env.in_grouping_b(G_V2, 97, 246)) {
+            return false;
+        }
+        return true;
+    }
+    function r_case_ending(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_6, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_2;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.eq_s_b("a")) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.eq_s_b("e")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.eq_s_b("i")) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (!env.eq_s_b("o")) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            if (!env.eq_s_b("\u00E4")) {
+                return false;
+            }
+        } else if (among_var == 6) {
+            if (!env.eq_s_b("\u00F6")) {
+                return false;
+            }
+        } else if (among_var == 7) {
+            var v_3 = env.limit - env.cursor;
+            lab0: while (true) {
+                var v_4 = env.limit - env.cursor;
+                lab1: while (true) {
+                    var v_5 = env.limit - env.cursor;
+                    lab2: while (true) {
+                        if (!r_LONG(env, context)) {
+                            break lab2;
+                        }
+                        break lab1;
+                    }
+                    env.cursor = env.limit - v_5;
+                    if (!env.eq_s_b("ie")) {
+                        env.cursor = env.limit - v_3;
+                        break lab0;
+                    }
+                    break lab1;
+                }
+                env.cursor = env.limit - v_4;
+                if (env.cursor <= env.limit_backward) {
+                    env.cursor = env.limit - v_3;
+                    break lab0;
+                }
+                env.previous_char();
+                env.bra = env.cursor;
+                break lab0;
+            }
+        } else if (among_var == 8) {
+            if (!env.in_grouping_b(G_V1, 97, 246)) {
+                return false;
+            }
+            if (!env.out_grouping_b(G_V1, 97, 246)) {
+                return false;
+            }
+        } else if (among_var == 9) {
+            if (!env.eq_s_b("e")) {
+                return false;
+            }
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        context.b_ending_removed = true;
+        return true;
+    }
+    function r_other_endings(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p2) {
+            return false;
+        }
+        env.cursor = context.i_p2;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_7, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_2;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            var v_3 = env.limit - env.cursor;
+            lab0: while (true) {
+                if (!env.eq_s_b("po")) {
+                    break lab0;
+                }
+                return false;
+            }
+            env.cursor = env.limit - v_3;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        return true;
+    }
+    function r_i_plural(env, context) {
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_8, context) == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_2;
+        if (!env.slice_del()) {
+            return false;
+        }
+        return true;
+    }
+    function r_t_plural(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (!env.eq_s_b("t")) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        var v_3 = env.limit - env.cursor;
+        if (!env.in_grouping_b(G_V1, 97, 246)) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.cursor = env.limit - v_3;
+        if (!env.slice_del()) {
+            return false;
+        }
+        env.limit_backward = v_2;
+        var v_4 = env.limit - env.cursor;
+        if (env.cursor < context.i_p2) {
+            return false;
+        }
+        env.cursor = context.i_p2;
+        var v_5 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_4;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_9, context);
+        if (among_var == 0) {
+            env.limit_backward = v_5;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_5;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            var v_6 = env.limit - env.cursor;
+            lab0: while (true) {
+                if (!env.eq_s_b("po")) {
+                    break lab0;
+                }
+                return false;
+            }
+            env.cursor = env.limit - v_6;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        return true;
+    }
+    function r_tidy(env, context) {
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        var v_3 = env.limit - env.cursor;
+        lab0: while (true) {
+            var v_4 = env.limit - env.cursor;
+            if (!r_LONG(env, context)) {
+                break lab0;
+            }
+            env.cursor = env.limit - v_4;
+            env.ket = env.cursor;
+            if (env.cursor <= env.limit_backward) {
+                break lab0;
+            }
+            env.previous_char();
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_3;
+        var v_5 = env.limit - env.cursor;
+        lab1: while (true) {
+            env.ket = env.cursor;
+            if (!env.in_grouping_b(G_AEI, 97, 228)) {
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (!env.out_grouping_b(G_V1, 97, 246)) {
+                break lab1;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab1;
+        }
+        env.cursor = env.limit - v_5;
+        var v_6 = env.limit - env.cursor;
+        lab2: while (true) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("j")) {
+                break lab2;
+            }
+            env.bra = env.cursor;
+            lab3: while (true) {
+                var v_7 = env.limit - env.cursor;
+                lab4: while (true) {
+                    if (!env.eq_s_b("o")) {
+                        break lab4;
+                    }
+                    break lab3;
+                }
+                env.cursor = env.limit - v_7;
+                if (!env.eq_s_b("u")) {
+                    break lab2;
+                }
+                break lab3;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_6;
+        var v_8 = env.limit - env.cursor;
+        lab5: while (true) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("o")) {
+                break lab5;
+            }
+            env.bra = env.cursor;
+            if (!env.eq_s_b("j")) {
+                break lab5;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab5;
+        }
+        env.cursor = env.limit - v_8;
+        env.limit_backward = v_2;
+        golab6: while (true) {
+            var v_9 = env.limit - env.cursor;
+            lab7: while (true) {
+                if (!env.out_grouping_b(G_V1, 97, 246)) {
+                    break lab7;
+                }
+                env.cursor = env.limit - v_9;
+                break golab6;
+            }
+            env.cursor = env.limit - v_9;
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+        }
+        env.ket = env.cursor;
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+        env.bra = env.cursor;
+        context.S_x = env.slice_to();
+        if (context.S_x.length == 0) {
+            return false;
+        }
+        if (!env.eq_s_b(context.S_x)) {
+            return false;
+        }
+        if (!env.slice_del()) {
+            return false;
+        }
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            b_ending_removed: false,
+            S_x: "",
+            i_p2: 0,
+            i_p1: 0,
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        context.b_ending_removed = false;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_2 = env.limit - env.cursor;
+        lab1: while (true) {
+            if (!r_particle_etc(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            if (!r_possessive(env, context)) {
+                break lab2;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_4 = env.limit - env.cursor;
+        lab3: while (true) {
+            if (!r_case_ending(env, context)) {
+                break lab3;
+            }
+            break lab3;
+        }
+        env.cursor = env.limit - v_4;
+        var v_5 = env.limit - env.cursor;
+        lab4: while (true) {
+            if (!r_other_endings(env, context)) {
+                break lab4;
+            }
+            break lab4;
+        }
+        env.cursor = env.limit - v_5;
+        lab5: while (true) {
+            var v_6 = env.limit - env.cursor;
+            lab6: while (true) {
+                if (!context.b_ending_removed) {
+                    break lab6;
+                }
+                var v_7 = env.limit - env.cursor;
+                lab7: while (true) {
+                    if (!r_i_plural(env, context)) {
+                        break lab7;
+                    }
+                    break lab7;
+                }
+                env.cursor = env.limit - v_7;
+                break lab5;
+            }
+            env.cursor = env.limit - v_6;
+            var v_8 = env.limit - env.cursor;
+            lab8: while (true) {
+                if (!r_t_plural(env, context)) {
+                    break lab8;
+                }
+                break lab8;
+            }
+            env.cursor = env.limit - v_8;
+            break lab5;
+        }
+        var v_9 = env.limit - env.cursor;
+        lab9: while (true) {
+            if (!r_tidy(env, context)) {
+                break lab9;
+            }
+            break lab9;
+        }
+        env.cursor = env.limit - v_9;
+        env.cursor = env.limit_backward;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("fi", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "ei", "eiv\u00E4t", "emme", "en", "et", "ette", "ett\u00E4", "he",
+            "heid\u00E4n", "heid\u00E4t", "heihin", "heille", "heill\u00E4",
+            "heilt\u00E4", "heiss\u00E4", "heist\u00E4", "heit\u00E4", "h\u00E4n",
+            "h\u00E4neen", "h\u00E4nelle", "h\u00E4nell\u00E4", "h\u00E4nelt\u00E4",
+            "h\u00E4nen", "h\u00E4ness\u00E4", "h\u00E4nest\u00E4", "h\u00E4net",
+            "h\u00E4nt\u00E4", "itse", "ja", "johon", "joiden", "joihin", "joiksi",
+            "joilla", "joille", "joilta", "joina", "joissa", "joista", "joita",
+            "joka", "joksi", "jolla", "jolle", "jolta", "jona", "jonka", "jos",
+            "jossa", "josta", "jota", "jotka", "kanssa", "keiden", "keihin",
+            "keiksi", "keille", "keill\u00E4", "keilt\u00E4", "kein\u00E4",
+            "keiss\u00E4", "keist\u00E4", "keit\u00E4", "keneen", "keneksi",
+            "kenelle", "kenell\u00E4", "kenelt\u00E4", "kenen", "kenen\u00E4",
+            "keness\u00E4", "kenest\u00E4", "kenet", "ketk\u00E4", "ketk\u00E4",
+            "ket\u00E4", "koska", "kuin", "kuka", "kun", "me", "meid\u00E4n",
+            "meid\u00E4t", "meihin", "meille", "meill\u00E4", "meilt\u00E4",
+            "meiss\u00E4", "meist\u00E4", "meit\u00E4", "mihin", "miksi",
+            "mik\u00E4", "mille", "mill\u00E4", "milt\u00E4", "mink\u00E4",
+            "mink\u00E4", "minua", "minulla", "minulle", "minulta", "minun",
+            "minussa", "minusta", "minut", "minuun", "min\u00E4", "min\u00E4",
+            "miss\u00E4", "mist\u00E4", "mitk\u00E4", "mit\u00E4", "mukaan",
+            "mutta", "ne", "niiden", "niihin", "niiksi", "niille", "niill\u00E4",
+            "niilt\u00E4", "niin", "niin", "niin\u00E4", "niiss\u00E4",
+            "niist\u00E4", "niit\u00E4", "noiden", "noihin", "noiksi", "noilla",
+            "noille", "noilta", "noin", "noina", "noissa", "noista", "noita", "nuo",
+            "nyt", "n\u00E4iden", "n\u00E4ihin", "n\u00E4iksi", "n\u00E4ille",
+            "n\u00E4ill\u00E4", "n\u00E4ilt\u00E4", "n\u00E4in\u00E4",
+            "n\u00E4iss\u00E4", "n\u00E4ist\u00E4", "n\u00E4it\u00E4",
+            "n\u00E4m\u00E4", "ole", "olemme", "olen", "olet", "olette", "oli",
+            "olimme", "olin", "olisi", "olisimme", "olisin", "olisit", "olisitte",
+            "olisivat", "olit", "olitte", "olivat", "olla", "olleet", "ollut", "on",
+            "ovat", "poikki", "se", "sek\u00E4", "sen", "siihen", "siin\u00E4",
+            "siit\u00E4", "siksi", "sille", "sill\u00E4", "sill\u00E4",
+            "silt\u00E4", "sinua", "sinulla", "sinulle", "sinulta", "sinun",
+            "sinussa", "sinusta", "sinut", "sinuun", "sin\u00E4", "sin\u00E4",
+            "sit\u00E4", "tai", "te", "teid\u00E4n", "teid\u00E4t", "teihin",
+            "teille", "teill\u00E4", "teilt\u00E4", "teiss\u00E4", "teist\u00E4",
+            "teit\u00E4", "tuo", "tuohon", "tuoksi", "tuolla", "tuolle", "tuolta",
+            "tuon", "tuona", "tuossa", "tuosta", "tuota", "t\u00E4h\u00E4n",
+            "t\u00E4ksi", "t\u00E4lle", "t\u00E4ll\u00E4", "t\u00E4lt\u00E4",
+            "t\u00E4m\u00E4", "t\u00E4m\u00E4n", "t\u00E4n\u00E4",
+            "t\u00E4ss\u00E4", "t\u00E4st\u00E4", "t\u00E4t\u00E4", "vaan", "vai",
+            "vaikka", "yli"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
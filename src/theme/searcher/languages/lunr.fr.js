@@ -0,0 +1,1165 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for French,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball French stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("col", -1, -1),
+        new Among("par", -1, -1),
+        new Among("tap", -1, -1)
+    ];
+
+    var A_1 = [
+        new Among("", -1, 4),
+        new Among("I", 0, 1),
+        new Among("U", 0, 2),
+        new Among("Y", 0, 3)
+    ];
+
+    var A_2 = [
+        new Among("iqU", -1, 3),
+        new Among("abl", -1, 3),
+        new Among("I\u00E8r", -1, 4),
+        new Among("i\u00E8r", -1, 4),
+        new Among("eus", -1, 2),
+        new Among("iv", -1, 1)
+    ];
+
+    var A_3 = [
+        new Among("ic", -1, 2),
+        new Among("abil", -1, 1),
+        new Among("iv", -1, 3)
+    ];
+
+    var A_4 = [
+        new Among("iqUe", -1, 1),
+        new Among("atrice", -1, 2),
+        new Among("ance", -1, 1),
+        new Among("ence", -1, 5),
+        new Among("logie", -1, 3),
+        new Among("able", -1, 1),
+        new Among("isme", -1, 1),
+        new Among("euse", -1, 11),
+        new Among("iste", -1, 1),
+        new Among("ive", -1, 8),
+        new Among("if", -1, 8),
+        new Among("usion", -1, 4),
+        new Among("ation", -1, 2),
+        new Among("ution", -1, 4),
+        new Among("ateur", -1, 2),
+        new Among("iqUes", -1, 1),
+        new Among("atrices", -1, 2),
+        new Among("ances", -1, 1),
+        new Among("ences", -1, 5),
+        new Among("logies", -1, 3),
+        new Among("ables", -1, 1),
+        new Among("ismes", -1, 1),
+        new Among("euses", -1, 11),
+        new Among("istes", -1, 1),
+        new Among("ives", -1, 8),
+        new Among("ifs", -1, 8),
+        new Among("usions", -1, 4),
+        new Among("ations", -1, 2),
+        new Among("utions", -1, 4),
+        new Among("ateurs", -1, 2),
+        new Among("ments", -1, 15),
+        new Among("ements", 30, 6),
+        new Among("issements", 31, 12),
+        new Among("it\u00E9s", -1, 7),
+        new Among("ment", -1, 15),
+        new Among("ement", 34, 6),
+        new Among("issement", 35, 12),
+        new Among("amment", 34, 13),
+        new Among("emment", 34, 14),
+        new Among("aux", -1, 10),
+        new Among("eaux", 39, 9),
+        new Among("eux", -1, 1),
+        new Among("it\u00E9", -1, 7)
+    ];
+
+    var A_5 = [
+        new Among("ira", -1, 1),
+        new Among("ie", -1, 1),
+        new Among("isse", -1, 1),
+        new Among("issante", -1, 1),
+        new Among("i", -1, 1),
+        new Among("irai", 4, 1),
+        new Among("ir", -1, 1),
+        new Among("iras", -1, 1),
+        new Among("ies", -1, 1),
+        new Among("\u00EEmes", -1, 1),
+        new Among("isses", -1, 1),
+        new Among("issantes", -1, 1),
+        new Among("\u00EEtes", -1, 1),
+        new Among("is", -1, 1),
+        new Among("irais", 13, 1),
+        new Among("issais", 13, 1),
+        new Among("irions", -1, 1),
+        new Among("issions", -1, 1),
+        new Among("irons", -1, 1),
+        new Among("issons", -1, 1),
+        new Among("issants", -1, 1),
+        new Among("it", -1, 1),
+        new Among("irait", 21, 1),
+        new Among("issait", 21, 1),
+        new Among("issant", -1, 1),
+        new Among("iraIent", -1, 1),
+        new Among("issaIent", -1, 1),
+        new Among("irent", -1, 1),
+        new Among("issent", -1, 1),
+        new Among("iront", -1, 1),
+        new Among("\u00EEt", -1, 1),
+        new Among("iriez", -1, 1),
+        new Among("issiez", -1, 1),
+        new Among("irez", -1, 1),
+        new Among("issez", -1, 1)
+    ];
+
+    var A_6 = [
+        new Among("a", -1, 3),
+        new Among("era", 0, 2),
+        new Among("asse", -1, 3),
+        new Among("ante", -1, 3),
+        new Among("\u00E9e", -1, 2),
+        new Among("ai", -1, 3),
+        new Among("erai", 5, 2),
+        new Among("er", -1, 2),
+        new Among("as", -1, 3),
+        new Among("eras", 8, 2),
+        new Among("\u00E2mes", -1, 3),
+        new Among("asses", -1, 3),
+        new Among("antes", -1, 3),
+        new Among("\u00E2tes", -1, 3),
+        new Among("\u00E9es", -1, 2),
+        new Among("ais", -1, 3),
+        new Among("erais", 15, 2),
+        new Among("ions", -1, 1),
+        new Among("erions", 17, 2),
+        new Among("assions", 17, 3),
+        new Among("erons", -1, 2),
+        new Among("ants", -1, 3),
+        new Among("\u00E9s", -1, 2),
+        new Among("ait", -1, 3),
+        new Among("erait", 23, 2),
+        new Among("ant", -1, 3),
+        new Among("aIent", -1, 3),
+        new Among("eraIent", 26, 2),
+        new Among("\u00E8rent", -1, 2),
+        new Among("assent", -1, 3),
+        new Among("eront", -1, 2),
+        new Among("\u00E2t", -1, 3),
+        new Among("ez", -1, 2),
+        new Among("iez", 32, 2),
+        new Among("eriez", 33, 2),
+        new Among("assiez", 33, 3),
+        new Among("erez", 32, 2),
+        new Among("\u00E9", -1, 2)
+    ];
+
+    var A_7 = [
+        new Among("e", -1, 3),
+        new Among("I\u00E8re", 0, 2),
+        new Among("i\u00E8re", 0, 2),
+        new Among("ion", -1, 1),
+        new Among("Ier", -1, 2),
+        new Among("ier", -1, 2),
+        new Among("\u00EB", -1, 4)
+    ];
+
+    var A_8 = [
+        new Among("ell", -1, -1),
+        new Among("eill", -1, -1),
+        new Among("enn", -1, -1),
+        new Among("onn", -1, -1),
+        new Among("ett", -1, -1)
+    ];
+
+    var G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 130, 103, 8, 5];
+
+    var G_keep_with_s = [1, 65, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128];
+
+    function r_prelude(env, context) {
+        replab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                golab2: while (true) {
+                    var v_2 = env.cursor;
+                    lab3: while (true) {
+                        lab4: while (true) {
+                            var v_3 = env.cursor;
+                            lab5: while (true) {
+                                if (!env.in_grouping(G_v, 97, 251)) {
+                                    break lab5;
+                                }
+                                env.bra = env.cursor;
+                                lab6: while (true) {
+                                    var v_4 = env.cursor;
+                                    lab7: while (true) {
+                                        if (!env.eq_s("u")) {
+                                            break lab7;
+                                        }
+                                        env.ket = env.cursor;
+                                        if (!env.in_grouping(G_v, 97, 251)) {
+                                            break lab7;
+                                        }
+                                        if (!env.slice_from("U")) {
+                                            return false;
+                                        }
+                                        break lab6;
+                                    }
+                                    env.cursor = v_4;
+                                    lab8: while (true) {
+                                        if (!env.eq_s("i")) {
+                                            break lab8;
+                                        }
+                                        env.ket = env.cursor;
+                                        if (!env.in_grouping(G_v, 97, 251)) {
+                                            break lab8;
+                                        }
+                                        if (!env.slice_from("I")) {
+                                            return false;
+                                        }
+                                        break lab6;
+                                    }
+                                    env.cursor = v_4;
+                                    if (!env.eq_s("y")) {
+                                        break lab5;
+                                    }
+                                    env.ket = env.cursor;
+                                    if (!env.slice_from("Y")) {
+                                        return false;
+                                    }
+                                    break lab6;
+                                }
+                                break lab4;
+                            }
+                            env.cursor = v_3;
+                            lab9: while (true) {
+                                env.bra = env.cursor;
+                                if (!env.eq_s("y")) {
+                                    break lab9;
+                                }
+                                env.ket = env.cursor;
+                                if (!env.in_grouping(G_v, 97, 251)) {
+                                    break lab9;
+                                }
+                                if (!env.slice_from("Y")) {
+                                    return false;
+                                }
+                                break lab4;
+                            }
+                            env.cursor = v_3;
+                            if (!env.eq_s("q")) {
+                                break lab3;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.eq_s("u")) {
+                                break lab3;
+                            }
+                            env.ket = env.cursor;
+                            if (!env.slice_from("U")) {
+                                return false;
+                            }
+                            break lab4;
+                        }
+                        env.cursor = v_2;
+                        break golab2;
+                    }
+                    env.cursor = v_2;
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_1;
+            break replab0;
+        }
+        return true;
+    }
+    function r_mark_regions(env, context) {
+        context.i_pV = env.limit;
+        context.i_p1 = env.limit;
+        context.i_p2 = env.limit;
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            lab1: while (true) {
+                var v_2 = env.cursor;
+                lab2: while (true) {
+                    if (!env.in_grouping(G_v, 97, 251)) {
+                        break lab2;
+                    }
+                    if (!env.in_grouping(G_v, 97, 251)) {
+                        break lab2;
+                    }
+                    if (env.cursor >= env.limit) {
+                        break lab2;
+                    }
+                    env.next_char();
+                    break lab1;
+                }
+                env.cursor = v_2;
+                lab3: while (true) {
+                    if (env.find_among(A_0, context) == 0) {
+                        break lab3;
+                    }
+                    break lab1;
+                }
+                env.cursor = v_2;
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+                golab4: while (true) {
+                    lab5: while (true) {
+                        if (!env.in_grouping(G_v, 97, 251)) {
+                            break lab5;
+                        }
+                        break golab4;
+                    }
+                    if (env.cursor >= env.limit) {
+                        break lab0;
+                    }
+                    env.next_char();
+                }
+                break lab1;
+            }
+            context.i_pV = env.cursor;
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_4 = env.cursor;
+        lab6: while (true) {
+            golab7: while (true) {
+                lab8: while (true) {
+                    if (!env.in_grouping(G_v, 97, 251)) {
+                        break lab8;
+                    }
+                    break golab7;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab6;
+                }
+                env.next_char();
+            }
+            golab9: while (true) {
+                lab10: while (true) {
+                    if (!env.out_grouping(G_v, 97, 251)) {
+                        break lab10;
+                    }
+                    break golab9;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab6;
+                }
+                env.next_char();
+            }
+            context.i_p1 = env.cursor;
+            golab11: while (true) {
+                lab12: while (true) {
+                    if (!env.in_grouping(G_v, 97, 251)) {
+                        break lab12;
+                    }
+                    break golab11;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab6;
+                }
+                env.next_char();
+            }
+            golab13: while (true) {
+                lab14: while (true) {
+                    if (!env.out_grouping(G_v, 97, 251)) {
+                        break lab14;
+                    }
+                    break golab13;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab6;
+                }
+                env.next_char();
+            }
+            context.i_p2 = env.cursor;
+            break lab6;
+        }
+        env.cursor = v_4;
+        return true;
+    }
+    function r_postlude(env, context) {
+        var among_var;
+        replab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_1, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_from("i")) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_from("u")) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (!env.slice_from("y")) {
+                        return false;
+                    }
+                } else if (among_var == 4) {
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_1;
+            break replab0;
+        }
+        return true;
+    }
+    function r_RV(env, context) {
+        if (!(context.i_pV <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R1(env, context) {
+        if (!(context.i_p1 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R2(env, context) {
+        if (!(context.i_p2 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_standard_suffix(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_4, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_1 = env.limit - env.cursor;
+            lab0: while (true) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("ic")) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                env.bra = env.cursor;
+                lab1: while (true) {
+                    var v_2 = env.limit - env.cursor;
+                    lab2: while (true) {
+                        if (!r_R2(env, context)) {
+                            break lab2;
+                        }
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        break lab1;
+                    }
+                    env.cursor = env.limit - v_2;
+                    if (!env.slice_from("iqU")) {
+                        return false;
+                    }
+                    break lab1;
+                }
+                break lab0;
+            }
+        } else if (among_var == 3) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("log")) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("u")) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("ent")) {
+                return false;
+            }
+        } else if (among_var == 6) {
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_3 = env.limit - env.cursor;
+            lab3: while (true) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_2, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_3;
+                    break lab3;
+                }
+                env.bra = env.cursor;
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_3;
+                    break lab3;
+                } else if (among_var == 1) {
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_3;
+                        break lab3;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    env.ket = env.cursor;
+                    if (!env.eq_s_b("at")) {
+                        env.cursor = env.limit - v_3;
+                        break lab3;
+                    }
+                    env.bra = env.cursor;
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_3;
+                        break lab3;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    lab4: while (true) {
+                        var v_4 = env.limit - env.cursor;
+                        lab5: while (true) {
+                            if (!r_R2(env, context)) {
+                                break lab5;
+                            }
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            break lab4;
+                        }
+                        env.cursor = env.limit - v_4;
+                        if (!r_R1(env, context)) {
+                            env.cursor = env.limit - v_3;
+                            break lab3;
+                        }
+                        if (!env.slice_from("eux")) {
+                            return false;
+                        }
+                        break lab4;
+                    }
+                } else if (among_var == 3) {
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_3;
+                        break lab3;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                } else if (among_var == 4) {
+                    if (!r_RV(env, context)) {
+                        env.cursor = env.limit - v_3;
+                        break lab3;
+                    }
+                    if (!env.slice_from("i")) {
+                        return false;
+                    }
+                }
+                break lab3;
+            }
+        } else if (among_var == 7) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_5 = env.limit - env.cursor;
+            lab6: while (true) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_3, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_5;
+                    break lab6;
+                }
+                env.bra = env.cursor;
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_5;
+                    break lab6;
+                } else if (among_var == 1) {
+                    lab7: while (true) {
+                        var v_6 = env.limit - env.cursor;
+                        lab8: while (true) {
+                            if (!r_R2(env, context)) {
+                                break lab8;
+                            }
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            break lab7;
+                        }
+                        env.cursor = env.limit - v_6;
+                        if (!env.slice_from("abl")) {
+                            return false;
+                        }
+                        break lab7;
+                    }
+                } else if (among_var == 2) {
+                    lab9: while (true) {
+                        var v_7 = env.limit - env.cursor;
+                        lab10: while (true) {
+                            if (!r_R2(env, context)) {
+                                break lab10;
+                            }
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            break lab9;
+                        }
+                        env.cursor = env.limit - v_7;
+                        if (!env.slice_from("iqU")) {
+                            return false;
+                        }
+                        break lab9;
+                    }
+                } else if (among_var == 3) {
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_5;
+                        break lab6;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                }
+                break lab6;
+            }
+        } else if (among_var == 8) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_8 = env.limit - env.cursor;
+            lab11: while (true) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("at")) {
+                    env.cursor = env.limit - v_8;
+                    break lab11;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_8;
+                    break lab11;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                env.ket = env.cursor;
+                if (!env.eq_s_b("ic")) {
+                    env.cursor = env.limit - v_8;
+                    break lab11;
+                }
+                env.bra = env.cursor;
+                lab12: while (true) {
+                    var v_9 = env.limit - env.cursor;
+                    lab13: while (true) {
+                        if (!r_R2(env, context)) {
+                            break lab13;
+                        }
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        break lab12;
+                    }
+                    env.cursor = env.limit - v_9;
+                    if (!env.slice_from("iqU")) {
+                        return false;
+                    }
+                    break lab12;
+                }
+                break lab11;
+            }
+        } else if (among_var == 9) {
+            if (!env.slice_from("eau")) {
+                return false;
+            }
+        } else if (among_var == 10) {
+            if (!r_R1(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("al")) {
+                return false;
+            }
+        } else if (among_var == 11) {
+            lab14: while (true) {
+                var v_10 = env.limit - env.cursor;
+                lab15: while (true) {
+                    if (!r_R2(env, context)) {
+                        break lab15;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    break lab14;
+                }
+                env.cursor = env.limit - v_10;
+                if (!r_R1(env, context)) {
+                    return false;
+                }
+                if (!env.slice_from("eux")) {
+                    return false;
+                }
+                break lab14;
+            }
+        } else if (among_var == 12) {
+            if (!r_R1(env, context)) {
+                return false;
+            }
+            if (!env.out_grouping_b(G_v, 97, 251)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 13) {
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("ant")) {
+                return false;
+            }
+            return false;
+        } else if (among_var == 14) {
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("ent")) {
+                return false;
+            }
+            return false;
+        } else if (among_var == 15) {
+            var v_11 = env.limit - env.cursor;
+            if (!env.in_grouping_b(G_v, 97, 251)) {
+                return false;
+            }
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            env.cursor = env.limit - v_11;
+            if (!env.slice_del()) {
+                return false;
+            }
+            return false;
+        }
+        return true;
+    }
+    function r_i_verb_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_pV) {
+            return false;
+        }
+        env.cursor = context.i_pV;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_5, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        } else if (among_var == 1) {
+            if (!env.out_grouping_b(G_v, 97, 251)) {
+                env.limit_backward = v_2;
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        env.limit_backward = v_2;
+        return true;
+    }
+    function r_verb_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_pV) {
+            return false;
+        }
+        env.cursor = context.i_pV;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_6, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        } else if (among_var == 1) {
+            if (!r_R2(env, context)) {
+                env.limit_backward = v_2;
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_3 = env.limit - env.cursor;
+            lab0: while (true) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("e")) {
+                    env.cursor = env.limit - v_3;
+                    break lab0;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab0;
+            }
+        }
+        env.limit_backward = v_2;
+        return true;
+    }
+    function r_residual_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        lab0: while (true) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("s")) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            var v_2 = env.limit - env.cursor;
+            if (!env.out_grouping_b(G_keep_with_s, 97, 232)) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.cursor = env.limit - v_2;
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab0;
+        }
+        var v_3 = env.limit - env.cursor;
+        if (env.cursor < context.i_pV) {
+            return false;
+        }
+        env.cursor = context.i_pV;
+        var v_4 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_3;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_7, context);
+        if (among_var == 0) {
+            env.limit_backward = v_4;
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            env.limit_backward = v_4;
+            return false;
+        } else if (among_var == 1) {
+            if (!r_R2(env, context)) {
+                env.limit_backward = v_4;
+                return false;
+            }
+            lab1: while (true) {
+                var v_5 = env.limit - env.cursor;
+                lab2: while (true) {
+                    if (!env.eq_s_b("s")) {
+                        break lab2;
+                    }
+                    break lab1;
+                }
+                env.cursor = env.limit - v_5;
+                if (!env.eq_s_b("t")) {
+                    env.limit_backward = v_4;
+                    return false;
+                }
+                break lab1;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_from("i")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (!env.eq_s_b("gu")) {
+                env.limit_backward = v_4;
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        env.limit_backward = v_4;
+        return true;
+    }
+    function r_un_double(env, context) {
+        var v_1 = env.limit - env.cursor;
+        if (env.find_among_b(A_8, context) == 0) {
+            return false;
+        }
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        if (env.cursor <= env.limit_backward) {
+            return false;
+        }
+        env.previous_char();
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        return true;
+    }
+    function r_un_accent(env, context) {
+        var v_1 = 1;
+        replab0: while (true) {
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                if (!env.out_grouping_b(G_v, 97, 251)) {
+                    break lab1;
+                }
+                v_1 -= 1;
+                continue replab0;
+            }
+            break replab0;
+        }
+        if (v_1 > 0) {
+            return false;
+        }
+        env.ket = env.cursor;
+        lab2: while (true) {
+            var v_3 = env.limit - env.cursor;
+            lab3: while (true) {
+                if (!env.eq_s_b("\u00E9")) {
+                    break lab3;
+                }
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            if (!env.eq_s_b("\u00E8")) {
+                return false;
+            }
+            break lab2;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_from("e")) {
+            return false;
+        }
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            i_p2: 0,
+            i_p1: 0,
+            i_pV: 0,
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_prelude(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_2 = env.cursor;
+        lab1: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = v_2;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            lab3: while (true) {
+                var v_4 = env.limit - env.cursor;
+                lab4: while (true) {
+                    var v_5 = env.limit - env.cursor;
+                    lab5: while (true) {
+                        var v_6 = env.limit - env.cursor;
+                        lab6: while (true) {
+                            if (!r_standard_suffix(env, context)) {
+                                break lab6;
+                            }
+                            break lab5;
+                        }
+                        env.cursor = env.limit - v_6;
+                        lab7: while (true) {
+                            if (!r_i_verb_suffix(env, context)) {
+                                break lab7;
+                            }
+                            break lab5;
+                        }
+                        env.cursor = env.limit - v_6;
+                        if (!r_verb_suffix(env, context)) {
+                            break lab4;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_5;
+                    var v_7 = env.limit - env.cursor;
+                    lab8: while (true) {
+                        env.ket = env.cursor;
+                        lab9: while (true) {
+                            var v_8 = env.limit - env.cursor;
+                            lab10: while (true) {
+                                if (!env.eq_s_b("Y")) {
+                                    break lab10;
+                                }
+                                env.bra = env.cursor;
+                                if (!env.slice_from("i")) {
+                                    return false;
+                                }
+                                break lab9;
+                            }
+                            env.cursor = env.limit - v_8;
+                            if (!env.eq_s_b("\u00E7")) {
+                                env.cursor = env.limit - v_7;
+                                break lab8;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_from("c")) {
+                                return false;
+                            }
+                            break lab9;
+                        }
+                        break lab8;
+                    }
+                    break lab3;
+                }
+                env.cursor = env.limit - v_4;
+                if (!r_residual_suffix(env, context)) {
+                    break lab2;
+                }
+                break lab3;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_9 = env.limit - env.cursor;
+        lab11: while (true) {
+            if (!r_un_double(env, context)) {
+                break lab11;
+            }
+            break lab11;
+        }
+        env.cursor = env.limit - v_9;
+        var v_10 = env.limit - env.cursor;
+        lab12: while (true) {
+            if (!r_un_accent(env, context)) {
+                break lab12;
+            }
+            break lab12;
+        }
+        env.cursor = env.limit - v_10;
+        env.cursor = env.limit_backward;
+        var v_11 = env.cursor;
+        lab13: while (true) {
+            if (!r_postlude(env, context)) {
+                break lab13;
+            }
+            break lab13;
+        }
+        env.cursor = v_11;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("fr", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "ai", "aie", "aient", "aies", "ait", "as", "au", "aura", "aurai",
+            "auraient", "aurais", "aurait", "auras", "aurez", "auriez", "aurions",
+            "aurons", "auront", "aux", "avaient", "avais", "avait", "avec", "avez",
+            "aviez", "avions", "avons", "ayant", "ayez", "ayons", "c", "ce", "ceci",
+            "cel\u00E0", "ces", "cet", "cette", "d", "dans", "de", "des", "du",
+            "elle", "en", "es", "est", "et", "eu", "eue", "eues", "eurent", "eus",
+            "eusse", "eussent", "eusses", "eussiez", "eussions", "eut", "eux",
+            "e\u00FBmes", "e\u00FBt", "e\u00FBtes", "furent", "fus", "fusse",
+            "fussent", "fusses", "fussiez", "fussions", "fut", "f\u00FBmes",
+            "f\u00FBt", "f\u00FBtes", "ici", "il", "ils", "j", "je", "l", "la",
+            "le", "les", "leur", "leurs", "lui", "m", "ma", "mais", "me", "mes",
+            "moi", "mon", "m\u00EAme", "n", "ne", "nos", "notre", "nous", "on",
+            "ont", "ou", "par", "pas", "pour", "qu", "que", "quel", "quelle",
+            "quelles", "quels", "qui", "s", "sa", "sans", "se", "sera", "serai",
+            "seraient", "serais", "serait", "seras", "serez", "seriez", "serions",
+            "serons", "seront", "ses", "soi", "soient", "sois", "soit", "sommes",
+            "son", "sont", "soyez", "soyons", "suis", "sur", "t", "ta", "te", "tes",
+            "toi", "ton", "tu", "un", "une", "vos", "votre", "vous", "y", "\u00E0",
+            "\u00E9taient", "\u00E9tais", "\u00E9tait", "\u00E9tant", "\u00E9tiez",
+            "\u00E9tions", "\u00E9t\u00E9", "\u00E9t\u00E9e", "\u00E9t\u00E9es",
+            "\u00E9t\u00E9s", "\u00EAtes"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
@@ -0,0 +1,957 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for Italian,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball Italian stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("", -1, 7),
+        new Among("qu", 0, 6),
+        new Among("\u00E1", 0, 1),
+        new Among("\u00E9", 0, 2),
+        new Among("\u00ED", 0, 3),
+        new Among("\u00F3", 0, 4),
+        new Among("\u00FA", 0, 5)
+    ];
+
+    var A_1 = [
+        new Among("", -1, 3),
+        new Among("I", 0, 1),
+        new Among("U", 0, 2)
+    ];
+
+    var A_2 = [
+        new Among("la", -1, -1),
+        new Among("cela", 0, -1),
+        new Among("gliela", 0, -1),
+        new Among("mela", 0, -1),
+        new Among("tela", 0, -1),
+        new Among("vela", 0, -1),
+        new Among("le", -1, -1),
+        new Among("cele", 6, -1),
+        new Among("gliele", 6, -1),
+        new Among("mele", 6, -1),
+        new Among("tele", 6, -1),
+        new Among("vele", 6, -1),
+        new Among("ne", -1, -1),
+        new Among("cene", 12, -1),
+        new Among("gliene", 12, -1),
+        new Among("mene", 12, -1),
+        new Among("sene", 12, -1),
+        new Among("tene", 12, -1),
+        new Among("vene", 12, -1),
+        new Among("ci", -1, -1),
+        new Among("li", -1, -1),
+        new Among("celi", 20, -1),
+        new Among("glieli", 20, -1),
+        new Among("meli", 20, -1),
+        new Among("teli", 20, -1),
+        new Among("veli", 20, -1),
+        new Among("gli", 20, -1),
+        new Among("mi", -1, -1),
+        new Among("si", -1, -1),
+        new Among("ti", -1, -1),
+        new Among("vi", -1, -1),
+        new Among("lo", -1, -1),
+        new Among("celo", 31, -1),
+        new Among("glielo", 31, -1),
+        new Among("melo", 31, -1),
+        new Among("telo", 31, -1),
+        new Among("velo", 31, -1)
+    ];
+
+    var A_3 = [
+        new Among("ando", -1, 1),
+        new Among("endo", -1, 1),
+        new Among("ar", -1, 2),
+        new Among("er", -1, 2),
+        new Among("ir", -1, 2)
+    ];
+
+    var A_4 = [
+        new Among("ic", -1, -1),
+        new Among("abil", -1, -1),
+        new Among("os", -1, -1),
+        new Among("iv", -1, 1)
+    ];
+
+    var A_5 = [
+        new Among("ic", -1, 1),
+        new Among("abil", -1, 1),
+        new Among("iv", -1, 1)
+    ];
+
+    var A_6 = [
+        new Among("ica", -1, 1),
+        new Among("logia", -1, 3),
+        new Among("osa", -1, 1),
+        new Among("ista", -1, 1),
+        new Among("iva", -1, 9),
+        new Among("anza", -1, 1),
+        new Among("enza", -1, 5),
+        new Among("ice", -1, 1),
+        new Among("atrice", 7, 1),
+        new Among("iche", -1, 1),
+        new Among("logie", -1, 3),
+        new Among("abile", -1, 1),
+        new Among("ibile", -1, 1),
+        new Among("usione", -1, 4),
+        new Among("azione", -1, 2),
+        new Among("uzione", -1, 4),
+        new Among("atore", -1, 2),
+        new Among("ose", -1, 1),
+        new Among("ante", -1, 1),
+        new Among("mente", -1, 1),
+        new Among("amente", 19, 7),
+        new Among("iste", -1, 1),
+        new Among("ive", -1, 9),
+        new Among("anze", -1, 1),
+        new Among("enze", -1, 5),
+        new Among("ici", -1, 1),
+        new Among("atrici", 25, 1),
+        new Among("ichi", -1, 1),
+        new Among("abili", -1, 1),
+        new Among("ibili", -1, 1),
+        new Among("ismi", -1, 1),
+        new Among("usioni", -1, 4),
+        new Among("azioni", -1, 2),
+        new Among("uzioni", -1, 4),
+        new Among("atori", -1, 2),
+        new Among("osi", -1, 1),
+        new Among("anti", -1, 1),
+        new Among("amenti", -1, 6),
+        new Among("imenti", -1, 6),
+        new Among("isti", -1, 1),
+        new Among("ivi", -1, 9),
+        new Among("ico", -1, 1),
+        new Among("ismo", -1, 1),
+        new Among("oso", -1, 1),
+        new Among("amento", -1, 6),
+        new Among("imento", -1, 6),
+        new Among("ivo", -1, 9),
+        new Among("it\u00E0", -1, 8),
+        new Among("ist\u00E0", -1, 1),
+        new Among("ist\u00E8", -1, 1),
+        new Among("ist\u00EC", -1, 1)
+    ];
+
+    var A_7 = [
+        new Among("isca", -1, 1),
+        new Among("enda", -1, 1),
+        new Among("ata", -1, 1),
+        new Among("ita", -1, 1),
+        new Among("uta", -1, 1),
+        new Among("ava", -1, 1),
+        new Among("eva", -1, 1),
+        new Among("iva", -1, 1),
+        new Among("erebbe", -1, 1),
+        new Among("irebbe", -1, 1),
+        new Among("isce", -1, 1),
+        new Among("ende", -1, 1),
+        new Among("are", -1, 1),
+        new Among("ere", -1, 1),
+        new Among("ire", -1, 1),
+        new Among("asse", -1, 1),
+        new Among("ate", -1, 1),
+        new Among("avate", 16, 1),
+        new Among("evate", 16, 1),
+        new Among("ivate", 16, 1),
+        new Among("ete", -1, 1),
+        new Among("erete", 20, 1),
+        new Among("irete", 20, 1),
+        new Among("ite", -1, 1),
+        new Among("ereste", -1, 1),
+        new Among("ireste", -1, 1),
+        new Among("ute", -1, 1),
+        new Among("erai", -1, 1),
+        new Among("irai", -1, 1),
+        new Among("isci", -1, 1),
+        new Among("endi", -1, 1),
+        new Among("erei", -1, 1),
+        new Among("irei", -1, 1),
+        new Among("assi", -1, 1),
+        new Among("ati", -1, 1),
+        new Among("iti", -1, 1),
+        new Among("eresti", -1, 1),
+        new Among("iresti", -1, 1),
+        new Among("uti", -1, 1),
+        new Among("avi", -1, 1),
+        new Among("evi", -1, 1),
+        new Among("ivi", -1, 1),
+        new Among("isco", -1, 1),
+        new Among("ando", -1, 1),
+        new Among("endo", -1, 1),
+        new Among("Yamo", -1, 1),
+        new Among("iamo", -1, 1),
+        new Among("avamo", -1, 1),
+        new Among("evamo", -1, 1),
+        new Among("ivamo", -1, 1),
+        new Among("eremo", -1, 1),
+        new Among("iremo", -1, 1),
+        new Among("assimo", -1, 1),
+        new Among("ammo", -1, 1),
+        new Among("emmo", -1, 1),
+        new Among("eremmo", 54, 1),
+        new Among("iremmo", 54, 1),
+        new Among("immo", -1, 1),
+        new Among("ano", -1, 1),
+        new Among("iscano", 58, 1),
+        new Among("avano", 58, 1),
+        new Among("evano", 58, 1),
+        new Among("ivano", 58, 1),
+        new Among("eranno", -1, 1),
+        new Among("iranno", -1, 1),
+        new Among("ono", -1, 1),
+        new Among("iscono", 65, 1),
+        new Among("arono", 65, 1),
+        new Among("erono", 65, 1),
+        new Among("irono", 65, 1),
+        new Among("erebbero", -1, 1),
+        new Among("irebbero", -1, 1),
+        new Among("assero", -1, 1),
+        new Among("essero", -1, 1),
+        new Among("issero", -1, 1),
+        new Among("ato", -1, 1),
+        new Among("ito", -1, 1),
+        new Among("uto", -1, 1),
+        new Among("avo", -1, 1),
+        new Among("evo", -1, 1),
+        new Among("ivo", -1, 1),
+        new Among("ar", -1, 1),
+        new Among("ir", -1, 1),
+        new Among("er\u00E0", -1, 1),
+        new Among("ir\u00E0", -1, 1),
+        new Among("er\u00F2", -1, 1),
+        new Among("ir\u00F2", -1, 1)
+    ];
+
+    var G_v = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 128, 8, 2, 1];
+
+    var G_AEIO = [17, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 128, 8, 2];
+
+    var G_CG = [17];
+
+    function r_prelude(env, context) {
+        var among_var;
+        var v_1 = env.cursor;
+        replab0: while (true) {
+            var v_2 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_0, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_from("\u00E0")) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_from("\u00E8")) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (!env.slice_from("\u00EC")) {
+                        return false;
+                    }
+                } else if (among_var == 4) {
+                    if (!env.slice_from("\u00F2")) {
+                        return false;
+                    }
+                } else if (among_var == 5) {
+                    if (!env.slice_from("\u00F9")) {
+                        return false;
+                    }
+                } else if (among_var == 6) {
+                    if (!env.slice_from("qU")) {
+                        return false;
+                    }
+                } else if (among_var == 7) {
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_2;
+            break replab0;
+        }
+        env.cursor = v_1;
+        replab2: while (true) {
+            var v_3 = env.cursor;
+            lab3: for (var lab3_i = 0; lab3_i < 1; lab3_i++) {
+                golab4: while (true) {
+                    var v_4 = env.cursor;
+                    lab5: while (true) {
+                        if (!env.in_grouping(G_v, 97, 249)) {
+                            break lab5;
+                        }
+                        env.bra = env.cursor;
+                        lab6: while (true) {
+                            var v_5 = env.cursor;
+                            lab7: while (true) {
+                                if (!env.eq_s("u")) {
+                                    break lab7;
+                                }
+                                env.ket = env.cursor;
+                                if (!env.in_grouping(G_v, 97, 249)) {
+                                    break lab7;
+                                }
+                                if (!env.slice_from("U")) {
+                                    return false;
+                                }
+                                break lab6;
+                            }
+                            env.cursor = v_5;
+                            if (!env.eq_s("i")) {
+                                break lab5;
+                            }
+                            env.ket = env.cursor;
+                            if (!env.in_grouping(G_v, 97, 249)) {
+                                break lab5;
+                            }
+                            if (!env.slice_from("I")) {
+                                return false;
+                            }
+                            break lab6;
+                        }
+                        env.cursor = v_4;
+                        break golab4;
+                    }
+                    env.cursor = v_4;
+                    if (env.cursor >= env.limit) {
+                        break lab3;
+                    }
+                    env.next_char();
+                }
+                continue replab2;
+            }
+            env.cursor = v_3;
+            break replab2;
+        }
+        return true;
+    }
+    function r_mark_regions(env, context) {
+        context.i_pV = env.limit;
+        context.i_p1 = env.limit;
+        context.i_p2 = env.limit;
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            lab1: while (true) {
+                var v_2 = env.cursor;
+                lab2: while (true) {
+                    if (!env.in_grouping(G_v, 97, 249)) {
+                        break lab2;
+                    }
+                    lab3: while (true) {
+                        var v_3 = env.cursor;
+                        lab4: while (true) {
+                            if (!env.out_grouping(G_v, 97, 249)) {
+                                break lab4;
+                            }
+                            golab5: while (true) {
+                                lab6: while (true) {
+                                    if (!env.in_grouping(G_v, 97, 249)) {
+                                        break lab6;
+                                    }
+                                    break golab5;
+                                }
+                                if (env.cursor >= env.limit) {
+                                    break lab4;
+                                }
+                                env.next_char();
+                            }
+                            break lab3;
+                        }
+                        env.cursor = v_3;
+                        if (!env.in_grouping(G_v, 97, 249)) {
+                            break lab2;
+                        }
+                        golab7: while (true) {
+                            lab8: while (true) {
+                                if (!env.out_grouping(G_v, 97, 249)) {
+                                    break lab8;
+                                }
+                                break golab7;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab2;
+                            }
+                            env.next_char();
+                        }
+                        break lab3;
+                    }
+                    break lab1;
+                }
+                env.cursor = v_2;
+                if (!env.out_grouping(G_v, 97, 249)) {
+                    break lab0;
+                }
+                lab9: while (true) {
+                    var v_6 = env.cursor;
+                    lab10: while (true) {
+                        if (!env.out_grouping(G_v, 97, 249)) {
+                            break lab10;
+                        }
+                        golab11: while (true) {
+                            lab12: while (true) {
+                                if (!env.in_grouping(G_v, 97, 249)) {
+                                    break lab12;
+                                }
+                                break golab11;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab10;
+                            }
+                            env.next_char();
+                        }
+                        break lab9;
+                    }
+                    env.cursor = v_6;
+                    if (!env.in_grouping(G_v, 97, 249)) {
+                        break lab0;
+                    }
+                    if (env.cursor >= env.limit) {
+                        break lab0;
+                    }
+                    env.next_char();
+                    break lab9;
+                }
+                break lab1;
+            }
+            context.i_pV = env.cursor;
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_8 = env.cursor;
+        lab13: while (true) {
+            golab14: while (true) {
+                lab15: while (true) {
+                    if (!env.in_grouping(G_v, 97, 249)) {
+                        break lab15;
+                    }
+                    break golab14;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            golab16: while (true) {
+                lab17: while (true) {
+                    if (!env.out_grouping(G_v, 97, 249)) {
+                        break lab17;
+                    }
+                    break golab16;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            context.i_p1 = env.cursor;
+            golab18: while (true) {
+                lab19: while (true) {
+                    if (!env.in_grouping(G_v, 97, 249)) {
+                        break lab19;
+                    }
+                    break golab18;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            golab20: while (true) {
+                lab21: while (true) {
+                    if (!env.out_grouping(G_v, 97, 249)) {
+                        break lab21;
+                    }
+                    break golab20;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            context.i_p2 = env.cursor;
+            break lab13;
+        }
+        env.cursor = v_8;
+        return true;
+    }
+    function r_postlude(env, context) {
+        var among_var;
+        replab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_1, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_from("i")) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_from("u")) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_1;
+            break replab0;
+        }
+        return true;
+    }
+    function r_RV(env, context) {
+        if (!(context.i_pV <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R1(env, context) {
+        if (!(context.i_p1 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R2(env, context) {
+        if (!(context.i_p2 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_attached_pronoun(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        if (env.find_among_b(A_2, context) == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        among_var = env.find_among_b(A_3, context);
+        if (among_var == 0) {
+            return false;
+        }
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_from("e")) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_standard_suffix(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_6, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_1 = env.limit - env.cursor;
+            lab0: while (true) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("ic")) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab0;
+            }
+        } else if (among_var == 3) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("log")) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("u")) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("ente")) {
+                return false;
+            }
+        } else if (among_var == 6) {
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 7) {
+            if (!r_R1(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_2 = env.limit - env.cursor;
+            lab1: while (true) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_4, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                } else if (among_var == 1) {
+                    env.ket = env.cursor;
+                    if (!env.eq_s_b("at")) {
+                        env.cursor = env.limit - v_2;
+                        break lab1;
+                    }
+                    env.bra = env.cursor;
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_2;
+                        break lab1;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                }
+                break lab1;
+            }
+        } else if (among_var == 8) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_3 = env.limit - env.cursor;
+            lab2: while (true) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_5, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_3;
+                    break lab2;
+                }
+                env.bra = env.cursor;
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_3;
+                    break lab2;
+                } else if (among_var == 1) {
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_3;
+                        break lab2;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                }
+                break lab2;
+            }
+        } else if (among_var == 9) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_4 = env.limit - env.cursor;
+            lab3: while (true) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("at")) {
+                    env.cursor = env.limit - v_4;
+                    break lab3;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_4;
+                    break lab3;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                env.ket = env.cursor;
+                if (!env.eq_s_b("ic")) {
+                    env.cursor = env.limit - v_4;
+                    break lab3;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_4;
+                    break lab3;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab3;
+            }
+        }
+        return true;
+    }
+    function r_verb_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_pV) {
+            return false;
+        }
+        env.cursor = context.i_pV;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_7, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        env.limit_backward = v_2;
+        return true;
+    }
+    function r_vowel_suffix(env, context) {
+        var v_1 = env.limit - env.cursor;
+        lab0: while (true) {
+            env.ket = env.cursor;
+            if (!env.in_grouping_b(G_AEIO, 97, 242)) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (!r_RV(env, context)) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            env.ket = env.cursor;
+            if (!env.eq_s_b("i")) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (!r_RV(env, context)) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab0;
+        }
+        var v_2 = env.limit - env.cursor;
+        lab1: while (true) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("h")) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            }
+            env.bra = env.cursor;
+            if (!env.in_grouping_b(G_CG, 99, 103)) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            }
+            if (!r_RV(env, context)) {
+                env.cursor = env.limit - v_2;
+                break lab1;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab1;
+        }
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            i_p2: 0,
+            i_p1: 0,
+            i_pV: 0,
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_prelude(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_2 = env.cursor;
+        lab1: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = v_2;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            if (!r_attached_pronoun(env, context)) {
+                break lab2;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_4 = env.limit - env.cursor;
+        lab3: while (true) {
+            lab4: while (true) {
+                var v_5 = env.limit - env.cursor;
+                lab5: while (true) {
+                    if (!r_standard_suffix(env, context)) {
+                        break lab5;
+                    }
+                    break lab4;
+                }
+                env.cursor = env.limit - v_5;
+                if (!r_verb_suffix(env, context)) {
+                    break lab3;
+                }
+                break lab4;
+            }
+            break lab3;
+        }
+        env.cursor = env.limit - v_4;
+        var v_6 = env.limit - env.cursor;
+        lab6: while (true) {
+            if (!r_vowel_suffix(env, context)) {
+                break lab6;
+            }
+            break lab6;
+        }
+        env.cursor = env.limit - v_6;
+        env.cursor = env.limit_backward;
+        var v_7 = env.cursor;
+        lab7: while (true) {
+            if (!r_postlude(env, context)) {
+                break lab7;
+            }
+            break lab7;
+        }
+        env.cursor = v_7;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("it", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "a", "abbia", "abbiamo", "abbiano", "abbiate", "ad", "agl", "agli",
+            "ai", "al", "all", "alla", "alle", "allo", "anche", "avemmo", "avendo",
+            "avesse", "avessero", "avessi", "avessimo", "aveste", "avesti", "avete",
+            "aveva", "avevamo", "avevano", "avevate", "avevi", "avevo", "avrai",
+            "avranno", "avrebbe", "avrebbero", "avrei", "avremmo", "avremo",
+            "avreste", "avresti", "avrete", "avr\u00E0", "avr\u00F2", "avuta",
+            "avute", "avuti", "avuto", "c", "che", "chi", "ci", "coi", "col",
+            "come", "con", "contro", "cui", "da", "dagl", "dagli", "dai", "dal",
+            "dall", "dalla", "dalle", "dallo", "degl", "degli", "dei", "del",
+            "dell", "della", "delle", "dello", "di", "dov", "dove", "e", "ebbe",
+            "ebbero", "ebbi", "ed", "era", "erano", "eravamo", "eravate", "eri",
+            "ero", "essendo", "faccia", "facciamo", "facciano", "facciate",
+            "faccio", "facemmo", "facendo", "facesse", "facessero", "facessi",
+            "facessimo", "faceste", "facesti", "faceva", "facevamo", "facevano",
+            "facevate", "facevi", "facevo", "fai", "fanno", "farai", "faranno",
+            "farebbe", "farebbero", "farei", "faremmo", "faremo", "fareste",
+            "faresti", "farete", "far\u00E0", "far\u00F2", "fece", "fecero", "feci",
+            "fosse", "fossero", "fossi", "fossimo", "foste", "fosti", "fu", "fui",
+            "fummo", "furono", "gli", "ha", "hai", "hanno", "ho", "i", "il", "in",
+            "io", "l", "la", "le", "lei", "li", "lo", "loro", "lui", "ma", "mi",
+            "mia", "mie", "miei", "mio", "ne", "negl", "negli", "nei", "nel",
+            "nell", "nella", "nelle", "nello", "noi", "non", "nostra", "nostre",
+            "nostri", "nostro", "o", "per", "perch\u00E9", "pi\u00F9", "quale",
+            "quanta", "quante", "quanti", "quanto", "quella", "quelle", "quelli",
+            "quello", "questa", "queste", "questi", "questo", "sarai", "saranno",
+            "sarebbe", "sarebbero", "sarei", "saremmo", "saremo", "sareste",
+            "saresti", "sarete", "sar\u00E0", "sar\u00F2", "se", "sei", "si", "sia",
+            "siamo", "siano", "siate", "siete", "sono", "sta", "stai", "stando",
+            "stanno", "starai", "staranno", "starebbe", "starebbero", "starei",
+            "staremmo", "staremo", "stareste", "staresti", "starete", "star\u00E0",
+            "star\u00F2", "stava", "stavamo", "stavano", "stavate", "stavi",
+            "stavo", "stemmo", "stesse", "stessero", "stessi", "stessimo", "steste",
+            "stesti", "stette", "stettero", "stetti", "stia", "stiamo", "stiano",
+            "stiate", "sto", "su", "sua", "sue", "sugl", "sugli", "sui", "sul",
+            "sull", "sulla", "sulle", "sullo", "suo", "suoi", "ti", "tra", "tu",
+            "tua", "tue", "tuo", "tuoi", "tutti", "tutto", "un", "una", "uno", "vi",
+            "voi", "vostra", "vostre", "vostri", "vostro", "\u00E8"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
@@ -0,0 +1,893 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for Portuguese,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball Portuguese stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("", -1, 3),
+        new Among("\u00E3", 0, 1),
+        new Among("\u00F5", 0, 2)
+    ];
+
+    var A_1 = [
+        new Among("", -1, 3),
+        new Among("a~", 0, 1),
+        new Among("o~", 0, 2)
+    ];
+
+    var A_2 = [
+        new Among("ic", -1, -1),
+        new Among("ad", -1, -1),
+        new Among("os", -1, -1),
+        new Among("iv", -1, 1)
+    ];
+
+    var A_3 = [
+        new Among("ante", -1, 1),
+        new Among("avel", -1, 1),
+        new Among("\u00EDvel", -1, 1)
+    ];
+
+    var A_4 = [
+        new Among("ic", -1, 1),
+        new Among("abil", -1, 1),
+        new Among("iv", -1, 1)
+    ];
+
+    var A_5 = [
+        new Among("ica", -1, 1),
+        new Among("\u00E2ncia", -1, 1),
+        new Among("\u00EAncia", -1, 4),
+        new Among("logia", -1, 2),
+        new Among("ira", -1, 9),
+        new Among("adora", -1, 1),
+        new Among("osa", -1, 1),
+        new Among("ista", -1, 1),
+        new Among("iva", -1, 8),
+        new Among("eza", -1, 1),
+        new Among("idade", -1, 7),
+        new Among("ante", -1, 1),
+        new Among("mente", -1, 6),
+        new Among("amente", 12, 5),
+        new Among("\u00E1vel", -1, 1),
+        new Among("\u00EDvel", -1, 1),
+        new Among("ico", -1, 1),
+        new Among("ismo", -1, 1),
+        new Among("oso", -1, 1),
+        new Among("amento", -1, 1),
+        new Among("imento", -1, 1),
+        new Among("ivo", -1, 8),
+        new Among("a\u00E7a~o", -1, 1),
+        new Among("u\u00E7a~o", -1, 3),
+        new Among("ador", -1, 1),
+        new Among("icas", -1, 1),
+        new Among("\u00EAncias", -1, 4),
+        new Among("logias", -1, 2),
+        new Among("iras", -1, 9),
+        new Among("adoras", -1, 1),
+        new Among("osas", -1, 1),
+        new Among("istas", -1, 1),
+        new Among("ivas", -1, 8),
+        new Among("ezas", -1, 1),
+        new Among("idades", -1, 7),
+        new Among("adores", -1, 1),
+        new Among("antes", -1, 1),
+        new Among("a\u00E7o~es", -1, 1),
+        new Among("u\u00E7o~es", -1, 3),
+        new Among("icos", -1, 1),
+        new Among("ismos", -1, 1),
+        new Among("osos", -1, 1),
+        new Among("amentos", -1, 1),
+        new Among("imentos", -1, 1),
+        new Among("ivos", -1, 8)
+    ];
+
+    var A_6 = [
+        new Among("ada", -1, 1),
+        new Among("ida", -1, 1),
+        new Among("ia", -1, 1),
+        new Among("aria", 2, 1),
+        new Among("eria", 2, 1),
+        new Among("iria", 2, 1),
+        new Among("ara", -1, 1),
+        new Among("era", -1, 1),
+        new Among("ira", -1, 1),
+        new Among("ava", -1, 1),
+        new Among("asse", -1, 1),
+        new Among("esse", -1, 1),
+        new Among("isse", -1, 1),
+        new Among("aste", -1, 1),
+        new Among("este", -1, 1),
+        new Among("iste", -1, 1),
+        new Among("ei", -1, 1),
+        new Among("arei", 16, 1),
+        new Among("erei", 16, 1),
+        new Among("irei", 16, 1),
+        new Among("am", -1, 1),
+        new Among("iam", 20, 1),
+        new Among("ariam", 21, 1),
+        new Among("eriam", 21, 1),
+        new Among("iriam", 21, 1),
+        new Among("aram", 20, 1),
+        new Among("eram", 20, 1),
+        new Among("iram", 20, 1),
+        new Among("avam", 20, 1),
+        new Among("em", -1, 1),
+        new Among("arem", 29, 1),
+        new Among("erem", 29, 1),
+        new Among("irem", 29, 1),
+        new Among("assem", 29, 1),
+        new Among("essem", 29, 1),
+        new Among("issem", 29, 1),
+        new Among("ado", -1, 1),
+        new Among("ido", -1, 1),
+        new Among("ando", -1, 1),
+        new Among("endo", -1, 1),
+        new Among("indo", -1, 1),
+        new Among("ara~o", -1, 1),
+        new Among("era~o", -1, 1),
+        new Among("ira~o", -1, 1),
+        new Among("ar", -1, 1),
+        new Among("er", -1, 1),
+        new Among("ir", -1, 1),
+        new Among("as", -1, 1),
+        new Among("adas", 47, 1),
+        new Among("idas", 47, 1),
+        new Among("ias", 47, 1),
+        new Among("arias", 50, 1),
+        new Among("erias", 50, 1),
+        new Among("irias", 50, 1),
+        new Among("aras", 47, 1),
+        new Among("eras", 47, 1),
+        new Among("iras", 47, 1),
+        new Among("avas", 47, 1),
+        new Among("es", -1, 1),
+        new Among("ardes", 58, 1),
+        new Among("erdes", 58, 1),
+        new Among("irdes", 58, 1),
+        new Among("ares", 58, 1),
+        new Among("eres", 58, 1),
+        new Among("ires", 58, 1),
+        new Among("asses", 58, 1),
+        new Among("esses", 58, 1),
+        new Among("isses", 58, 1),
+        new Among("astes", 58, 1),
+        new Among("estes", 58, 1),
+        new Among("istes", 58, 1),
+        new Among("is", -1, 1),
+        new Among("ais", 71, 1),
+        new Among("eis", 71, 1),
+        new Among("areis", 73, 1),
+        new Among("ereis", 73, 1),
+        new Among("ireis", 73, 1),
+        new Among("\u00E1reis", 73, 1),
+        new Among("\u00E9reis", 73, 1),
+        new Among("\u00EDreis", 73, 1),
+        new Among("\u00E1sseis", 73, 1),
+        new Among("\u00E9sseis", 73, 1),
+        new Among("\u00EDsseis", 73, 1),
+        new Among("\u00E1veis", 73, 1),
+        new Among("\u00EDeis", 73, 1),
+        new Among("ar\u00EDeis", 84, 1),
+        new Among("er\u00EDeis", 84, 1),
+        new Among("ir\u00EDeis", 84, 1),
+        new Among("ados", -1, 1),
+        new Among("idos", -1, 1),
+        new Among("amos", -1, 1),
+        new Among("\u00E1ramos", 90, 1),
+        new Among("\u00E9ramos", 90, 1),
+        new Among("\u00EDramos", 90, 1),
+        new Among("\u00E1vamos", 90, 1),
+        new Among("\u00EDamos", 90, 1),
+        new Among("ar\u00EDamos", 95, 1),
+        new Among("er\u00EDamos", 95, 1),
+        new Among("ir\u00EDamos", 95, 1),
+        new Among("emos", -1, 1),
+        new Among("aremos", 99, 1),
+        new Among("eremos", 99, 1),
+        new Among("iremos", 99, 1),
+        new Among("\u00E1ssemos", 99, 1),
+        new Among("\u00EAssemos", 99, 1),
+        new Among("\u00EDssemos", 99, 1),
+        new Among("imos", -1, 1),
+        new Among("armos", -1, 1),
+        new Among("ermos", -1, 1),
+        new Among("irmos", -1, 1),
+        new Among("\u00E1mos", -1, 1),
+        new Among("ar\u00E1s", -1, 1),
+        new Among("er\u00E1s", -1, 1),
+        new Among("ir\u00E1s", -1, 1),
+        new Among("eu", -1, 1),
+        new Among("iu", -1, 1),
+        new Among("ou", -1, 1),
+        new Among("ar\u00E1", -1, 1),
+        new Among("er\u00E1", -1, 1),
+        new Among("ir\u00E1", -1, 1)
+    ];
+
+    var A_7 = [
+        new Among("a", -1, 1),
+        new Among("i", -1, 1),
+        new Among("o", -1, 1),
+        new Among("os", -1, 1),
+        new Among("\u00E1", -1, 1),
+        new Among("\u00ED", -1, 1),
+        new Among("\u00F3", -1, 1)
+    ];
+
+    var A_8 = [
+        new Among("e", -1, 1),
+        new Among("\u00E7", -1, 2),
+        new Among("\u00E9", -1, 1),
+        new Among("\u00EA", -1, 1)
+    ];
+
+    var G_v = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 19, 12, 2];
+
+    function r_prelude(env, context) {
+        var among_var;
+        replab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_0, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_from("a~")) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_from("o~")) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_1;
+            break replab0;
+        }
+        return true;
+    }
+    function r_mark_regions(env, context) {
+        context.i_pV = env.limit;
+        context.i_p1 = env.limit;
+        context.i_p2 = env.limit;
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            lab1: while (true) {
+                var v_2 = env.cursor;
+                lab2: while (true) {
+                    if (!env.in_grouping(G_v, 97, 250)) {
+                        break lab2;
+                    }
+                    lab3: while (true) {
+                        var v_3 = env.cursor;
+                        lab4: while (true) {
+                            if (!env.out_grouping(G_v, 97, 250)) {
+                                break lab4;
+                            }
+                            golab5: while (true) {
+                                lab6: while (true) {
+                                    if (!env.in_grouping(G_v, 97, 250)) {
+                                        break lab6;
+                                    }
+                                    break golab5;
+                                }
+                                if (env.cursor >= env.limit) {
+                                    break lab4;
+                                }
+                                env.next_char();
+                            }
+                            break lab3;
+                        }
+                        env.cursor = v_3;
+                        if (!env.in_grouping(G_v, 97, 250)) {
+                            break lab2;
+                        }
+                        golab7: while (true) {
+                            lab8: while (true) {
+                                if (!env.out_grouping(G_v, 97, 250)) {
+                                    break lab8;
+                                }
+                                break golab7;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab2;
+                            }
+                            env.next_char();
+                        }
+                        break lab3;
+                    }
+                    break lab1;
+                }
+                env.cursor = v_2;
+                if (!env.out_grouping(G_v, 97, 250)) {
+                    break lab0;
+                }
+                lab9: while (true) {
+                    var v_6 = env.cursor;
+                    lab10: while (true) {
+                        if (!env.out_grouping(G_v, 97, 250)) {
+                            break lab10;
+                        }
+                        golab11: while (true) {
+                            lab12: while (true) {
+                                if (!env.in_grouping(G_v, 97, 250)) {
+                                    break lab12;
+                                }
+                                break golab11;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab10;
+                            }
+                            env.next_char();
+                        }
+                        break lab9;
+                    }
+                    env.cursor = v_6;
+                    if (!env.in_grouping(G_v, 97, 250)) {
+                        break lab0;
+                    }
+                    if (env.cursor >= env.limit) {
+                        break lab0;
+                    }
+                    env.next_char();
+                    break lab9;
+                }
+                break lab1;
+            }
+            context.i_pV = env.cursor;
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_8 = env.cursor;
+        lab13: while (true) {
+            golab14: while (true) {
+                lab15: while (true) {
+                    if (!env.in_grouping(G_v, 97, 250)) {
+                        break lab15;
+                    }
+                    break golab14;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            golab16: while (true) {
+                lab17: while (true) {
+                    if (!env.out_grouping(G_v, 97, 250)) {
+                        break lab17;
+                    }
+                    break golab16;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            context.i_p1 = env.cursor;
+            golab18: while (true) {
+                lab19: while (true) {
+                    if (!env.in_grouping(G_v, 97, 250)) {
+                        break lab19;
+                    }
+                    break golab18;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            golab20: while (true) {
+                lab21: while (true) {
+                    if (!env.out_grouping(G_v, 97, 250)) {
+                        break lab21;
+                    }
+                    break golab20;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            context.i_p2 = env.cursor;
+            break lab13;
+        }
+        env.cursor = v_8;
+        return true;
+    }
+    function r_postlude(env, context) {
+        var among_var;
+        replab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_1, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_from("\u00E3")) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_from("\u00F5")) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_1;
+            break replab0;
+        }
+        return true;
+    }
+    function r_RV(env, context) {
+        if (!(context.i_pV <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R1(env, context) {
+        if (!(context.i_p1 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R2(env, context) {
+        if (!(context.i_p2 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_standard_suffix(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_5, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("log")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("u")) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_from("ente")) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            if (!r_R1(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_1 = env.limit - env.cursor;
+            lab0: while (true) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_2, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_1;
+                    break lab0;
+                } else if (among_var == 1) {
+                    env.ket = env.cursor;
+                    if (!env.eq_s_b("at")) {
+                        env.cursor = env.limit - v_1;
+                        break lab0;
+                    }
+                    env.bra = env.cursor;
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_1;
+                        break lab0;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                }
+                break lab0;
+            }
+        } else if (among_var == 6) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_2 = env.limit - env.cursor;
+            lab1: while (true) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_3, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                }
+                env.bra = env.cursor;
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_2;
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_2;
+                        break lab1;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                }
+                break lab1;
+            }
+        } else if (among_var == 7) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_3 = env.limit - env.cursor;
+            lab2: while (true) {
+                env.ket = env.cursor;
+                among_var = env.find_among_b(A_4, context);
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_3;
+                    break lab2;
+                }
+                env.bra = env.cursor;
+                if (among_var == 0) {
+                    env.cursor = env.limit - v_3;
+                    break lab2;
+                } else if (among_var == 1) {
+                    if (!r_R2(env, context)) {
+                        env.cursor = env.limit - v_3;
+                        break lab2;
+                    }
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                }
+                break lab2;
+            }
+        } else if (among_var == 8) {
+            if (!r_R2(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_4 = env.limit - env.cursor;
+            lab3: while (true) {
+                env.ket = env.cursor;
+                if (!env.eq_s_b("at")) {
+                    env.cursor = env.limit - v_4;
+                    break lab3;
+                }
+                env.bra = env.cursor;
+                if (!r_R2(env, context)) {
+                    env.cursor = env.limit - v_4;
+                    break lab3;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab3;
+            }
+        } else if (among_var == 9) {
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            if (!env.eq_s_b("e")) {
+                return false;
+            }
+            if (!env.slice_from("ir")) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_verb_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_pV) {
+            return false;
+        }
+        env.cursor = context.i_pV;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_6, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        env.limit_backward = v_2;
+        return true;
+    }
+    function r_residual_suffix(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_7, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_residual_form(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_8, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+            env.ket = env.cursor;
+            lab0: while (true) {
+                var v_1 = env.limit - env.cursor;
+                lab1: while (true) {
+                    if (!env.eq_s_b("u")) {
+                        break lab1;
+                    }
+                    env.bra = env.cursor;
+                    var v_2 = env.limit - env.cursor;
+                    if (!env.eq_s_b("g")) {
+                        break lab1;
+                    }
+                    env.cursor = env.limit - v_2;
+                    break lab0;
+                }
+                env.cursor = env.limit - v_1;
+                if (!env.eq_s_b("i")) {
+                    return false;
+                }
+                env.bra = env.cursor;
+                var v_3 = env.limit - env.cursor;
+                if (!env.eq_s_b("c")) {
+                    return false;
+                }
+                env.cursor = env.limit - v_3;
+                break lab0;
+            }
+            if (!r_RV(env, context)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_from("c")) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            i_p2: 0,
+            i_p1: 0,
+            i_pV: 0,
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_prelude(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_2 = env.cursor;
+        lab1: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = v_2;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            lab3: while (true) {
+                var v_4 = env.limit - env.cursor;
+                lab4: while (true) {
+                    var v_5 = env.limit - env.cursor;
+                    lab5: while (true) {
+                        var v_6 = env.limit - env.cursor;
+                        lab6: while (true) {
+                            if (!r_standard_suffix(env, context)) {
+                                break lab6;
+                            }
+                            break lab5;
+                        }
+                        env.cursor = env.limit - v_6;
+                        if (!r_verb_suffix(env, context)) {
+                            break lab4;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_5;
+                    var v_7 = env.limit - env.cursor;
+                    lab7: while (true) {
+                        env.ket = env.cursor;
+                        if (!env.eq_s_b("i")) {
+                            break lab7;
+                        }
+                        env.bra = env.cursor;
+                        var v_8 = env.limit - env.cursor;
+                        if (!env.eq_s_b("c")) {
+                            break lab7;
+                        }
+                        env.cursor = env.limit - v_8;
+                        if (!r_RV(env, context)) {
+                            break lab7;
+                        }
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        break lab7;
+                    }
+                    env.cursor = env.limit - v_7;
+                    break lab3;
+                }
+                env.cursor = env.limit - v_4;
+                if (!r_residual_suffix(env, context)) {
+                    break lab2;
+                }
+                break lab3;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_9 = env.limit - env.cursor;
+        lab8: while (true) {
+            if (!r_residual_form(env, context)) {
+                break lab8;
+            }
+            break lab8;
+        }
+        env.cursor = env.limit - v_9;
+        env.cursor = env.limit_backward;
+        var v_10 = env.cursor;
+        lab9: while (true) {
+            if (!r_postlude(env, context)) {
+                break lab9;
+            }
+            break lab9;
+        }
+        env.cursor = v_10;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("pt", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "a", "ao", "aos", "aquela", "aquelas", "aquele", "aqueles",
+            "aquilo", "as", "at\u00E9", "com", "como", "da", "das", "de", "dela",
+            "delas", "dele", "deles", "depois", "do", "dos", "e", "ela", "elas",
+            "ele", "eles", "em", "entre", "era", "eram", "essa", "essas", "esse",
+            "esses", "esta", "estamos", "estas", "estava", "estavam", "este",
+            "esteja", "estejam", "estejamos", "estes", "esteve", "estive",
+            "estivemos", "estiver", "estivera", "estiveram", "estiverem",
+            "estivermos", "estivesse", "estivessem", "estiv\u00E9ramos",
+            "estiv\u00E9ssemos", "estou", "est\u00E1", "est\u00E1vamos",
+            "est\u00E3o", "eu", "foi", "fomos", "for", "fora", "foram", "forem",
+            "formos", "fosse", "fossem", "fui", "f\u00F4ramos", "f\u00F4ssemos",
+            "haja", "hajam", "hajamos", "havemos", "hei", "houve", "houvemos",
+            "houver", "houvera", "houveram", "houverei", "houverem", "houveremos",
+            "houveria", "houveriam", "houvermos", "houver\u00E1", "houver\u00E3o",
+            "houver\u00EDamos", "houvesse", "houvessem", "houv\u00E9ramos",
+            "houv\u00E9ssemos", "h\u00E1", "h\u00E3o", "isso", "isto", "j\u00E1",
+            "lhe", "lhes", "mais", "mas", "me", "mesmo", "meu", "meus", "minha",
+            "minhas", "muito", "na", "nas", "nem", "no", "nos", "nossa", "nossas",
+            "nosso", "nossos", "num", "numa", "n\u00E3o", "n\u00F3s", "o", "os",
+            "ou", "para", "pela", "pelas", "pelo", "pelos", "por", "qual", "quando",
+            "que", "quem", "se", "seja", "sejam", "sejamos", "sem", "serei",
+            "seremos", "seria", "seriam", "ser\u00E1", "ser\u00E3o",
+            "ser\u00EDamos", "seu", "seus", "somos", "sou", "sua", "suas",
+            "s\u00E3o", "s\u00F3", "tamb\u00E9m", "te", "tem", "temos", "tenha",
+            "tenham", "tenhamos", "tenho", "terei", "teremos", "teria", "teriam",
+            "ter\u00E1", "ter\u00E3o", "ter\u00EDamos", "teu", "teus", "teve",
+            "tinha", "tinham", "tive", "tivemos", "tiver", "tivera", "tiveram",
+            "tiverem", "tivermos", "tivesse", "tivessem", "tiv\u00E9ramos",
+            "tiv\u00E9ssemos", "tu", "tua", "tuas", "t\u00E9m", "t\u00EDnhamos",
+            "um", "uma", "voc\u00EA", "voc\u00EAs", "vos", "\u00E0", "\u00E0s",
+            "\u00E9ramos"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
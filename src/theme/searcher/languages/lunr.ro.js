@@ -0,0 +1,859 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for Romanian,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball Romanian stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("", -1, 3),
+        new Among("I", 0, 1),
+        new Among("U", 0, 2)
+    ];
+
+    var A_1 = [
+        new Among("ea", -1, 3),
+        new Among("a\u0163ia", -1, 7),
+        new Among("aua", -1, 2),
+        new Among("iua", -1, 4),
+        new Among("a\u0163ie", -1, 7),
+        new Among("ele", -1, 3),
+        new Among("ile", -1, 5),
+        new Among("iile", 6, 4),
+        new Among("iei", -1, 4),
+        new Among("atei", -1, 6),
+        new Among("ii", -1, 4),
+        new Among("ului", -1, 1),
+        new Among("ul", -1, 1),
+        new Among("elor", -1, 3),
+        new Among("ilor", -1, 4),
+        new Among("iilor", 14, 4)
+    ];
+
+    var A_2 = [
+        new Among("icala", -1, 4),
+        new Among("iciva", -1, 4),
+        new Among("ativa", -1, 5),
+        new Among("itiva", -1, 6),
+        new Among("icale", -1, 4),
+        new Among("a\u0163iune", -1, 5),
+        new Among("i\u0163iune", -1, 6),
+        new Among("atoare", -1, 5),
+        new Among("itoare", -1, 6),
+        new Among("\u0103toare", -1, 5),
+        new Among("icitate", -1, 4),
+        new Among("abilitate", -1, 1),
+        new Among("ibilitate", -1, 2),
+        new Among("ivitate", -1, 3),
+        new Among("icive", -1, 4),
+        new Among("ative", -1, 5),
+        new Among("itive", -1, 6),
+        new Among("icali", -1, 4),
+        new Among("atori", -1, 5),
+        new Among("icatori", 18, 4),
+        new Among("itori", -1, 6),
+        new Among("\u0103tori", -1, 5),
+        new Among("icitati", -1, 4),
+        new Among("abilitati", -1, 1),
+        new Among("ivitati", -1, 3),
+        new Among("icivi", -1, 4),
+        new Among("ativi", -1, 5),
+        new Among("itivi", -1, 6),
+        new Among("icit\u0103i", -1, 4),
+        new Among("abilit\u0103i", -1, 1),
+        new Among("ivit\u0103i", -1, 3),
+        new Among("icit\u0103\u0163i", -1, 4),
+        new Among("abilit\u0103\u0163i", -1, 1),
+        new Among("ivit\u0103\u0163i", -1, 3),
+        new Among("ical", -1, 4),
+        new Among("ator", -1, 5),
+        new Among("icator", 35, 4),
+        new Among("itor", -1, 6),
+        new Among("\u0103tor", -1, 5),
+        new Among("iciv", -1, 4),
+        new Among("ativ", -1, 5),
+        new Among("itiv", -1, 6),
+        new Among("ical\u0103", -1, 4),
+        new Among("iciv\u0103", -1, 4),
+        new Among("ativ\u0103", -1, 5),
+        new Among("itiv\u0103", -1, 6)
+    ];
+
+    var A_3 = [
+        new Among("ica", -1, 1),
+        new Among("abila", -1, 1),
+        new Among("ibila", -1, 1),
+        new Among("oasa", -1, 1),
+        new Among("ata", -1, 1),
+        new Among("ita", -1, 1),
+        new Among("anta", -1, 1),
+        new Among("ista", -1, 3),
+        new Among("uta", -1, 1),
+        new Among("iva", -1, 1),
+        new Among("ic", -1, 1),
+        new Among("ice", -1, 1),
+        new Among("abile", -1, 1),
+        new Among("ibile", -1, 1),
+        new Among("isme", -1, 3),
+        new Among("iune", -1, 2),
+        new Among("oase", -1, 1),
+        new Among("ate", -1, 1),
+        new Among("itate", 17, 1),
+        new Among("ite", -1, 1),
+        new Among("ante", -1, 1),
+        new Among("iste", -1, 3),
+        new Among("ute", -1, 1),
+        new Among("ive", -1, 1),
+        new Among("ici", -1, 1),
+        new Among("abili", -1, 1),
+        new Among("ibili", -1, 1),
+        new Among("iuni", -1, 2),
+        new Among("atori", -1, 1),
+        new Among("osi", -1, 1),
+        new Among("ati", -1, 1),
+        new Among("itati", 30, 1),
+        new Among("iti", -1, 1),
+        new Among("anti", -1, 1),
+        new Among("isti", -1, 3),
+        new Among("uti", -1, 1),
+        new Among("i\u015Fti", -1, 3),
+        new Among("ivi", -1, 1),
+        new Among("it\u0103i", -1, 1),
+        new Among("o\u015Fi", -1, 1),
+        new Among("it\u0103\u0163i", -1, 1),
+        new Among("abil", -1, 1),
+        new Among("ibil", -1, 1),
+        new Among("ism", -1, 3),
+        new Among("ator", -1, 1),
+        new Among("os", -1, 1),
+        new Among("at", -1, 1),
+        new Among("it", -1, 1),
+        new Among("ant", -1, 1),
+        new Among("ist", -1, 3),
+        new Among("ut", -1, 1),
+        new Among("iv", -1, 1),
+        new Among("ic\u0103", -1, 1),
+        new Among("abil\u0103", -1, 1),
+        new Among("ibil\u0103", -1, 1),
+        new Among("oas\u0103", -1, 1),
+        new Among("at\u0103", -1, 1),
+        new Among("it\u0103", -1, 1),
+        new Among("ant\u0103", -1, 1),
+        new Among("ist\u0103", -1, 3),
+        new Among("ut\u0103", -1, 1),
+        new Among("iv\u0103", -1, 1)
+    ];
+
+    var A_4 = [
+        new Among("ea", -1, 1),
+        new Among("ia", -1, 1),
+        new Among("esc", -1, 1),
+        new Among("\u0103sc", -1, 1),
+        new Among("ind", -1, 1),
+        new Among("\u00E2nd", -1, 1),
+        new Among("are", -1, 1),
+        new Among("ere", -1, 1),
+        new Among("ire", -1, 1),
+        new Among("\u00E2re", -1, 1),
+        new Among("se", -1, 2),
+        new Among("ase", 10, 1),
+        new Among("sese", 10, 2),
+        new Among("ise", 10, 1),
+        new Among("use", 10, 1),
+        new Among("\u00E2se", 10, 1),
+        new Among("e\u015Fte", -1, 1),
+        new Among("\u0103\u015Fte", -1, 1),
+        new Among("eze", -1, 1),
+        new Among("ai", -1, 1),
+        new Among("eai", 19, 1),
+        new Among("iai", 19, 1),
+        new Among("sei", -1, 2),
+        new Among("e\u015Fti", -1, 1),
+        new Among("\u0103\u015Fti", -1, 1),
+        new Among("ui", -1, 1),
+        new Among("ezi", -1, 1),
+        new Among("\u00E2i", -1, 1),
+        new Among("a\u015Fi", -1, 1),
+        new Among("se\u015Fi", -1, 2),
+        new Among("ase\u015Fi", 29, 1),
+        new Among("sese\u015Fi", 29, 2),
+        new Among("ise\u015Fi", 29, 1),
+        new Among("use\u015Fi", 29, 1),
+        new Among("\u00E2se\u015Fi", 29, 1),
+        new Among("i\u015Fi", -1, 1),
+        new Among("u\u015Fi", -1, 1),
+        new Among("\u00E2\u015Fi", -1, 1),
+        new Among("a\u0163i", -1, 2),
+        new Among("ea\u0163i", 38, 1),
+        new Among("ia\u0163i", 38, 1),
+        new Among("e\u0163i", -1, 2),
+        new Among("i\u0163i", -1, 2),
+        new Among("\u00E2\u0163i", -1, 2),
+        new Among("ar\u0103\u0163i", -1, 1),
+        new Among("ser\u0103\u0163i", -1, 2),
+        new Among("aser\u0103\u0163i", 45, 1),
+        new Among("seser\u0103\u0163i", 45, 2),
+        new Among("iser\u0103\u0163i", 45, 1),
+        new Among("user\u0103\u0163i", 45, 1),
+        new Among("\u00E2ser\u0103\u0163i", 45, 1),
+        new Among("ir\u0103\u0163i", -1, 1),
+        new Among("ur\u0103\u0163i", -1, 1),
+        new Among("\u00E2r\u0103\u0163i", -1, 1),
+        new Among("am", -1, 1),
+        new Among("eam", 54, 1),
+        new Among("iam", 54, 1),
+        new Among("em", -1, 2),
+        new Among("asem", 57, 1),
+        new Among("sesem", 57, 2),
+        new Among("isem", 57, 1),
+        new Among("usem", 57, 1),
+        new Among("\u00E2sem", 57, 1),
+        new Among("im", -1, 2),
+        new Among("\u00E2m", -1, 2),
+        new Among("\u0103m", -1, 2),
+        new Among("ar\u0103m", 65, 1),
+        new Among("ser\u0103m", 65, 2),
+        new Among("aser\u0103m", 67, 1),
+        new Among("seser\u0103m", 67, 2),
+        new Among("iser\u0103m", 67, 1),
+        new Among("user\u0103m", 67, 1),
+        new Among("\u00E2ser\u0103m", 67, 1),
+        new Among("ir\u0103m", 65, 1),
+        new Among("ur\u0103m", 65, 1),
+        new Among("\u00E2r\u0103m", 65, 1),
+        new Among("au", -1, 1),
+        new Among("eau", 76, 1),
+        new Among("iau", 76, 1),
+        new Among("indu", -1, 1),
+        new Among("\u00E2ndu", -1, 1),
+        new Among("ez", -1, 1),
+        new Among("easc\u0103", -1, 1),
+        new Among("ar\u0103", -1, 1),
+        new Among("ser\u0103", -1, 2),
+        new Among("aser\u0103", 84, 1),
+        new Among("seser\u0103", 84, 2),
+        new Among("iser\u0103", 84, 1),
+        new Among("user\u0103", 84, 1),
+        new Among("\u00E2ser\u0103", 84, 1),
+        new Among("ir\u0103", -1, 1),
+        new Among("ur\u0103", -1, 1),
+        new Among("\u00E2r\u0103", -1, 1),
+        new Among("eaz\u0103", -1, 1)
+    ];
+
+    var A_5 = [
+        new Among("a", -1, 1),
+        new Among("e", -1, 1),
+        new Among("ie", 1, 1),
+        new Among("i", -1, 1),
+        new Among("\u0103", -1, 1)
+    ];
+
+    var G_v = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 32, 0, 0, 4];
+
+    function r_prelude(env, context) {
+        replab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                golab2: while (true) {
+                    var v_2 = env.cursor;
+                    lab3: while (true) {
+                        if (!env.in_grouping(G_v, 97, 259)) {
+                            break lab3;
+                        }
+                        env.bra = env.cursor;
+                        lab4: while (true) {
+                            var v_3 = env.cursor;
+                            lab5: while (true) {
+                                if (!env.eq_s("u")) {
+                                    break lab5;
+                                }
+                                env.ket = env.cursor;
+                                if (!env.in_grouping(G_v, 97, 259)) {
+                                    break lab5;
+                                }
+                                if (!env.slice_from("U")) {
+                                    return false;
+                                }
+                                break lab4;
+                            }
+                            env.cursor = v_3;
+                            if (!env.eq_s("i")) {
+                                break lab3;
+                            }
+                            env.ket = env.cursor;
+                            if (!env.in_grouping(G_v, 97, 259)) {
+                                break lab3;
+                            }
+                            if (!env.slice_from("I")) {
+                                return false;
+                            }
+                            break lab4;
+                        }
+                        env.cursor = v_2;
+                        break golab2;
+                    }
+                    env.cursor = v_2;
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_1;
+            break replab0;
+        }
+        return true;
+    }
+    function r_mark_regions(env, context) {
+        context.i_pV = env.limit;
+        context.i_p1 = env.limit;
+        context.i_p2 = env.limit;
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            lab1: while (true) {
+                var v_2 = env.cursor;
+                lab2: while (true) {
+                    if (!env.in_grouping(G_v, 97, 259)) {
+                        break lab2;
+                    }
+                    lab3: while (true) {
+                        var v_3 = env.cursor;
+                        lab4: while (true) {
+                            if (!env.out_grouping(G_v, 97, 259)) {
+                                break lab4;
+                            }
+                            golab5: while (true) {
+                                lab6: while (true) {
+                                    if (!env.in_grouping(G_v, 97, 259)) {
+                                        break lab6;
+                                    }
+                                    break golab5;
+                                }
+                                if (env.cursor >= env.limit) {
+                                    break lab4;
+                                }
+                                env.next_char();
+                            }
+                            break lab3;
+                        }
+                        env.cursor = v_3;
+                        if (!env.in_grouping(G_v, 97, 259)) {
+                            break lab2;
+                        }
+                        golab7: while (true) {
+                            lab8: while (true) {
+                                if (!env.out_grouping(G_v, 97, 259)) {
+                                    break lab8;
+                                }
+                                break golab7;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab2;
+                            }
+                            env.next_char();
+                        }
+                        break lab3;
+                    }
+                    break lab1;
+                }
+                env.cursor = v_2;
+                if (!env.out_grouping(G_v, 97, 259)) {
+                    break lab0;
+                }
+                lab9: while (true) {
+                    var v_6 = env.cursor;
+                    lab10: while (true) {
+                        if (!env.out_grouping(G_v, 97, 259)) {
+                            break lab10;
+                        }
+                        golab11: while (true) {
+                            lab12: while (true) {
+                                if (!env.in_grouping(G_v, 97, 259)) {
+                                    break lab12;
+                                }
+                                break golab11;
+                            }
+                            if (env.cursor >= env.limit) {
+                                break lab10;
+                            }
+                            env.next_char();
+                        }
+                        break lab9;
+                    }
+                    env.cursor = v_6;
+                    if (!env.in_grouping(G_v, 97, 259)) {
+                        break lab0;
+                    }
+                    if (env.cursor >= env.limit) {
+                        break lab0;
+                    }
+                    env.next_char();
+                    break lab9;
+                }
+                break lab1;
+            }
+            context.i_pV = env.cursor;
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_8 = env.cursor;
+        lab13: while (true) {
+            golab14: while (true) {
+                lab15: while (true) {
+                    if (!env.in_grouping(G_v, 97, 259)) {
+                        break lab15;
+                    }
+                    break golab14;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            golab16: while (true) {
+                lab17: while (true) {
+                    if (!env.out_grouping(G_v, 97, 259)) {
+                        break lab17;
+                    }
+                    break golab16;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            context.i_p1 = env.cursor;
+            golab18: while (true) {
+                lab19: while (true) {
+                    if (!env.in_grouping(G_v, 97, 259)) {
+                        break lab19;
+                    }
+                    break golab18;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            golab20: while (true) {
+                lab21: while (true) {
+                    if (!env.out_grouping(G_v, 97, 259)) {
+                        break lab21;
+                    }
+                    break golab20;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab13;
+                }
+                env.next_char();
+            }
+            context.i_p2 = env.cursor;
+            break lab13;
+        }
+        env.cursor = v_8;
+        return true;
+    }
+    function r_postlude(env, context) {
+        var among_var;
+        replab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                env.bra = env.cursor;
+                among_var = env.find_among(A_0, context);
+                if (among_var == 0) {
+                    break lab1;
+                }
+                env.ket = env.cursor;
+                if (among_var == 0) {
+                    break lab1;
+                } else if (among_var == 1) {
+                    if (!env.slice_from("i")) {
+                        return false;
+                    }
+                } else if (among_var == 2) {
+                    if (!env.slice_from("u")) {
+                        return false;
+                    }
+                } else if (among_var == 3) {
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                continue replab0;
+            }
+            env.cursor = v_1;
+            break replab0;
+        }
+        return true;
+    }
+    function r_RV(env, context) {
+        if (!(context.i_pV <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R1(env, context) {
+        if (!(context.i_p1 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_R2(env, context) {
+        if (!(context.i_p2 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_step_0(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_1, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (!r_R1(env, context)) {
+            return false;
+        }
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_from("a")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_from("e")) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (!env.slice_from("i")) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            var v_1 = env.limit - env.cursor;
+            lab0: while (true) {
+                if (!env.eq_s_b("ab")) {
+                    break lab0;
+                }
+                return false;
+            }
+            env.cursor = env.limit - v_1;
+            if (!env.slice_from("i")) {
+                return false;
+            }
+        } else if (among_var == 6) {
+            if (!env.slice_from("at")) {
+                return false;
+            }
+        } else if (among_var == 7) {
+            if (!env.slice_from("a\u0163i")) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_combo_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_2, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (!r_R1(env, context)) {
+            return false;
+        }
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_from("abil")) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_from("ibil")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_from("iv")) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (!env.slice_from("ic")) {
+                return false;
+            }
+        } else if (among_var == 5) {
+            if (!env.slice_from("at")) {
+                return false;
+            }
+        } else if (among_var == 6) {
+            if (!env.slice_from("it")) {
+                return false;
+            }
+        }
+        context.b_standard_suffix_removed = true;
+        env.cursor = env.limit - v_1;
+        return true;
+    }
+    function r_standard_suffix(env, context) {
+        var among_var;
+        context.b_standard_suffix_removed = false;
+        replab0: while (true) {
+            var v_1 = env.limit - env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                if (!r_combo_suffix(env, context)) {
+                    break lab1;
+                }
+                continue replab0;
+            }
+            env.cursor = env.limit - v_1;
+            break replab0;
+        }
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_3, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.eq_s_b("\u0163")) {
+                return false;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_from("t")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_from("ist")) {
+                return false;
+            }
+        }
+        context.b_standard_suffix_removed = true;
+        return true;
+    }
+    function r_verb_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_pV) {
+            return false;
+        }
+        env.cursor = context.i_pV;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_4, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        } else if (among_var == 1) {
+            lab0: while (true) {
+                var v_3 = env.limit - env.cursor;
+                lab1: while (true) {
+                    if (!env.out_grouping_b(G_v, 97, 259)) {
+                        break lab1;
+                    }
+                    break lab0;
+                }
+                env.cursor = env.limit - v_3;
+                if (!env.eq_s_b("u")) {
+                    env.limit_backward = v_2;
+                    return false;
+                }
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        env.limit_backward = v_2;
+        return true;
+    }
+    function r_vowel_suffix(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_5, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (!r_RV(env, context)) {
+            return false;
+        }
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            b_standard_suffix_removed: false,
+            i_p2: 0,
+            i_p1: 0,
+            i_pV: 0,
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_prelude(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        var v_2 = env.cursor;
+        lab1: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = v_2;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            if (!r_step_0(env, context)) {
+                break lab2;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_4 = env.limit - env.cursor;
+        lab3: while (true) {
+            if (!r_standard_suffix(env, context)) {
+                break lab3;
+            }
+            break lab3;
+        }
+        env.cursor = env.limit - v_4;
+        var v_5 = env.limit - env.cursor;
+        lab4: while (true) {
+            lab5: while (true) {
+                var v_6 = env.limit - env.cursor;
+                lab6: while (true) {
+                    if (!context.b_standard_suffix_removed) {
+                        break lab6;
+                    }
+                    break lab5;
+                }
+                env.cursor = env.limit - v_6;
+                if (!r_verb_suffix(env, context)) {
+                    break lab4;
+                }
+                break lab5;
+            }
+            break lab4;
+        }
+        env.cursor = env.limit - v_5;
+        var v_7 = env.limit - env.cursor;
+        lab7: while (true) {
+            if (!r_vowel_suffix(env, context)) {
+                break lab7;
+            }
+            break lab7;
+        }
+        env.cursor = env.limit - v_7;
+        env.cursor = env.limit_backward;
+        var v_8 = env.cursor;
+        lab8: while (true) {
+            if (!r_postlude(env, context)) {
+                break lab8;
+            }
+            break lab8;
+        }
+        env.cursor = v_8;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("ro", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "acea", "aceasta", "aceast\u0103", "aceea", "acei", "aceia", "acel",
+            "acela", "acele", "acelea", "acest", "acesta", "aceste", "acestea",
+            "ace\u015Fti", "ace\u015Ftia", "acolo", "acord", "acum", "ai", "aia",
+            "aib\u0103", "aici", "al", "ale", "alea", "altceva", "altcineva", "am",
+            "ar", "are", "asemenea", "asta", "astea", "ast\u0103zi", "asupra", "au",
+            "avea", "avem", "ave\u0163i", "azi", "a\u015F", "a\u015Fadar",
+            "a\u0163i", "bine", "bucur", "bun\u0103", "ca", "care", "caut", "ce",
+            "cel", "ceva", "chiar", "cinci", "cine", "cineva", "contra", "cu",
+            "cum", "cumva", "cur\u00E2nd", "cur\u00EEnd", "c\u00E2nd", "c\u00E2t",
+            "c\u00E2te", "c\u00E2tva", "c\u00E2\u0163i", "c\u00EEnd", "c\u00EEt",
+            "c\u00EEte", "c\u00EEtva", "c\u00EE\u0163i", "c\u0103", "c\u0103ci",
+            "c\u0103rei", "c\u0103ror", "c\u0103rui", "c\u0103tre", "da",
+            "dac\u0103", "dar", "datorit\u0103", "dat\u0103", "dau", "de", "deci",
+            "deja", "deoarece", "departe", "de\u015Fi", "din", "dinaintea",
+            "dintr-", "dintre", "doi", "doilea", "dou\u0103", "drept", "dup\u0103",
+            "d\u0103", "ea", "ei", "el", "ele", "eram", "este", "eu", "e\u015Fti",
+            "face", "fata", "fi", "fie", "fiecare", "fii", "fim", "fiu",
+            "fi\u0163i", "frumos", "f\u0103r\u0103", "gra\u0163ie", "halb\u0103",
+            "iar", "ieri", "la", "le", "li", "lor", "lui", "l\u00E2ng\u0103",
+            "l\u00EEng\u0103", "mai", "mea", "mei", "mele", "mereu", "meu", "mi",
+            "mie", "mine", "mult", "mult\u0103", "mul\u0163i", "mul\u0163umesc",
+            "m\u00E2ine", "m\u00EEine", "m\u0103", "ne", "nevoie", "nici",
+            "nic\u0103ieri", "nimeni", "nimeri", "nimic", "ni\u015Fte", "noastre",
+            "noastr\u0103", "noi", "noroc", "nostru", "nou\u0103", "no\u015Ftri",
+            "nu", "opt", "ori", "oricare", "orice", "oricine", "oricum",
+            "oric\u00E2nd", "oric\u00E2t", "oric\u00EEnd", "oric\u00EEt", "oriunde",
+            "patra", "patru", "patrulea", "pe", "pentru", "peste", "pic", "poate",
+            "pot", "prea", "prima", "primul", "prin", "pu\u0163in", "pu\u0163ina",
+            "pu\u0163in\u0103", "p\u00E2n\u0103", "p\u00EEn\u0103", "rog", "sa",
+            "sale", "sau", "se", "spate", "spre", "sub", "sunt", "suntem",
+            "sunte\u0163i", "sut\u0103", "s\u00EEnt", "s\u00EEntem",
+            "s\u00EEnte\u0163i", "s\u0103", "s\u0103i", "s\u0103u", "ta", "tale",
+            "te", "timp", "tine", "toate", "toat\u0103", "tot", "totu\u015Fi",
+            "to\u0163i", "trei", "treia", "treilea", "tu", "t\u0103i", "t\u0103u",
+            "un", "una", "unde", "undeva", "unei", "uneia", "unele", "uneori",
+            "unii", "unor", "unora", "unu", "unui", "unuia", "unul", "vi",
+            "voastre", "voastr\u0103", "voi", "vostru", "vou\u0103", "vo\u015Ftri",
+            "vreme", "vreo", "vreun", "v\u0103", "zece", "zero", "zi", "zice",
+            "\u00EEi", "\u00EEl", "\u00EEmi", "\u00EEmpotriva", "\u00EEn",
+            "\u00EEnainte", "\u00EEnaintea", "\u00EEncotro", "\u00EEnc\u00E2t",
+            "\u00EEnc\u00EEt", "\u00EEntre", "\u00EEntruc\u00E2t",
+            "\u00EEntruc\u00EEt", "\u00EE\u0163i", "\u0103la", "\u0103lea",
+            "\u0103sta", "\u0103stea", "\u0103\u015Ftia", "\u015Fapte", "\u015Fase",
+            "\u015Fi", "\u015Ftiu", "\u0163i", "\u0163ie"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
@@ -0,0 +1,832 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for Russian,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball Russian stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("\u0432", -1, 1),
+        new Among("\u0438\u0432", 0, 2),
+        new Among("\u044B\u0432", 0, 2),
+        new Among("\u0432\u0448\u0438", -1, 1),
+        new Among("\u0438\u0432\u0448\u0438", 3, 2),
+        new Among("\u044B\u0432\u0448\u0438", 3, 2),
+        new Among("\u0432\u0448\u0438\u0441\u044C", -1, 1),
+        new Among("\u0438\u0432\u0448\u0438\u0441\u044C", 6, 2),
+        new Among("\u044B\u0432\u0448\u0438\u0441\u044C", 6, 2)
+    ];
+
+    var A_1 = [
+        new Among("\u0435\u0435", -1, 1),
+        new Among("\u0438\u0435", -1, 1),
+        new Among("\u043E\u0435", -1, 1),
+        new Among("\u044B\u0435", -1, 1),
+        new Among("\u0438\u043C\u0438", -1, 1),
+        new Among("\u044B\u043C\u0438", -1, 1),
+        new Among("\u0435\u0439", -1, 1),
+        new Among("\u0438\u0439", -1, 1),
+        new Among("\u043E\u0439", -1, 1),
+        new Among("\u044B\u0439", -1, 1),
+        new Among("\u0435\u043C", -1, 1),
+        new Among("\u0438\u043C", -1, 1),
+        new Among("\u043E\u043C", -1, 1),
+        new Among("\u044B\u043C", -1, 1),
+        new Among("\u0435\u0433\u043E", -1, 1),
+        new Among("\u043E\u0433\u043E", -1, 1),
+        new Among("\u0435\u043C\u0443", -1, 1),
+        new Among("\u043E\u043C\u0443", -1, 1),
+        new Among("\u0438\u0445", -1, 1),
+        new Among("\u044B\u0445", -1, 1),
+        new Among("\u0435\u044E", -1, 1),
+        new Among("\u043E\u044E", -1, 1),
+        new Among("\u0443\u044E", -1, 1),
+        new Among("\u044E\u044E", -1, 1),
+        new Among("\u0430\u044F", -1, 1),
+        new Among("\u044F\u044F", -1, 1)
+    ];
+
+    var A_2 = [
+        new Among("\u0435\u043C", -1, 1),
+        new Among("\u043D\u043D", -1, 1),
+        new Among("\u0432\u0448", -1, 1),
+        new Among("\u0438\u0432\u0448", 2, 2),
+        new Among("\u044B\u0432\u0448", 2, 2),
+        new Among("\u0449", -1, 1),
+        new Among("\u044E\u0449", 5, 1),
+        new Among("\u0443\u044E\u0449", 6, 2)
+    ];
+
+    var A_3 = [
+        new Among("\u0441\u044C", -1, 1),
+        new Among("\u0441\u044F", -1, 1)
+    ];
+
+    var A_4 = [
+        new Among("\u043B\u0430", -1, 1),
+        new Among("\u0438\u043B\u0430", 0, 2),
+        new Among("\u044B\u043B\u0430", 0, 2),
+        new Among("\u043D\u0430", -1, 1),
+        new Among("\u0435\u043D\u0430", 3, 2),
+        new Among("\u0435\u0442\u0435", -1, 1),
+        new Among("\u0438\u0442\u0435", -1, 2),
+        new Among("\u0439\u0442\u0435", -1, 1),
+        new Among("\u0435\u0439\u0442\u0435", 7, 2),
+        new Among("\u0443\u0439\u0442\u0435", 7, 2),
+        new Among("\u043B\u0438", -1, 1),
+        new Among("\u0438\u043B\u0438", 10, 2),
+        new Among("\u044B\u043B\u0438", 10, 2),
+        new Among("\u0439", -1, 1),
+        new Among("\u0435\u0439", 13, 2),
+        new Among("\u0443\u0439", 13, 2),
+        new Among("\u043B", -1, 1),
+        new Among("\u0438\u043B", 16, 2),
+        new Among("\u044B\u043B", 16, 2),
+        new Among("\u0435\u043C", -1, 1),
+        new Among("\u0438\u043C", -1, 2),
+        new Among("\u044B\u043C", -1, 2),
+        new Among("\u043D", -1, 1),
+        new Among("\u0435\u043D", 22, 2),
+        new Among("\u043B\u043E", -1, 1),
+        new Among("\u0438\u043B\u043E", 24, 2),
+        new Among("\u044B\u043B\u043E", 24, 2),
+        new Among("\u043D\u043E", -1, 1),
+        new Among("\u0435\u043D\u043E", 27, 2),
+        new Among("\u043D\u043D\u043E", 27, 1),
+        new Among("\u0435\u0442", -1, 1),
+        new Among("\u0443\u0435\u0442", 30, 2),
+        new Among("\u0438\u0442", -1, 2),
+        new Among("\u044B\u0442", -1, 2),
+        new Among("\u044E\u0442", -1, 1),
+        new Among("\u0443\u044E\u0442", 34, 2),
+        new Among("\u044F\u0442", -1, 2),
+        new Among("\u043D\u044B", -1, 1),
+        new Among("\u0435\u043D\u044B", 37, 2),
+        new Among("\u0442\u044C", -1, 1),
+        new Among("\u0438\u0442\u044C", 39, 2),
+        new Among("\u044B\u0442\u044C", 39, 2),
+        new Among("\u0435\u0448\u044C", -1, 1),
+        new Among("\u0438\u0448\u044C", -1, 2),
+        new Among("\u044E", -1, 2),
+        new Among("\u0443\u044E", 44, 2)
+    ];
+
+    var A_5 = [
+        new Among("\u0430", -1, 1),
+        new Among("\u0435\u0432", -1, 1),
+        new Among("\u043E\u0432", -1, 1),
+        new Among("\u0435", -1, 1),
+        new Among("\u0438\u0435", 3, 1),
+        new Among("\u044C\u0435", 3, 1),
+        new Among("\u0438", -1, 1),
+        new Among("\u0435\u0438", 6, 1),
+        new Among("\u0438\u0438", 6, 1),
+        new Among("\u0430\u043C\u0438", 6, 1),
+        new Among("\u044F\u043C\u0438", 6, 1),
+        new Among("\u0438\u044F\u043C\u0438", 10, 1),
+        new Among("\u0439", -1, 1),
+        new Among("\u0435\u0439", 12, 1),
+        new Among("\u0438\u0435\u0439", 13, 1),
+        new Among("\u0438\u0439", 12, 1),
+        new Among("\u043E\u0439", 12, 1),
+        new Among("\u0430\u043C", -1, 1),
+        new Among("\u0435\u043C", -1, 1),
+        new Among("\u0438\u0435\u043C", 18, 1),
+        new Among("\u043E\u043C", -1, 1),
+        new Among("\u044F\u043C", -1, 1),
+        new Among("\u0438\u044F\u043C", 21, 1),
+        new Among("\u043E", -1, 1),
+        new Among("\u0443", -1, 1),
+        new Among("\u0430\u0445", -1, 1),
+        new Among("\u044F\u0445", -1, 1),
+        new Among("\u0438\u044F\u0445", 26, 1),
+        new Among("\u044B", -1, 1),
+        new Among("\u044C", -1, 1),
+        new Among("\u044E", -1, 1),
+        new Among("\u0438\u044E", 30, 1),
+        new Among("\u044C\u044E", 30, 1),
+        new Among("\u044F", -1, 1),
+        new Among("\u0438\u044F", 33, 1),
+        new Among("\u044C\u044F", 33, 1)
+    ];
+
+    var A_6 = [
+        new Among("\u043E\u0441\u0442", -1, 1),
+        new Among("\u043E\u0441\u0442\u044C", -1, 1)
+    ];
+
+    var A_7 = [
+        new Among("\u0435\u0439\u0448\u0435", -1, 1),
+        new Among("\u043D", -1, 2),
+        new Among("\u0435\u0439\u0448", -1, 1),
+        new Among("\u044C", -1, 3)
+    ];
+
+    var G_v = [33, 65, 8, 232];
+
+    function r_mark_regions(env, context) {
+        context.i_pV = env.limit;
+        context.i_p2 = env.limit;
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            golab1: while (true) {
+                lab2: while (true) {
+                    if (!env.in_grouping(G_v, 1072, 1103)) {
+                        break lab2;
+                    }
+                    break golab1;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+            }
+            context.i_pV = env.cursor;
+            golab3: while (true) {
+                lab4: while (true) {
+                    if (!env.out_grouping(G_v, 1072, 1103)) {
+                        break lab4;
+                    }
+                    break golab3;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+            }
+            golab5: while (true) {
+                lab6: while (true) {
+                    if (!env.in_grouping(G_v, 1072, 1103)) {
+                        break lab6;
+                    }
+                    break golab5;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+            }
+            golab7: while (true) {
+                lab8: while (true) {
+                    if (!env.out_grouping(G_v, 1072, 1103)) {
+                        break lab8;
+                    }
+                    break golab7;
+                }
+                if (env.cursor >= env.limit) {
+                    break lab0;
+                }
+                env.next_char();
+            }
+            context.i_p2 = env.cursor;
+            break lab0;
+        }
+        env.cursor = v_1;
+        return true;
+    }
+    function r_R2(env, context) {
+        if (!(context.i_p2 <= env.cursor)) {
+            return false;
+        }
+        return true;
+    }
+    function r_perfective_gerund(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_0, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            lab0: while (true) {
+                var v_1 = env.limit - env.cursor;
+                lab1: while (true) {
+                    if (!env.eq_s_b("\u0430")) {
+                        break lab1;
+                    }
+                    break lab0;
+                }
+                env.cursor = env.limit - v_1;
+                if (!env.eq_s_b("\u044F")) {
+                    return false;
+                }
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_adjective(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_1, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_adjectival(env, context) {
+        var among_var;
+        if (!r_adjective(env, context)) {
+            return false;
+        }
+        var v_1 = env.limit - env.cursor;
+        lab0: while (true) {
+            env.ket = env.cursor;
+            among_var = env.find_among_b(A_2, context);
+            if (among_var == 0) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            }
+            env.bra = env.cursor;
+            if (among_var == 0) {
+                env.cursor = env.limit - v_1;
+                break lab0;
+            } else if (among_var == 1) {
+                lab1: while (true) {
+                    var v_2 = env.limit - env.cursor;
+                    lab2: while (true) {
+                        if (!env.eq_s_b("\u0430")) {
+                            break lab2;
+                        }
+                        break lab1;
+                    }
+                    env.cursor = env.limit - v_2;
+                    if (!env.eq_s_b("\u044F")) {
+                        env.cursor = env.limit - v_1;
+                        break lab0;
+                    }
+                    break lab1;
+                }
+                if (!env.slice_del()) {
+                    return false;
+                }
+            } else if (among_var == 2) {
+                if (!env.slice_del()) {
+                    return false;
+                }
+            }
+            break lab0;
+        }
+        return true;
+    }
+    function r_reflexive(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_3, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_verb(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_4, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            lab0: while (true) {
+                var v_1 = env.limit - env.cursor;
+                lab1: while (true) {
+                    if (!env.eq_s_b("\u0430")) {
+                        break lab1;
+                    }
+                    break lab0;
+                }
+                env.cursor = env.limit - v_1;
+                if (!env.eq_s_b("\u044F")) {
+                    return false;
+                }
+                break lab0;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_noun(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_5, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_derivational(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_6, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (!r_R2(env, context)) {
+            return false;
+        }
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_tidy_up(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_7, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+            env.ket = env.cursor;
+            if (!env.eq_s_b("\u043D")) {
+                return false;
+            }
+            env.bra = env.cursor;
+            if (!env.eq_s_b("\u043D")) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.eq_s_b("\u043D")) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            i_p2: 0,
+            i_pV: 0,
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_2 = env.limit - env.cursor;
+        if (env.cursor < context.i_pV) {
+            return false;
+        }
+        env.cursor = context.i_pV;
+        var v_3 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_2;
+        var v_4 = env.limit - env.cursor;
+        lab1: while (true) {
+            lab2: while (true) {
+                var v_5 = env.limit - env.cursor;
+                lab3: while (true) {
+                    if (!r_perfective_gerund(env, context)) {
+                        break lab3;
+                    }
+                    break lab2;
+                }
+                env.cursor = env.limit - v_5;
+                var v_6 = env.limit - env.cursor;
+                lab4: while (true) {
+                    if (!r_reflexive(env, context)) {
+                        env.cursor = env.limit - v_6;
+                        break lab4;
+                    }
+                    break lab4;
+                }
+                lab5: while (true) {
+                    var v_7 = env.limit - env.cursor;
+                    lab6: while (true) {
+                        if (!r_adjectival(env, context)) {
+                            break lab6;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_7;
+                    lab7: while (true) {
+                        if (!r_verb(env, context)) {
+                            break lab7;
+                        }
+                        break lab5;
+                    }
+                    env.cursor = env.limit - v_7;
+                    if (!r_noun(env, context)) {
+                        break lab1;
+                    }
+                    break lab5;
+                }
+                break lab2;
+            }
+            break lab1;
+        }
+        env.cursor = env.limit - v_4;
+        var v_8 = env.limit - env.cursor;
+        lab8: while (true) {
+            env.ket = env.cursor;
+            if (!env.eq_s_b("\u0438")) {
+                env.cursor = env.limit - v_8;
+                break lab8;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            break lab8;
+        }
+        var v_9 = env.limit - env.cursor;
+        lab9: while (true) {
+            if (!r_derivational(env, context)) {
+                break lab9;
+            }
+            break lab9;
+        }
+        env.cursor = env.limit - v_9;
+        var v_10 = env.limit - env.cursor;
+        lab10: while (true) {
+            if (!r_tidy_up(env, context)) {
+                break lab10;
+            }
+            break lab10;
+        }
+        env.cursor = env.limit - v_10;
+        env.limit_backward = v_3;
+        env.cursor = env.limit_backward;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("ru", {
+        wordCharacters: "\\u0400-\\u0484\\u0487-\\u052F\\u1D2B\\u1D78\\u2DE0-\\u2DFF\\uA640-\\uA69F\\uFE2E\\uFE2F",
+        stopWords: [
+            "", "\u0430\u043B\u043B\u043E", "\u0431\u0435\u0437",
+            "\u0431\u043B\u0438\u0437\u043A\u043E",
+            "\u0431\u043E\u043B\u0435\u0435",
+            "\u0431\u043E\u043B\u044C\u0448\u0435",
+            "\u0431\u0443\u0434\u0435\u043C", "\u0431\u0443\u0434\u0435\u0442",
+            "\u0431\u0443\u0434\u0435\u0442\u0435",
+            "\u0431\u0443\u0434\u0435\u0448\u044C",
+            "\u0431\u0443\u0434\u0442\u043E", "\u0431\u0443\u0434\u0443",
+            "\u0431\u0443\u0434\u0443\u0442", "\u0431\u0443\u0434\u044C",
+            "\u0431\u044B", "\u0431\u044B\u0432\u0430\u0435\u0442",
+            "\u0431\u044B\u0432\u044C", "\u0431\u044B\u043B",
+            "\u0431\u044B\u043B\u0430", "\u0431\u044B\u043B\u0438",
+            "\u0431\u044B\u043B\u043E", "\u0431\u044B\u0442\u044C", "\u0432",
+            "\u0432\u0430\u0436\u043D\u0430\u044F",
+            "\u0432\u0430\u0436\u043D\u043E\u0435",
+            "\u0432\u0430\u0436\u043D\u044B\u0435",
+            "\u0432\u0430\u0436\u043D\u044B\u0439", "\u0432\u0430\u043C",
+            "\u0432\u0430\u043C\u0438", "\u0432\u0430\u0441", "\u0432\u0430\u0448",
+            "\u0432\u0430\u0448\u0430", "\u0432\u0430\u0448\u0435",
+            "\u0432\u0430\u0448\u0438", "\u0432\u0432\u0435\u0440\u0445",
+            "\u0432\u0434\u0430\u043B\u0438", "\u0432\u0434\u0440\u0443\u0433",
+            "\u0432\u0435\u0434\u044C", "\u0432\u0435\u0437\u0434\u0435",
+            "\u0432\u0435\u0441\u044C", "\u0432\u043D\u0438\u0437",
+            "\u0432\u043D\u0438\u0437\u0443", "\u0432\u043E",
+            "\u0432\u043E\u043A\u0440\u0443\u0433", "\u0432\u043E\u043D",
+            "\u0432\u043E\u0441\u0435\u043C\u043D\u0430\u0434\u0446\u0430\u0442\u044B\u0439",
+            "\u0432\u043E\u0441\u0435\u043C\u043D\u0430\u0434\u0446\u0430\u0442\u044C",
+            "\u0432\u043E\u0441\u0435\u043C\u044C",
+            "\u0432\u043E\u0441\u044C\u043C\u043E\u0439", "\u0432\u043E\u0442",
+            "\u0432\u043F\u0440\u043E\u0447\u0435\u043C",
+            "\u0432\u0440\u0435\u043C\u0435\u043D\u0438",
+            "\u0432\u0440\u0435\u043C\u044F", "\u0432\u0441\u0435",
+            "\u0432\u0441\u0435\u0433\u0434\u0430",
+            "\u0432\u0441\u0435\u0433\u043E", "\u0432\u0441\u0435\u043C",
+            "\u0432\u0441\u0435\u043C\u0438", "\u0432\u0441\u0435\u043C\u0443",
+            "\u0432\u0441\u0435\u0445", "\u0432\u0441\u0435\u044E",
+            "\u0432\u0441\u044E", "\u0432\u0441\u044E\u0434\u0443",
+            "\u0432\u0441\u044F", "\u0432\u0441\u0451",
+            "\u0432\u0442\u043E\u0440\u043E\u0439", "\u0432\u044B", "\u0433",
+            "\u0433\u0434\u0435", "\u0433\u043E\u0432\u043E\u0440\u0438\u043B",
+            "\u0433\u043E\u0432\u043E\u0440\u0438\u0442", "\u0433\u043E\u0434",
+            "\u0433\u043E\u0434\u0430", "\u0433\u043E\u0434\u0443", "\u0434\u0430",
+            "\u0434\u0430\u0432\u043D\u043E", "\u0434\u0430\u0436\u0435",
+            "\u0434\u0430\u043B\u0435\u043A\u043E",
+            "\u0434\u0430\u043B\u044C\u0448\u0435",
+            "\u0434\u0430\u0440\u043E\u043C", "\u0434\u0432\u0430",
+            "\u0434\u0432\u0430\u0434\u0446\u0430\u0442\u044B\u0439",
+            "\u0434\u0432\u0430\u0434\u0446\u0430\u0442\u044C",
+            "\u0434\u0432\u0435",
+            "\u0434\u0432\u0435\u043D\u0430\u0434\u0446\u0430\u0442\u044B\u0439",
+            "\u0434\u0432\u0435\u043D\u0430\u0434\u0446\u0430\u0442\u044C",
+            "\u0434\u0432\u0443\u0445",
+            "\u0434\u0435\u0432\u044F\u0442\u043D\u0430\u0434\u0446\u0430\u0442\u044B\u0439",
+            "\u0434\u0435\u0432\u044F\u0442\u043D\u0430\u0434\u0446\u0430\u0442\u044C",
+            "\u0434\u0435\u0432\u044F\u0442\u044B\u0439",
+            "\u0434\u0435\u0432\u044F\u0442\u044C",
+            "\u0434\u0435\u0439\u0441\u0442\u0432\u0438\u0442\u0435\u043B\u044C\u043D\u043E",
+            "\u0434\u0435\u043B", "\u0434\u0435\u043D\u044C",
+            "\u0434\u0435\u0441\u044F\u0442\u044B\u0439",
+            "\u0434\u0435\u0441\u044F\u0442\u044C", "\u0434\u043B\u044F",
+            "\u0434\u043E", "\u0434\u043E\u0432\u043E\u043B\u044C\u043D\u043E",
+            "\u0434\u043E\u043B\u0433\u043E",
+            "\u0434\u043E\u043B\u0436\u043D\u043E",
+            "\u0434\u0440\u0443\u0433\u0430\u044F",
+            "\u0434\u0440\u0443\u0433\u0438\u0435",
+            "\u0434\u0440\u0443\u0433\u0438\u0445",
+            "\u0434\u0440\u0443\u0433\u043E",
+            "\u0434\u0440\u0443\u0433\u043E\u0435",
+            "\u0434\u0440\u0443\u0433\u043E\u0439", "\u0435", "\u0435\u0433\u043E",
+            "\u0435\u0435", "\u0435\u0439", "\u0435\u043C\u0443",
+            "\u0435\u0441\u043B\u0438", "\u0435\u0441\u0442\u044C",
+            "\u0435\u0449\u0435", "\u0435\u0449\u0451", "\u0435\u044E",
+            "\u0435\u0451", "\u0436", "\u0436\u0435",
+            "\u0436\u0438\u0437\u043D\u044C", "\u0437\u0430",
+            "\u0437\u0430\u043D\u044F\u0442",
+            "\u0437\u0430\u043D\u044F\u0442\u0430",
+            "\u0437\u0430\u043D\u044F\u0442\u043E",
+            "\u0437\u0430\u043D\u044F\u0442\u044B",
+            "\u0437\u0430\u0442\u0435\u043C", "\u0437\u0430\u0442\u043E",
+            "\u0437\u0430\u0447\u0435\u043C", "\u0437\u0434\u0435\u0441\u044C",
+            "\u0437\u043D\u0430\u0447\u0438\u0442", "\u0438", "\u0438\u0437",
+            "\u0438\u043B\u0438", "\u0438\u043C",
+            "\u0438\u043C\u0435\u043D\u043D\u043E",
+            "\u0438\u043C\u0435\u0442\u044C", "\u0438\u043C\u0438",
+            "\u0438\u043C\u044F", "\u0438\u043D\u043E\u0433\u0434\u0430",
+            "\u0438\u0445", "\u043A", "\u043A\u0430\u0436\u0434\u0430\u044F",
+            "\u043A\u0430\u0436\u0434\u043E\u0435",
+            "\u043A\u0430\u0436\u0434\u044B\u0435",
+            "\u043A\u0430\u0436\u0434\u044B\u0439",
+            "\u043A\u0430\u0436\u0435\u0442\u0441\u044F", "\u043A\u0430\u043A",
+            "\u043A\u0430\u043A\u0430\u044F", "\u043A\u0430\u043A\u043E\u0439",
+            "\u043A\u0435\u043C", "\u043A\u043E\u0433\u0434\u0430",
+            "\u043A\u043E\u0433\u043E", "\u043A\u043E\u043C",
+            "\u043A\u043E\u043C\u0443",
+            "\u043A\u043E\u043D\u0435\u0447\u043D\u043E",
+            "\u043A\u043E\u0442\u043E\u0440\u0430\u044F",
+            "\u043A\u043E\u0442\u043E\u0440\u043E\u0433\u043E",
+            "\u043A\u043E\u0442\u043E\u0440\u043E\u0439",
+            "\u043A\u043E\u0442\u043E\u0440\u044B\u0435",
+            "\u043A\u043E\u0442\u043E\u0440\u044B\u0439",
+            "\u043A\u043E\u0442\u043E\u0440\u044B\u0445",
+            "\u043A\u0440\u043E\u043C\u0435",
+            "\u043A\u0440\u0443\u0433\u043E\u043C", "\u043A\u0442\u043E",
+            "\u043A\u0443\u0434\u0430", "\u043B\u0435\u0442", "\u043B\u0438",
+            "\u043B\u0438\u0448\u044C", "\u043B\u0443\u0447\u0448\u0435",
+            "\u043B\u044E\u0434\u0438", "\u043C", "\u043C\u0430\u043B\u043E",
+            "\u043C\u0435\u0436\u0434\u0443", "\u043C\u0435\u043B\u044F",
+            "\u043C\u0435\u043D\u0435\u0435",
+            "\u043C\u0435\u043D\u044C\u0448\u0435", "\u043C\u0435\u043D\u044F",
+            "\u043C\u0438\u043B\u043B\u0438\u043E\u043D\u043E\u0432",
+            "\u043C\u0438\u043C\u043E", "\u043C\u0438\u0440\u0430",
+            "\u043C\u043D\u0435", "\u043C\u043D\u043E\u0433\u043E",
+            "\u043C\u043D\u043E\u0433\u043E\u0447\u0438\u0441\u043B\u0435\u043D\u043D\u0430\u044F",
+            "\u043C\u043D\u043E\u0433\u043E\u0447\u0438\u0441\u043B\u0435\u043D\u043D\u043E\u0435",
+            "\u043C\u043D\u043E\u0433\u043E\u0447\u0438\u0441\u043B\u0435\u043D\u043D\u044B\u0435",
+            "\u043C\u043D\u043E\u0433\u043E\u0447\u0438\u0441\u043B\u0435\u043D\u043D\u044B\u0439",
+            "\u043C\u043D\u043E\u0439", "\u043C\u043D\u043E\u044E",
+            "\u043C\u043E\u0433", "\u043C\u043E\u0433\u0443\u0442",
+            "\u043C\u043E\u0436", "\u043C\u043E\u0436\u0435\u0442",
+            "\u043C\u043E\u0436\u043D\u043E", "\u043C\u043E\u0436\u0445\u043E",
+            "\u043C\u043E\u0438", "\u043C\u043E\u0439", "\u043C\u043E\u0440",
+            "\u043C\u043E\u0447\u044C", "\u043C\u043E\u044F", "\u043C\u043E\u0451",
+            "\u043C\u044B", "\u043D\u0430",
+            "\u043D\u0430\u0432\u0435\u0440\u0445\u0443", "\u043D\u0430\u0434",
+            "\u043D\u0430\u0434\u043E", "\u043D\u0430\u0437\u0430\u0434",
+            "\u043D\u0430\u0438\u0431\u043E\u043B\u0435\u0435",
+            "\u043D\u0430\u043A\u043E\u043D\u0435\u0446", "\u043D\u0430\u043C",
+            "\u043D\u0430\u043C\u0438", "\u043D\u0430\u0441",
+            "\u043D\u0430\u0447\u0430\u043B\u0430", "\u043D\u0430\u0448",
+            "\u043D\u0430\u0448\u0430", "\u043D\u0430\u0448\u0435",
+            "\u043D\u0430\u0448\u0438", "\u043D\u0435", "\u043D\u0435\u0433\u043E",
+            "\u043D\u0435\u0434\u0430\u0432\u043D\u043E",
+            "\u043D\u0435\u0434\u0430\u043B\u0435\u043A\u043E",
+            "\u043D\u0435\u0435", "\u043D\u0435\u0439",
+            "\u043D\u0435\u043B\u044C\u0437\u044F", "\u043D\u0435\u043C",
+            "\u043D\u0435\u043C\u043D\u043E\u0433\u043E",
+            "\u043D\u0435\u043C\u0443",
+            "\u043D\u0435\u043F\u0440\u0435\u0440\u044B\u0432\u043D\u043E",
+            "\u043D\u0435\u0440\u0435\u0434\u043A\u043E",
+            "\u043D\u0435\u0441\u043A\u043E\u043B\u044C\u043A\u043E",
+            "\u043D\u0435\u0442", "\u043D\u0435\u044E", "\u043D\u0435\u0451",
+            "\u043D\u0438", "\u043D\u0438\u0431\u0443\u0434\u044C",
+            "\u043D\u0438\u0436\u0435", "\u043D\u0438\u0437\u043A\u043E",
+            "\u043D\u0438\u043A\u043E\u0433\u0434\u0430",
+            "\u043D\u0438\u043A\u0443\u0434\u0430", "\u043D\u0438\u043C\u0438",
+            "\u043D\u0438\u0445", "\u043D\u0438\u0447\u0435\u0433\u043E",
+            "\u043D\u043E", "\u043D\u0443", "\u043D\u0443\u0436\u043D\u043E",
+            "\u043D\u0445", "\u043E", "\u043E\u0431", "\u043E\u0431\u0430",
+            "\u043E\u0431\u044B\u0447\u043D\u043E", "\u043E\u0434\u0438\u043D",
+            "\u043E\u0434\u0438\u043D\u043D\u0430\u0434\u0446\u0430\u0442\u044B\u0439",
+            "\u043E\u0434\u0438\u043D\u043D\u0430\u0434\u0446\u0430\u0442\u044C",
+            "\u043E\u0434\u043D\u0430\u0436\u0434\u044B",
+            "\u043E\u0434\u043D\u0430\u043A\u043E",
+            "\u043E\u0434\u043D\u043E\u0433\u043E",
+            "\u043E\u0434\u043D\u043E\u0439", "\u043E\u043A\u043E\u043B\u043E",
+            "\u043E\u043D", "\u043E\u043D\u0430", "\u043E\u043D\u0438",
+            "\u043E\u043D\u043E", "\u043E\u043F\u044F\u0442\u044C",
+            "\u043E\u0441\u043E\u0431\u0435\u043D\u043D\u043E", "\u043E\u0442",
+            "\u043E\u0442\u043E\u0432\u0441\u044E\u0434\u0443",
+            "\u043E\u0442\u0441\u044E\u0434\u0430",
+            "\u043E\u0447\u0435\u043D\u044C",
+            "\u043F\u0435\u0440\u0432\u044B\u0439",
+            "\u043F\u0435\u0440\u0435\u0434", "\u043F\u043E", "\u043F\u043E\u0434",
+            "\u043F\u043E\u0436\u0430\u043B\u0443\u0439\u0441\u0442\u0430",
+            "\u043F\u043E\u0437\u0436\u0435", "\u043F\u043E\u043A\u0430",
+            "\u043F\u043E\u0440", "\u043F\u043E\u0440\u0430",
+            "\u043F\u043E\u0441\u043B\u0435",
+            "\u043F\u043E\u0441\u0440\u0435\u0434\u0438",
+            "\u043F\u043E\u0442\u043E\u043C",
+            "\u043F\u043E\u0442\u043E\u043C\u0443",
+            "\u043F\u043E\u0447\u0435\u043C\u0443",
+            "\u043F\u043E\u0447\u0442\u0438",
+            "\u043F\u0440\u0435\u043A\u0440\u0430\u0441\u043D\u043E",
+            "\u043F\u0440\u0438", "\u043F\u0440\u043E",
+            "\u043F\u0440\u043E\u0441\u0442\u043E",
+            "\u043F\u0440\u043E\u0442\u0438\u0432",
+            "\u043F\u0440\u043E\u0446\u0435\u043D\u0442\u043E\u0432",
+            "\u043F\u044F\u0442\u043D\u0430\u0434\u0446\u0430\u0442\u044B\u0439",
+            "\u043F\u044F\u0442\u043D\u0430\u0434\u0446\u0430\u0442\u044C",
+            "\u043F\u044F\u0442\u044B\u0439", "\u043F\u044F\u0442\u044C",
+            "\u0440\u0430\u0437", "\u0440\u0430\u0437\u0432\u0435",
+            "\u0440\u0430\u043D\u043E", "\u0440\u0430\u043D\u044C\u0448\u0435",
+            "\u0440\u044F\u0434\u043E\u043C", "\u0441", "\u0441\u0430\u043C",
+            "\u0441\u0430\u043C\u0430", "\u0441\u0430\u043C\u0438",
+            "\u0441\u0430\u043C\u0438\u043C",
+            "\u0441\u0430\u043C\u0438\u043C\u0438",
+            "\u0441\u0430\u043C\u0438\u0445", "\u0441\u0430\u043C\u043E",
+            "\u0441\u0430\u043C\u043E\u0433\u043E",
+            "\u0441\u0430\u043C\u043E\u0439", "\u0441\u0430\u043C\u043E\u043C",
+            "\u0441\u0430\u043C\u043E\u043C\u0443", "\u0441\u0430\u043C\u0443",
+            "\u0441\u0432\u043E\u0435", "\u0441\u0432\u043E\u0435\u0433\u043E",
+            "\u0441\u0432\u043E\u0435\u0439", "\u0441\u0432\u043E\u0438",
+            "\u0441\u0432\u043E\u0438\u0445", "\u0441\u0432\u043E\u044E",
+            "\u0441\u0435\u0430\u043E\u0439", "\u0441\u0435\u0431\u0435",
+            "\u0441\u0435\u0431\u044F",
+            "\u0441\u0435\u0433\u043E\u0434\u043D\u044F",
+            "\u0441\u0435\u0434\u044C\u043C\u043E\u0439",
+            "\u0441\u0435\u0439\u0447\u0430\u0441",
+            "\u0441\u0435\u043C\u043D\u0430\u0434\u0446\u0430\u0442\u044B\u0439",
+            "\u0441\u0435\u043C\u043D\u0430\u0434\u0446\u0430\u0442\u044C",
+            "\u0441\u0435\u043C\u044C", "\u0441\u0438\u0445",
+            "\u0441\u043A\u0430\u0437\u0430\u043B",
+            "\u0441\u043A\u0430\u0437\u0430\u043B\u0430",
+            "\u0441\u043A\u0430\u0437\u0430\u0442\u044C",
+            "\u0441\u043A\u043E\u043B\u044C\u043A\u043E",
+            "\u0441\u043B\u0438\u0448\u043A\u043E\u043C",
+            "\u0441\u043D\u0430\u0447\u0430\u043B\u0430",
+            "\u0441\u043D\u043E\u0432\u0430", "\u0441\u043E",
+            "\u0441\u043E\u0431\u043E\u0439", "\u0441\u043E\u0431\u043E\u044E",
+            "\u0441\u043E\u0432\u0441\u0435\u043C",
+            "\u0441\u043F\u0430\u0441\u0438\u0431\u043E",
+            "\u0441\u0442\u0430\u043B", "\u0441\u0443\u0442\u044C", "\u0442",
+            "\u0442\u0430", "\u0442\u0430\u043A", "\u0442\u0430\u043A\u0430\u044F",
+            "\u0442\u0430\u043A\u0436\u0435", "\u0442\u0430\u043A\u0438\u0435",
+            "\u0442\u0430\u043A\u043E\u0435", "\u0442\u0430\u043A\u043E\u0439",
+            "\u0442\u0430\u043C", "\u0442\u0432\u043E\u0439",
+            "\u0442\u0432\u043E\u044F", "\u0442\u0432\u043E\u0451", "\u0442\u0435",
+            "\u0442\u0435\u0431\u0435", "\u0442\u0435\u0431\u044F",
+            "\u0442\u0435\u043C", "\u0442\u0435\u043C\u0438",
+            "\u0442\u0435\u043F\u0435\u0440\u044C", "\u0442\u0435\u0445",
+            "\u0442\u043E", "\u0442\u043E\u0431\u043E\u0439",
+            "\u0442\u043E\u0431\u043E\u044E", "\u0442\u043E\u0433\u0434\u0430",
+            "\u0442\u043E\u0433\u043E", "\u0442\u043E\u0436\u0435",
+            "\u0442\u043E\u043B\u044C\u043A\u043E", "\u0442\u043E\u043C",
+            "\u0442\u043E\u043C\u0443", "\u0442\u043E\u0442", "\u0442\u043E\u044E",
+            "\u0442\u0440\u0435\u0442\u0438\u0439", "\u0442\u0440\u0438",
+            "\u0442\u0440\u0438\u043D\u0430\u0434\u0446\u0430\u0442\u044B\u0439",
+            "\u0442\u0440\u0438\u043D\u0430\u0434\u0446\u0430\u0442\u044C",
+            "\u0442\u0443", "\u0442\u0443\u0434\u0430", "\u0442\u0443\u0442",
+            "\u0442\u044B", "\u0442\u044B\u0441\u044F\u0447", "\u0443",
+            "\u0443\u0436", "\u0443\u0436\u0435", "\u0443\u043C\u0435\u0442\u044C",
+            "\u0445\u043E\u0440\u043E\u0448\u043E",
+            "\u0445\u043E\u0442\u0435\u0442\u044C", "\u0445\u043E\u0442\u044C",
+            "\u0445\u043E\u0442\u044F", "\u0445\u043E\u0447\u0435\u0448\u044C",
+            "\u0447\u0430\u0441\u0442\u043E", "\u0447\u0430\u0449\u0435",
+            "\u0447\u0435\u0433\u043E",
+            "\u0447\u0435\u043B\u043E\u0432\u0435\u043A", "\u0447\u0435\u043C",
+            "\u0447\u0435\u043C\u0443", "\u0447\u0435\u0440\u0435\u0437",
+            "\u0447\u0435\u0442\u0432\u0435\u0440\u0442\u044B\u0439",
+            "\u0447\u0435\u0442\u044B\u0440\u0435",
+            "\u0447\u0435\u0442\u044B\u0440\u043D\u0430\u0434\u0446\u0430\u0442\u044B\u0439",
+            "\u0447\u0435\u0442\u044B\u0440\u043D\u0430\u0434\u0446\u0430\u0442\u044C",
+            "\u0447\u0442\u043E", "\u0447\u0442\u043E\u0431",
+            "\u0447\u0442\u043E\u0431\u044B", "\u0447\u0443\u0442\u044C",
+            "\u0448\u0435\u0441\u0442\u043D\u0430\u0434\u0446\u0430\u0442\u044B\u0439",
+            "\u0448\u0435\u0441\u0442\u043D\u0430\u0434\u0446\u0430\u0442\u044C",
+            "\u0448\u0435\u0441\u0442\u043E\u0439",
+            "\u0448\u0435\u0441\u0442\u044C", "\u044D\u0442\u0430",
+            "\u044D\u0442\u0438", "\u044D\u0442\u0438\u043C",
+            "\u044D\u0442\u0438\u043C\u0438", "\u044D\u0442\u0438\u0445",
+            "\u044D\u0442\u043E", "\u044D\u0442\u043E\u0433\u043E",
+            "\u044D\u0442\u043E\u0439", "\u044D\u0442\u043E\u043C",
+            "\u044D\u0442\u043E\u043C\u0443", "\u044D\u0442\u043E\u0442",
+            "\u044D\u0442\u0443", "\u044F", "\uFEFF\u0430"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
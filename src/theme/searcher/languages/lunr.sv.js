@@ -0,0 +1,291 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for Swedish,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball Swedish stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("a", -1, 1),
+        new Among("arna", 0, 1),
+        new Among("erna", 0, 1),
+        new Among("heterna", 2, 1),
+        new Among("orna", 0, 1),
+        new Among("ad", -1, 1),
+        new Among("e", -1, 1),
+        new Among("ade", 6, 1),
+        new Among("ande", 6, 1),
+        new Among("arne", 6, 1),
+        new Among("are", 6, 1),
+        new Among("aste", 6, 1),
+        new Among("en", -1, 1),
+        new Among("anden", 12, 1),
+        new Among("aren", 12, 1),
+        new Among("heten", 12, 1),
+        new Among("ern", -1, 1),
+        new Among("ar", -1, 1),
+        new Among("er", -1, 1),
+        new Among("heter", 18, 1),
+        new Among("or", -1, 1),
+        new Among("s", -1, 2),
+        new Among("as", 21, 1),
+        new Among("arnas", 22, 1),
+        new Among("ernas", 22, 1),
+        new Among("ornas", 22, 1),
+        new Among("es", 21, 1),
+        new Among("ades", 26, 1),
+        new Among("andes", 26, 1),
+        new Among("ens", 21, 1),
+        new Among("arens", 29, 1),
+        new Among("hetens", 29, 1),
+        new Among("erns", 21, 1),
+        new Among("at", -1, 1),
+        new Among("andet", -1, 1),
+        new Among("het", -1, 1),
+        new Among("ast", -1, 1)
+    ];
+
+    var A_1 = [
+        new Among("dd", -1, -1),
+        new Among("gd", -1, -1),
+        new Among("nn", -1, -1),
+        new Among("dt", -1, -1),
+        new Among("gt", -1, -1),
+        new Among("kt", -1, -1),
+        new Among("tt", -1, -1)
+    ];
+
+    var A_2 = [
+        new Among("ig", -1, 1),
+        new Among("lig", 0, 1),
+        new Among("els", -1, 1),
+        new Among("fullt", -1, 3),
+        new Among("l\u00F6st", -1, 2)
+    ];
+
+    var G_v = [17, 65, 16, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 32];
+
+    var G_s_ending = [119, 127, 149];
+
+    function r_mark_regions(env, context) {
+        context.i_p1 = env.limit;
+        var v_1 = env.cursor;
+        var c = env.hop(3);
+        if (0 > c || c > env.limit) {
+            return false;
+        }
+        env.cursor = c;
+        context.i_x = env.cursor;
+        env.cursor = v_1;
+        golab0: while (true) {
+            var v_2 = env.cursor;
+            lab1: while (true) {
+                if (!env.in_grouping(G_v, 97, 246)) {
+                    break lab1;
+                }
+                env.cursor = v_2;
+                break golab0;
+            }
+            env.cursor = v_2;
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        golab2: while (true) {
+            lab3: while (true) {
+                if (!env.out_grouping(G_v, 97, 246)) {
+                    break lab3;
+                }
+                break golab2;
+            }
+            if (env.cursor >= env.limit) {
+                return false;
+            }
+            env.next_char();
+        }
+        context.i_p1 = env.cursor;
+        lab4: while (true) {
+            if (!(context.i_p1 < context.i_x)) {
+                break lab4;
+            }
+            context.i_p1 = context.i_x;
+            break lab4;
+        }
+        return true;
+    }
+    function r_main_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_0, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        env.limit_backward = v_2;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.in_grouping_b(G_s_ending, 98, 121)) {
+                return false;
+            }
+            if (!env.slice_del()) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_consonant_pair(env, context) {
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        var v_3 = env.limit - env.cursor;
+        if (env.find_among_b(A_1, context) == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.cursor = env.limit - v_3;
+        env.ket = env.cursor;
+        if (env.cursor <= env.limit_backward) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.previous_char();
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        env.limit_backward = v_2;
+        return true;
+    }
+    function r_other_suffix(env, context) {
+        var among_var;
+        var v_1 = env.limit - env.cursor;
+        if (env.cursor < context.i_p1) {
+            return false;
+        }
+        env.cursor = context.i_p1;
+        var v_2 = env.limit_backward;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit - v_1;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_2, context);
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            env.limit_backward = v_2;
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_del()) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_from("l\u00F6s")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_from("full")) {
+                return false;
+            }
+        }
+        env.limit_backward = v_2;
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            i_x: 0,
+            i_p1: 0,
+        };
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_mark_regions(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = v_1;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_2 = env.limit - env.cursor;
+        lab1: while (true) {
+            if (!r_main_suffix(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            if (!r_consonant_pair(env, context)) {
+                break lab2;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        var v_4 = env.limit - env.cursor;
+        lab3: while (true) {
+            if (!r_other_suffix(env, context)) {
+                break lab3;
+            }
+            break lab3;
+        }
+        env.cursor = env.limit - v_4;
+        env.cursor = env.limit_backward;
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("sv", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "alla", "allt", "att", "av", "blev", "bli", "blir", "blivit", "de",
+            "dem", "den", "denna", "deras", "dess", "dessa", "det", "detta", "dig",
+            "din", "dina", "ditt", "du", "d\u00E4r", "d\u00E5", "efter", "ej",
+            "eller", "en", "er", "era", "ert", "ett", "fr\u00E5n", "f\u00F6r", "ha",
+            "hade", "han", "hans", "har", "henne", "hennes", "hon", "honom", "hur",
+            "h\u00E4r", "i", "icke", "ingen", "inom", "inte", "jag", "ju", "kan",
+            "kunde", "man", "med", "mellan", "men", "mig", "min", "mina", "mitt",
+            "mot", "mycket", "ni", "nu", "n\u00E4r", "n\u00E5gon", "n\u00E5got",
+            "n\u00E5gra", "och", "om", "oss", "p\u00E5", "samma", "sedan", "sig",
+            "sin", "sina", "sitta", "sj\u00E4lv", "skulle", "som", "s\u00E5",
+            "s\u00E5dan", "s\u00E5dana", "s\u00E5dant", "till", "under", "upp",
+            "ut", "utan", "vad", "var", "vara", "varf\u00F6r", "varit", "varje",
+            "vars", "vart", "vem", "vi", "vid", "vilka", "vilkas", "vilken",
+            "vilket", "v\u00E5r", "v\u00E5ra", "v\u00E5rt", "\u00E4n", "\u00E4r",
+            "\u00E5t", "\u00F6ver"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
@@ -0,0 +1,2205 @@
+/*!
+ * The trimmer, stop word filter and stemmer elasticlunr-rs uses for Turkish,
+ * so that search words are turned into the same terms as the book was.
+ *
+ * The stemmer is the Snowball Turkish stemmer, https://snowballstem.org/
+ * Copyright (c) 2001, Dr Martin Porter
+ * Copyright (c) 2004,2005, Richard Boulton
+ * Copyright (c) 2013, Yoshiki Shibukawa
+ * Copyright (c) 2006,2007,2009,2010,2011,2014-2019, Olly Betts
+ * All rights reserved. Distributed under the BSD 3-clause licence.
+ *
+ * Translated from the Rust code rust-stemmers 1.2.0 generated for it.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    var SnowballEnv = elasticlunr.stemmerSupport.SnowballEnv;
+    var Among = elasticlunr.stemmerSupport.Among;
+
+    var A_0 = [
+        new Among("m", -1, -1),
+        new Among("n", -1, -1),
+        new Among("miz", -1, -1),
+        new Among("niz", -1, -1),
+        new Among("muz", -1, -1),
+        new Among("nuz", -1, -1),
+        new Among("m\u00FCz", -1, -1),
+        new Among("n\u00FCz", -1, -1),
+        new Among("m\u0131z", -1, -1),
+        new Among("n\u0131z", -1, -1)
+    ];
+
+    var A_1 = [
+        new Among("leri", -1, -1),
+        new Among("lar\u0131", -1, -1)
+    ];
+
+    var A_2 = [
+        new Among("ni", -1, -1),
+        new Among("nu", -1, -1),
+        new Among("n\u00FC", -1, -1),
+        new Among("n\u0131", -1, -1)
+    ];
+
+    var A_3 = [
+        new Among("in", -1, -1),
+        new Among("un", -1, -1),
+        new Among("\u00FCn", -1, -1),
+        new Among("\u0131n", -1, -1)
+    ];
+
+    var A_4 = [
+        new Among("a", -1, -1),
+        new Among("e", -1, -1)
+    ];
+
+    var A_5 = [
+        new Among("na", -1, -1),
+        new Among("ne", -1, -1)
+    ];
+
+    var A_6 = [
+        new Among("da", -1, -1),
+        new Among("ta", -1, -1),
+        new Among("de", -1, -1),
+        new Among("te", -1, -1)
+    ];
+
+    var A_7 = [
+        new Among("nda", -1, -1),
+        new Among("nde", -1, -1)
+    ];
+
+    var A_8 = [
+        new Among("dan", -1, -1),
+        new Among("tan", -1, -1),
+        new Among("den", -1, -1),
+        new Among("ten", -1, -1)
+    ];
+
+    var A_9 = [
+        new Among("ndan", -1, -1),
+        new Among("nden", -1, -1)
+    ];
+
+    var A_10 = [
+        new Among("la", -1, -1),
+        new Among("le", -1, -1)
+    ];
+
+    var A_11 = [
+        new Among("ca", -1, -1),
+        new Among("ce", -1, -1)
+    ];
+
+    var A_12 = [
+        new Among("im", -1, -1),
+        new Among("um", -1, -1),
+        new Among("\u00FCm", -1, -1),
+        new Among("\u0131m", -1, -1)
+    ];
+
+    var A_13 = [
+        new Among("sin", -1, -1),
+        new Among("sun", -1, -1),
+        new Among("s\u00FCn", -1, -1),
+        new Among("s\u0131n", -1, -1)
+    ];
+
+    var A_14 = [
+        new Among("iz", -1, -1),
+        new Among("uz", -1, -1),
+        new Among("\u00FCz", -1, -1),
+        new Among("\u0131z", -1, -1)
+    ];
+
+    var A_15 = [
+        new Among("siniz", -1, -1),
+        new Among("sunuz", -1, -1),
+        new Among("s\u00FCn\u00FCz", -1, -1),
+        new Among("s\u0131n\u0131z", -1, -1)
+    ];
+
+    var A_16 = [
+        new Among("lar", -1, -1),
+        new Among("ler", -1, -1)
+    ];
+
+    var A_17 = [
+        new Among("niz", -1, -1),
+        new Among("nuz", -1, -1),
+        new Among("n\u00FCz", -1, -1),
+        new Among("n\u0131z", -1, -1)
+    ];
+
+    var A_18 = [
+        new Among("dir", -1, -1),
+        new Among("tir", -1, -1),
+        new Among("dur", -1, -1),
+        new Among("tur", -1, -1),
+        new Among("d\u00FCr", -1, -1),
+        new Among("t\u00FCr", -1, -1),
+        new Among("d\u0131r", -1, -1),
+        new Among("t\u0131r", -1, -1)
+    ];
+
+    var A_19 = [
+        new Among("cas\u0131na", -1, -1),
+        new Among("cesine", -1, -1)
+    ];
+
+    var A_20 = [
+        new Among("di", -1, -1),
+        new Among("ti", -1, -1),
+        new Among("dik", -1, -1),
+        new Among("tik", -1, -1),
+        new Among("duk", -1, -1),
+        new Among("tuk", -1, -1),
+        new Among("d\u00FCk", -1, -1),
+        new Among("t\u00FCk", -1, -1),
+        new Among("d\u0131k", -1, -1),
+        new Among("t\u0131k", -1, -1),
+        new Among("dim", -1, -1),
+        new Among("tim", -1, -1),
+        new Among("dum", -1, -1),
+        new Among("tum", -1, -1),
+        new Among("d\u00FCm", -1, -1),
+        new Among("t\u00FCm", -1, -1),
+        new Among("d\u0131m", -1, -1),
+        new Among("t\u0131m", -1, -1),
+        new Among("din", -1, -1),
+        new Among("tin", -1, -1),
+        new Among("dun", -1, -1),
+        new Among("tun", -1, -1),
+        new Among("d\u00FCn", -1, -1),
+        new Among("t\u00FCn", -1, -1),
+        new Among("d\u0131n", -1, -1),
+        new Among("t\u0131n", -1, -1),
+        new Among("du", -1, -1),
+        new Among("tu", -1, -1),
+        new Among("d\u00FC", -1, -1),
+        new Among("t\u00FC", -1, -1),
+        new Among("d\u0131", -1, -1),
+        new Among("t\u0131", -1, -1)
+    ];
+
+    var A_21 = [
+        new Among("sa", -1, -1),
+        new Among("se", -1, -1),
+        new Among("sak", -1, -1),
+        new Among("sek", -1, -1),
+        new Among("sam", -1, -1),
+        new Among("sem", -1, -1),
+        new Among("san", -1, -1),
+        new Among("sen", -1, -1)
+    ];
+
+    var A_22 = [
+        new Among("mi\u015F", -1, -1),
+        new Among("mu\u015F", -1, -1),
+        new Among("m\u00FC\u015F", -1, -1),
+        new Among("m\u0131\u015F", -1, -1)
+    ];
+
+    var A_23 = [
+        new Among("b", -1, 1),
+        new Among("c", -1, 2),
+        new Among("d", -1, 3),
+        new Among("\u011F", -1, 4)
+    ];
+
+    var G_vowel = [17, 65, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 8, 0, 0, 0, 0, 0, 0, 1];
+
+    var G_U = [1, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 1];
+
+    var G_vowel1 = [1, 64, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+    var G_vowel2 = [17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 130];
+
+    var G_vowel3 = [1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1];
+
+    var G_vowel4 = [17];
+
+    var G_vowel5 = [65];
+
+    var G_vowel6 = [65];
+
+    function r_check_vowel_harmony(env, context) {
+        var v_1 = env.limit - env.cursor;
+        golab0: while (true) {
+            var v_2 = env.limit - env.cursor;
+            lab1: while (true) {
+                if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                    break lab1;
+                }
+                env.cursor = env.limit - v_2;
+                break golab0;
+            }
+            env.cursor = env.limit - v_2;
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+        }
+        lab2: while (true) {
+            var v_3 = env.limit - env.cursor;
+            lab3: while (true) {
+                if (!env.eq_s_b("a")) {
+                    break lab3;
+                }
+                golab4: while (true) {
+                    var v_4 = env.limit - env.cursor;
+                    lab5: while (true) {
+                        if (!env.in_grouping_b(G_vowel1, 97, 305)) {
+                            break lab5;
+                        }
+                        env.cursor = env.limit - v_4;
+                        break golab4;
+                    }
+                    env.cursor = env.limit - v_4;
+                    if (env.cursor <= env.limit_backward) {
+                        break lab3;
+                    }
+                    env.previous_char();
+                }
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            lab6: while (true) {
+                if (!env.eq_s_b("e")) {
+                    break lab6;
+                }
+                golab7: while (true) {
+                    var v_5 = env.limit - env.cursor;
+                    lab8: while (true) {
+                        if (!env.in_grouping_b(G_vowel2, 101, 252)) {
+                            break lab8;
+                        }
+                        env.cursor = env.limit - v_5;
+                        break golab7;
+                    }
+                    env.cursor = env.limit - v_5;
+                    if (env.cursor <= env.limit_backward) {
+                        break lab6;
+                    }
+                    env.previous_char();
+                }
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            lab9: while (true) {
+                if (!env.eq_s_b("\u0131")) {
+                    break lab9;
+                }
+                golab10: while (true) {
+                    var v_6 = env.limit - env.cursor;
+                    lab11: while (true) {
+                        if (!env.in_grouping_b(G_vowel3, 97, 305)) {
+                            break lab11;
+                        }
+                        env.cursor = env.limit - v_6;
+                        break golab10;
+                    }
+                    env.cursor = env.limit - v_6;
+                    if (env.cursor <= env.limit_backward) {
+                        break lab9;
+                    }
+                    env.previous_char();
+                }
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            lab12: while (true) {
+                if (!env.eq_s_b("i")) {
+                    break lab12;
+                }
+                golab13: while (true) {
+                    var v_7 = env.limit - env.cursor;
+                    lab14: while (true) {
+                        if (!env.in_grouping_b(G_vowel4, 101, 105)) {
+                            break lab14;
+                        }
+                        env.cursor = env.limit - v_7;
+                        break golab13;
+                    }
+                    env.cursor = env.limit - v_7;
+                    if (env.cursor <= env.limit_backward) {
+                        break lab12;
+                    }
+                    env.previous_char();
+                }
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            lab15: while (true) {
+                if (!env.eq_s_b("o")) {
+                    break lab15;
+                }
+                golab16: while (true) {
+                    var v_8 = env.limit - env.cursor;
+                    lab17: while (true) {
+                        if (!env.in_grouping_b(G_vowel5, 111, 117)) {
+                            break lab17;
+                        }
+                        env.cursor = env.limit - v_8;
+                        break golab16;
+                    }
+                    env.cursor = env.limit - v_8;
+                    if (env.cursor <= env.limit_backward) {
+                        break lab15;
+                    }
+                    env.previous_char();
+                }
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            lab18: while (true) {
+                if (!env.eq_s_b("\u00F6")) {
+                    break lab18;
+                }
+                golab19: while (true) {
+                    var v_9 = env.limit - env.cursor;
+                    lab20: while (true) {
+                        if (!env.in_grouping_b(G_vowel6, 246, 252)) {
+                            break lab20;
+                        }
+                        env.cursor = env.limit - v_9;
+                        break golab19;
+                    }
+                    env.cursor = env.limit - v_9;
+                    if (env.cursor <= env.limit_backward) {
+                        break lab18;
+                    }
+                    env.previous_char();
+                }
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            lab21: while (true) {
+                if (!env.eq_s_b("u")) {
+                    break lab21;
+                }
+                golab22: while (true) {
+                    var v_10 = env.limit - env.cursor;
+                    lab23: while (true) {
+                        if (!env.in_grouping_b(G_vowel5, 111, 117)) {
+                            break lab23;
+                        }
+                        env.cursor = env.limit - v_10;
+                        break golab22;
+                    }
+                    env.cursor = env.limit - v_10;
+                    if (env.cursor <= env.limit_backward) {
+                        break lab21;
+                    }
+                    env.previous_char();
+                }
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            if (!env.eq_s_b("\u00FC")) {
+                return false;
+            }
+            golab24: while (true) {
+                var v_11 = env.limit - env.cursor;
+                lab25: while (true) {
+                    if (!env.in_grouping_b(G_vowel6, 246, 252)) {
+                        break lab25;
+                    }
+                    env.cursor = env.limit - v_11;
+                    break golab24;
+                }
+                env.cursor = env.limit - v_11;
+                if (env.cursor <= env.limit_backward) {
+                    return false;
+                }
+                env.previous_char();
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_1;
+        return true;
+    }
+    function r_mark_suffix_with_optional_n_consonant(env, context) {
+        lab0: while (true) {
+            var v_1 = env.limit - env.cursor;
+            lab1: while (true) {
+                if (!env.eq_s_b("n")) {
+                    break lab1;
+                }
+                var v_2 = env.limit - env.cursor;
+                if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                    break lab1;
+                }
+                env.cursor = env.limit - v_2;
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            var v_3 = env.limit - env.cursor;
+            lab2: while (true) {
+                var v_4 = env.limit - env.cursor;
+                if (!env.eq_s_b("n")) {
+                    break lab2;
+                }
+                env.cursor = env.limit - v_4;
+                return false;
+            }
+            env.cursor = env.limit - v_3;
+            var v_5 = env.limit - env.cursor;
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+            if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                return false;
+            }
+            env.cursor = env.limit - v_5;
+            break lab0;
+        }
+        return true;
+    }
+    function r_mark_suffix_with_optional_s_consonant(env, context) {
+        lab0: while (true) {
+            var v_1 = env.limit - env.cursor;
+            lab1: while (true) {
+                if (!env.eq_s_b("s")) {
+                    break lab1;
+                }
+                var v_2 = env.limit - env.cursor;
+                if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                    break lab1;
+                }
+                env.cursor = env.limit - v_2;
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            var v_3 = env.limit - env.cursor;
+            lab2: while (true) {
+                var v_4 = env.limit - env.cursor;
+                if (!env.eq_s_b("s")) {
+                    break lab2;
+                }
+                env.cursor = env.limit - v_4;
+                return false;
+            }
+            env.cursor = env.limit - v_3;
+            var v_5 = env.limit - env.cursor;
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+            if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                return false;
+            }
+            env.cursor = env.limit - v_5;
+            break lab0;
+        }
+        return true;
+    }
+    function r_mark_suffix_with_optional_y_consonant(env, context) {
+        lab0: while (true) {
+            var v_1 = env.limit - env.cursor;
+            lab1: while (true) {
+                if (!env.eq_s_b("y")) {
+                    break lab1;
+                }
+                var v_2 = env.limit - env.cursor;
+                if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                    break lab1;
+                }
+                env.cursor = env.limit - v_2;
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            var v_3 = env.limit - env.cursor;
+            lab2: while (true) {
+                var v_4 = env.limit - env.cursor;
+                if (!env.eq_s_b("y")) {
+                    break lab2;
+                }
+                env.cursor = env.limit - v_4;
+                return false;
+            }
+            env.cursor = env.limit - v_3;
+            var v_5 = env.limit - env.cursor;
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+            if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                return false;
+            }
+            env.cursor = env.limit - v_5;
+            break lab0;
+        }
+        return true;
+    }
+    function r_mark_suffix_with_optional_U_vowel(env, context) {
+        lab0: while (true) {
+            var v_1 = env.limit - env.cursor;
+            lab1: while (true) {
+                if (!env.in_grouping_b(G_U, 105, 305)) {
+                    break lab1;
+                }
+                var v_2 = env.limit - env.cursor;
+                if (!env.out_grouping_b(G_vowel, 97, 305)) {
+                    break lab1;
+                }
+                env.cursor = env.limit - v_2;
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            var v_3 = env.limit - env.cursor;
+            lab2: while (true) {
+                var v_4 = env.limit - env.cursor;
+                if (!env.in_grouping_b(G_U, 105, 305)) {
+                    break lab2;
+                }
+                env.cursor = env.limit - v_4;
+                return false;
+            }
+            env.cursor = env.limit - v_3;
+            var v_5 = env.limit - env.cursor;
+            if (env.cursor <= env.limit_backward) {
+                return false;
+            }
+            env.previous_char();
+            if (!env.out_grouping_b(G_vowel, 97, 305)) {
+                return false;
+            }
+            env.cursor = env.limit - v_5;
+            break lab0;
+        }
+        return true;
+    }
+    function r_mark_possessives(env, context) {
+        if (env.find_among_b(A_0, context) == 0) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_U_vowel(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_sU(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (!env.in_grouping_b(G_U, 105, 305)) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_s_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_lArI(env, context) {
+        if (env.find_among_b(A_1, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_yU(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (!env.in_grouping_b(G_U, 105, 305)) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_nU(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_2, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_nUn(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_3, context) == 0) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_n_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_yA(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_4, context) == 0) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_nA(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_5, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_DA(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_6, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_ndA(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_7, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_DAn(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_8, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_ndAn(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_9, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_ylA(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_10, context) == 0) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_ki(env, context) {
+        if (!env.eq_s_b("ki")) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_ncA(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_11, context) == 0) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_n_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_yUm(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_12, context) == 0) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_sUn(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_13, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_yUz(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_14, context) == 0) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_sUnUz(env, context) {
+        if (env.find_among_b(A_15, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_lAr(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_16, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_nUz(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_17, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_DUr(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_18, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_cAsInA(env, context) {
+        if (env.find_among_b(A_19, context) == 0) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_yDU(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_20, context) == 0) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_ysA(env, context) {
+        if (env.find_among_b(A_21, context) == 0) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_ymUs_(env, context) {
+        if (!r_check_vowel_harmony(env, context)) {
+            return false;
+        }
+        if (env.find_among_b(A_22, context) == 0) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_mark_yken(env, context) {
+        if (!env.eq_s_b("ken")) {
+            return false;
+        }
+        if (!r_mark_suffix_with_optional_y_consonant(env, context)) {
+            return false;
+        }
+        return true;
+    }
+    function r_stem_nominal_verb_suffixes(env, context) {
+        env.ket = env.cursor;
+        context.b_continue_stemming_noun_suffixes = true;
+        lab0: while (true) {
+            var v_1 = env.limit - env.cursor;
+            lab1: while (true) {
+                lab2: while (true) {
+                    var v_2 = env.limit - env.cursor;
+                    lab3: while (true) {
+                        if (!r_mark_ymUs_(env, context)) {
+                            break lab3;
+                        }
+                        break lab2;
+                    }
+                    env.cursor = env.limit - v_2;
+                    lab4: while (true) {
+                        if (!r_mark_yDU(env, context)) {
+                            break lab4;
+                        }
+                        break lab2;
+                    }
+                    env.cursor = env.limit - v_2;
+                    lab5: while (true) {
+                        if (!r_mark_ysA(env, context)) {
+                            break lab5;
+                        }
+                        break lab2;
+                    }
+                    env.cursor = env.limit - v_2;
+                    if (!r_mark_yken(env, context)) {
+                        break lab1;
+                    }
+                    break lab2;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab6: while (true) {
+                if (!r_mark_cAsInA(env, context)) {
+                    break lab6;
+                }
+                lab7: while (true) {
+                    var v_3 = env.limit - env.cursor;
+                    lab8: while (true) {
+                        if (!r_mark_sUnUz(env, context)) {
+                            break lab8;
+                        }
+                        break lab7;
+                    }
+                    env.cursor = env.limit - v_3;
+                    lab9: while (true) {
+                        if (!r_mark_lAr(env, context)) {
+                            break lab9;
+                        }
+                        break lab7;
+                    }
+                    env.cursor = env.limit - v_3;
+                    lab10: while (true) {
+                        if (!r_mark_yUm(env, context)) {
+                            break lab10;
+                        }
+                        break lab7;
+                    }
+                    env.cursor = env.limit - v_3;
+                    lab11: while (true) {
+                        if (!r_mark_sUn(env, context)) {
+                            break lab11;
+                        }
+                        break lab7;
+                    }
+                    env.cursor = env.limit - v_3;
+                    lab12: while (true) {
+                        if (!r_mark_yUz(env, context)) {
+                            break lab12;
+                        }
+                        break lab7;
+                    }
+                    env.cursor = env.limit - v_3;
+                    break lab7;
+                }
+                if (!r_mark_ymUs_(env, context)) {
+                    break lab6;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab13: while (true) {
+                if (!r_mark_lAr(env, context)) {
+                    break lab13;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_4 = env.limit - env.cursor;
+                lab14: while (true) {
+                    env.ket = env.cursor;
+                    lab15: while (true) {
+                        var v_5 = env.limit - env.cursor;
+                        lab16: while (true) {
+                            if (!r_mark_DUr(env, context)) {
+                                break lab16;
+                            }
+                            break lab15;
+                        }
+                        env.cursor = env.limit - v_5;
+                        lab17: while (true) {
+                            if (!r_mark_yDU(env, context)) {
+                                break lab17;
+                            }
+                            break lab15;
+                        }
+                        env.cursor = env.limit - v_5;
+                        lab18: while (true) {
+                            if (!r_mark_ysA(env, context)) {
+                                break lab18;
+                            }
+                            break lab15;
+                        }
+                        env.cursor = env.limit - v_5;
+                        if (!r_mark_ymUs_(env, context)) {
+                            env.cursor = env.limit - v_4;
+                            break lab14;
+                        }
+                        break lab15;
+                    }
+                    break lab14;
+                }
+                context.b_continue_stemming_noun_suffixes = false;
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab19: while (true) {
+                if (!r_mark_nUz(env, context)) {
+                    break lab19;
+                }
+                lab20: while (true) {
+                    var v_6 = env.limit - env.cursor;
+                    lab21: while (true) {
+                        if (!r_mark_yDU(env, context)) {
+                            break lab21;
+                        }
+                        break lab20;
+                    }
+                    env.cursor = env.limit - v_6;
+                    if (!r_mark_ysA(env, context)) {
+                        break lab19;
+                    }
+                    break lab20;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab22: while (true) {
+                lab23: while (true) {
+                    var v_7 = env.limit - env.cursor;
+                    lab24: while (true) {
+                        if (!r_mark_sUnUz(env, context)) {
+                            break lab24;
+                        }
+                        break lab23;
+                    }
+                    env.cursor = env.limit - v_7;
+                    lab25: while (true) {
+                        if (!r_mark_yUz(env, context)) {
+                            break lab25;
+                        }
+                        break lab23;
+                    }
+                    env.cursor = env.limit - v_7;
+                    lab26: while (true) {
+                        if (!r_mark_sUn(env, context)) {
+                            break lab26;
+                        }
+                        break lab23;
+                    }
+                    env.cursor = env.limit - v_7;
+                    if (!r_mark_yUm(env, context)) {
+                        break lab22;
+                    }
+                    break lab23;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_8 = env.limit - env.cursor;
+                lab27: while (true) {
+                    env.ket = env.cursor;
+                    if (!r_mark_ymUs_(env, context)) {
+                        env.cursor = env.limit - v_8;
+                        break lab27;
+                    }
+                    break lab27;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            if (!r_mark_DUr(env, context)) {
+                return false;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_9 = env.limit - env.cursor;
+            lab28: while (true) {
+                env.ket = env.cursor;
+                lab29: while (true) {
+                    var v_10 = env.limit - env.cursor;
+                    lab30: while (true) {
+                        if (!r_mark_sUnUz(env, context)) {
+                            break lab30;
+                        }
+                        break lab29;
+                    }
+                    env.cursor = env.limit - v_10;
+                    lab31: while (true) {
+                        if (!r_mark_lAr(env, context)) {
+                            break lab31;
+                        }
+                        break lab29;
+                    }
+                    env.cursor = env.limit - v_10;
+                    lab32: while (true) {
+                        if (!r_mark_yUm(env, context)) {
+                            break lab32;
+                        }
+                        break lab29;
+                    }
+                    env.cursor = env.limit - v_10;
+                    lab33: while (true) {
+                        if (!r_mark_sUn(env, context)) {
+                            break lab33;
+                        }
+                        break lab29;
+                    }
+                    env.cursor = env.limit - v_10;
+                    lab34: while (true) {
+                        if (!r_mark_yUz(env, context)) {
+                            break lab34;
+                        }
+                        break lab29;
+                    }
+                    env.cursor = env.limit - v_10;
+                    break lab29;
+                }
+                if (!r_mark_ymUs_(env, context)) {
+                    env.cursor = env.limit - v_9;
+                    break lab28;
+                }
+                break lab28;
+            }
+            break lab0;
+        }
+        env.bra = env.cursor;
+        if (!env.slice_del()) {
+            return false;
+        }
+        return true;
+    }
+    function r_stem_suffix_chain_before_ki(env, context) {
+        env.ket = env.cursor;
+        if (!r_mark_ki(env, context)) {
+            return false;
+        }
+        lab0: while (true) {
+            var v_1 = env.limit - env.cursor;
+            lab1: while (true) {
+                if (!r_mark_DA(env, context)) {
+                    break lab1;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_2 = env.limit - env.cursor;
+                lab2: while (true) {
+                    env.ket = env.cursor;
+                    lab3: while (true) {
+                        var v_3 = env.limit - env.cursor;
+                        lab4: while (true) {
+                            if (!r_mark_lAr(env, context)) {
+                                break lab4;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            var v_4 = env.limit - env.cursor;
+                            lab5: while (true) {
+                                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                    env.cursor = env.limit - v_4;
+                                    break lab5;
+                                }
+                                break lab5;
+                            }
+                            break lab3;
+                        }
+                        env.cursor = env.limit - v_3;
+                        if (!r_mark_possessives(env, context)) {
+                            env.cursor = env.limit - v_2;
+                            break lab2;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        var v_5 = env.limit - env.cursor;
+                        lab6: while (true) {
+                            env.ket = env.cursor;
+                            if (!r_mark_lAr(env, context)) {
+                                env.cursor = env.limit - v_5;
+                                break lab6;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                env.cursor = env.limit - v_5;
+                                break lab6;
+                            }
+                            break lab6;
+                        }
+                        break lab3;
+                    }
+                    break lab2;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab7: while (true) {
+                if (!r_mark_nUn(env, context)) {
+                    break lab7;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_6 = env.limit - env.cursor;
+                lab8: while (true) {
+                    env.ket = env.cursor;
+                    lab9: while (true) {
+                        var v_7 = env.limit - env.cursor;
+                        lab10: while (true) {
+                            if (!r_mark_lArI(env, context)) {
+                                break lab10;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            break lab9;
+                        }
+                        env.cursor = env.limit - v_7;
+                        lab11: while (true) {
+                            env.ket = env.cursor;
+                            lab12: while (true) {
+                                var v_8 = env.limit - env.cursor;
+                                lab13: while (true) {
+                                    if (!r_mark_possessives(env, context)) {
+                                        break lab13;
+                                    }
+                                    break lab12;
+                                }
+                                env.cursor = env.limit - v_8;
+                                if (!r_mark_sU(env, context)) {
+                                    break lab11;
+                                }
+                                break lab12;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            var v_9 = env.limit - env.cursor;
+                            lab14: while (true) {
+                                env.ket = env.cursor;
+                                if (!r_mark_lAr(env, context)) {
+                                    env.cursor = env.limit - v_9;
+                                    break lab14;
+                                }
+                                env.bra = env.cursor;
+                                if (!env.slice_del()) {
+                                    return false;
+                                }
+                                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                    env.cursor = env.limit - v_9;
+                                    break lab14;
+                                }
+                                break lab14;
+                            }
+                            break lab9;
+                        }
+                        env.cursor = env.limit - v_7;
+                        if (!r_stem_suffix_chain_before_ki(env, context)) {
+                            env.cursor = env.limit - v_6;
+                            break lab8;
+                        }
+                        break lab9;
+                    }
+                    break lab8;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            if (!r_mark_ndA(env, context)) {
+                return false;
+            }
+            lab15: while (true) {
+                var v_10 = env.limit - env.cursor;
+                lab16: while (true) {
+                    if (!r_mark_lArI(env, context)) {
+                        break lab16;
+                    }
+                    env.bra = env.cursor;
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    break lab15;
+                }
+                env.cursor = env.limit - v_10;
+                lab17: while (true) {
+                    if (!r_mark_sU(env, context)) {
+                        break lab17;
+                    }
+                    env.bra = env.cursor;
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    var v_11 = env.limit - env.cursor;
+                    lab18: while (true) {
+                        env.ket = env.cursor;
+                        if (!r_mark_lAr(env, context)) {
+                            env.cursor = env.limit - v_11;
+                            break lab18;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        if (!r_stem_suffix_chain_before_ki(env, context)) {
+                            env.cursor = env.limit - v_11;
+                            break lab18;
+                        }
+                        break lab18;
+                    }
+                    break lab15;
+                }
+                env.cursor = env.limit - v_10;
+                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                    return false;
+                }
+                break lab15;
+            }
+            break lab0;
+        }
+        return true;
+    }
+    function r_stem_noun_suffixes(env, context) {
+        lab0: while (true) {
+            var v_1 = env.limit - env.cursor;
+            lab1: while (true) {
+                env.ket = env.cursor;
+                if (!r_mark_lAr(env, context)) {
+                    break lab1;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_2 = env.limit - env.cursor;
+                lab2: while (true) {
+                    if (!r_stem_suffix_chain_before_ki(env, context)) {
+                        env.cursor = env.limit - v_2;
+                        break lab2;
+                    }
+                    break lab2;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab3: while (true) {
+                env.ket = env.cursor;
+                if (!r_mark_ncA(env, context)) {
+                    break lab3;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_3 = env.limit - env.cursor;
+                lab4: while (true) {
+                    lab5: while (true) {
+                        var v_4 = env.limit - env.cursor;
+                        lab6: while (true) {
+                            env.ket = env.cursor;
+                            if (!r_mark_lArI(env, context)) {
+                                break lab6;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            break lab5;
+                        }
+                        env.cursor = env.limit - v_4;
+                        lab7: while (true) {
+                            env.ket = env.cursor;
+                            lab8: while (true) {
+                                var v_5 = env.limit - env.cursor;
+                                lab9: while (true) {
+                                    if (!r_mark_possessives(env, context)) {
+                                        break lab9;
+                                    }
+                                    break lab8;
+                                }
+                                env.cursor = env.limit - v_5;
+                                if (!r_mark_sU(env, context)) {
+                                    break lab7;
+                                }
+                                break lab8;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            var v_6 = env.limit - env.cursor;
+                            lab10: while (true) {
+                                env.ket = env.cursor;
+                                if (!r_mark_lAr(env, context)) {
+                                    env.cursor = env.limit - v_6;
+                                    break lab10;
+                                }
+                                env.bra = env.cursor;
+                                if (!env.slice_del()) {
+                                    return false;
+                                }
+                                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                    env.cursor = env.limit - v_6;
+                                    break lab10;
+                                }
+                                break lab10;
+                            }
+                            break lab5;
+                        }
+                        env.cursor = env.limit - v_4;
+                        env.ket = env.cursor;
+                        if (!r_mark_lAr(env, context)) {
+                            env.cursor = env.limit - v_3;
+                            break lab4;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        if (!r_stem_suffix_chain_before_ki(env, context)) {
+                            env.cursor = env.limit - v_3;
+                            break lab4;
+                        }
+                        break lab5;
+                    }
+                    break lab4;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab11: while (true) {
+                env.ket = env.cursor;
+                lab12: while (true) {
+                    var v_7 = env.limit - env.cursor;
+                    lab13: while (true) {
+                        if (!r_mark_ndA(env, context)) {
+                            break lab13;
+                        }
+                        break lab12;
+                    }
+                    env.cursor = env.limit - v_7;
+                    if (!r_mark_nA(env, context)) {
+                        break lab11;
+                    }
+                    break lab12;
+                }
+                lab14: while (true) {
+                    var v_8 = env.limit - env.cursor;
+                    lab15: while (true) {
+                        if (!r_mark_lArI(env, context)) {
+                            break lab15;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        break lab14;
+                    }
+                    env.cursor = env.limit - v_8;
+                    lab16: while (true) {
+                        if (!r_mark_sU(env, context)) {
+                            break lab16;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        var v_9 = env.limit - env.cursor;
+                        lab17: while (true) {
+                            env.ket = env.cursor;
+                            if (!r_mark_lAr(env, context)) {
+                                env.cursor = env.limit - v_9;
+                                break lab17;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                env.cursor = env.limit - v_9;
+                                break lab17;
+                            }
+                            break lab17;
+                        }
+                        break lab14;
+                    }
+                    env.cursor = env.limit - v_8;
+                    if (!r_stem_suffix_chain_before_ki(env, context)) {
+                        break lab11;
+                    }
+                    break lab14;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab18: while (true) {
+                env.ket = env.cursor;
+                lab19: while (true) {
+                    var v_10 = env.limit - env.cursor;
+                    lab20: while (true) {
+                        if (!r_mark_ndAn(env, context)) {
+                            break lab20;
+                        }
+                        break lab19;
+                    }
+                    env.cursor = env.limit - v_10;
+                    if (!r_mark_nU(env, context)) {
+                        break lab18;
+                    }
+                    break lab19;
+                }
+                lab21: while (true) {
+                    var v_11 = env.limit - env.cursor;
+                    lab22: while (true) {
+                        if (!r_mark_sU(env, context)) {
+                            break lab22;
+                        }
+                        env.bra = env.cursor;
+                        if (!env.slice_del()) {
+                            return false;
+                        }
+                        var v_12 = env.limit - env.cursor;
+                        lab23: while (true) {
+                            env.ket = env.cursor;
+                            if (!r_mark_lAr(env, context)) {
+                                env.cursor = env.limit - v_12;
+                                break lab23;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                env.cursor = env.limit - v_12;
+                                break lab23;
+                            }
+                            break lab23;
+                        }
+                        break lab21;
+                    }
+                    env.cursor = env.limit - v_11;
+                    if (!r_mark_lArI(env, context)) {
+                        break lab18;
+                    }
+                    break lab21;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab24: while (true) {
+                env.ket = env.cursor;
+                if (!r_mark_DAn(env, context)) {
+                    break lab24;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_13 = env.limit - env.cursor;
+                lab25: while (true) {
+                    env.ket = env.cursor;
+                    lab26: while (true) {
+                        var v_14 = env.limit - env.cursor;
+                        lab27: while (true) {
+                            if (!r_mark_possessives(env, context)) {
+                                break lab27;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            var v_15 = env.limit - env.cursor;
+                            lab28: while (true) {
+                                env.ket = env.cursor;
+                                if (!r_mark_lAr(env, context)) {
+                                    env.cursor = env.limit - v_15;
+                                    break lab28;
+                                }
+                                env.bra = env.cursor;
+                                if (!env.slice_del()) {
+                                    return false;
+                                }
+                                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                    env.cursor = env.limit - v_15;
+                                    break lab28;
+                                }
+                                break lab28;
+                            }
+                            break lab26;
+                        }
+                        env.cursor = env.limit - v_14;
+                        lab29: while (true) {
+                            if (!r_mark_lAr(env, context)) {
+                                break lab29;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            var v_16 = env.limit - env.cursor;
+                            lab30: while (true) {
+                                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                    env.cursor = env.limit - v_16;
+                                    break lab30;
+                                }
+                                break lab30;
+                            }
+                            break lab26;
+                        }
+                        env.cursor = env.limit - v_14;
+                        if (!r_stem_suffix_chain_before_ki(env, context)) {
+                            env.cursor = env.limit - v_13;
+                            break lab25;
+                        }
+                        break lab26;
+                    }
+                    break lab25;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab31: while (true) {
+                env.ket = env.cursor;
+                lab32: while (true) {
+                    var v_17 = env.limit - env.cursor;
+                    lab33: while (true) {
+                        if (!r_mark_nUn(env, context)) {
+                            break lab33;
+                        }
+                        break lab32;
+                    }
+                    env.cursor = env.limit - v_17;
+                    if (!r_mark_ylA(env, context)) {
+                        break lab31;
+                    }
+                    break lab32;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_18 = env.limit - env.cursor;
+                lab34: while (true) {
+                    lab35: while (true) {
+                        var v_19 = env.limit - env.cursor;
+                        lab36: while (true) {
+                            env.ket = env.cursor;
+                            if (!r_mark_lAr(env, context)) {
+                                break lab36;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                break lab36;
+                            }
+                            break lab35;
+                        }
+                        env.cursor = env.limit - v_19;
+                        lab37: while (true) {
+                            env.ket = env.cursor;
+                            lab38: while (true) {
+                                var v_20 = env.limit - env.cursor;
+                                lab39: while (true) {
+                                    if (!r_mark_possessives(env, context)) {
+                                        break lab39;
+                                    }
+                                    break lab38;
+                                }
+                                env.cursor = env.limit - v_20;
+                                if (!r_mark_sU(env, context)) {
+                                    break lab37;
+                                }
+                                break lab38;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            var v_21 = env.limit - env.cursor;
+                            lab40: while (true) {
+                                env.ket = env.cursor;
+                                if (!r_mark_lAr(env, context)) {
+                                    env.cursor = env.limit - v_21;
+                                    break lab40;
+                                }
+                                env.bra = env.cursor;
+                                if (!env.slice_del()) {
+                                    return false;
+                                }
+                                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                                    env.cursor = env.limit - v_21;
+                                    break lab40;
+                                }
+                                break lab40;
+                            }
+                            break lab35;
+                        }
+                        env.cursor = env.limit - v_19;
+                        if (!r_stem_suffix_chain_before_ki(env, context)) {
+                            env.cursor = env.limit - v_18;
+                            break lab34;
+                        }
+                        break lab35;
+                    }
+                    break lab34;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab41: while (true) {
+                env.ket = env.cursor;
+                if (!r_mark_lArI(env, context)) {
+                    break lab41;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab42: while (true) {
+                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                    break lab42;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            lab43: while (true) {
+                env.ket = env.cursor;
+                lab44: while (true) {
+                    var v_22 = env.limit - env.cursor;
+                    lab45: while (true) {
+                        if (!r_mark_DA(env, context)) {
+                            break lab45;
+                        }
+                        break lab44;
+                    }
+                    env.cursor = env.limit - v_22;
+                    lab46: while (true) {
+                        if (!r_mark_yU(env, context)) {
+                            break lab46;
+                        }
+                        break lab44;
+                    }
+                    env.cursor = env.limit - v_22;
+                    if (!r_mark_yA(env, context)) {
+                        break lab43;
+                    }
+                    break lab44;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                var v_23 = env.limit - env.cursor;
+                lab47: while (true) {
+                    env.ket = env.cursor;
+                    lab48: while (true) {
+                        var v_24 = env.limit - env.cursor;
+                        lab49: while (true) {
+                            if (!r_mark_possessives(env, context)) {
+                                break lab49;
+                            }
+                            env.bra = env.cursor;
+                            if (!env.slice_del()) {
+                                return false;
+                            }
+                            var v_25 = env.limit - env.cursor;
+                            lab50: while (true) {
+                                env.ket = env.cursor;
+                                if (!r_mark_lAr(env, context)) {
+                                    env.cursor = env.limit - v_25;
+                                    break lab50;
+                                }
+                                break lab50;
+                            }
+                            break lab48;
+                        }
+                        env.cursor = env.limit - v_24;
+                        if (!r_mark_lAr(env, context)) {
+                            env.cursor = env.limit - v_23;
+                            break lab47;
+                        }
+                        break lab48;
+                    }
+                    env.bra = env.cursor;
+                    if (!env.slice_del()) {
+                        return false;
+                    }
+                    env.ket = env.cursor;
+                    if (!r_stem_suffix_chain_before_ki(env, context)) {
+                        env.cursor = env.limit - v_23;
+                        break lab47;
+                    }
+                    break lab47;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_1;
+            env.ket = env.cursor;
+            lab51: while (true) {
+                var v_26 = env.limit - env.cursor;
+                lab52: while (true) {
+                    if (!r_mark_possessives(env, context)) {
+                        break lab52;
+                    }
+                    break lab51;
+                }
+                env.cursor = env.limit - v_26;
+                if (!r_mark_sU(env, context)) {
+                    return false;
+                }
+                break lab51;
+            }
+            env.bra = env.cursor;
+            if (!env.slice_del()) {
+                return false;
+            }
+            var v_27 = env.limit - env.cursor;
+            lab53: while (true) {
+                env.ket = env.cursor;
+                if (!r_mark_lAr(env, context)) {
+                    env.cursor = env.limit - v_27;
+                    break lab53;
+                }
+                env.bra = env.cursor;
+                if (!env.slice_del()) {
+                    return false;
+                }
+                if (!r_stem_suffix_chain_before_ki(env, context)) {
+                    env.cursor = env.limit - v_27;
+                    break lab53;
+                }
+                break lab53;
+            }
+            break lab0;
+        }
+        return true;
+    }
+    function r_post_process_last_consonants(env, context) {
+        var among_var;
+        env.ket = env.cursor;
+        among_var = env.find_among_b(A_23, context);
+        if (among_var == 0) {
+            return false;
+        }
+        env.bra = env.cursor;
+        if (among_var == 0) {
+            return false;
+        } else if (among_var == 1) {
+            if (!env.slice_from("p")) {
+                return false;
+            }
+        } else if (among_var == 2) {
+            if (!env.slice_from("\u00E7")) {
+                return false;
+            }
+        } else if (among_var == 3) {
+            if (!env.slice_from("t")) {
+                return false;
+            }
+        } else if (among_var == 4) {
+            if (!env.slice_from("k")) {
+                return false;
+            }
+        }
+        return true;
+    }
+    function r_append_U_to_stems_ending_with_d_or_g(env, context) {
+        var v_1 = env.limit - env.cursor;
+        lab0: while (true) {
+            var v_2 = env.limit - env.cursor;
+            lab1: while (true) {
+                if (!env.eq_s_b("d")) {
+                    break lab1;
+                }
+                break lab0;
+            }
+            env.cursor = env.limit - v_2;
+            if (!env.eq_s_b("g")) {
+                return false;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        lab2: while (true) {
+            var v_3 = env.limit - env.cursor;
+            lab3: while (true) {
+                var v_4 = env.limit - env.cursor;
+                golab4: while (true) {
+                    var v_5 = env.limit - env.cursor;
+                    lab5: while (true) {
+                        if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                            break lab5;
+                        }
+                        env.cursor = env.limit - v_5;
+                        break golab4;
+                    }
+                    env.cursor = env.limit - v_5;
+                    if (env.cursor <= env.limit_backward) {
+                        break lab3;
+                    }
+                    env.previous_char();
+                }
+                lab6: while (true) {
+                    var v_6 = env.limit - env.cursor;
+                    lab7: while (true) {
+                        if (!env.eq_s_b("a")) {
+                            break lab7;
+                        }
+                        break lab6;
+                    }
+                    env.cursor = env.limit - v_6;
+                    if (!env.eq_s_b("\u0131")) {
+                        break lab3;
+                    }
+                    break lab6;
+                }
+                env.cursor = env.limit - v_4;
+                var c = env.cursor;
+                var bra = env.cursor, ket = env.cursor;
+                env.insert(bra, ket, "\u0131");
+                env.cursor = c;
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            lab8: while (true) {
+                var v_7 = env.limit - env.cursor;
+                golab9: while (true) {
+                    var v_8 = env.limit - env.cursor;
+                    lab10: while (true) {
+                        if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                            break lab10;
+                        }
+                        env.cursor = env.limit - v_8;
+                        break golab9;
+                    }
+                    env.cursor = env.limit - v_8;
+                    if (env.cursor <= env.limit_backward) {
+                        break lab8;
+                    }
+                    env.previous_char();
+                }
+                lab11: while (true) {
+                    var v_9 = env.limit - env.cursor;
+                    lab12: while (true) {
+                        if (!env.eq_s_b("e")) {
+                            break lab12;
+                        }
+                        break lab11;
+                    }
+                    env.cursor = env.limit - v_9;
+                    if (!env.eq_s_b("i")) {
+                        break lab8;
+                    }
+                    break lab11;
+                }
+                env.cursor = env.limit - v_7;
+                var c = env.cursor;
+                var bra = env.cursor, ket = env.cursor;
+                env.insert(bra, ket, "i");
+                env.cursor = c;
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            lab13: while (true) {
+                var v_10 = env.limit - env.cursor;
+                golab14: while (true) {
+                    var v_11 = env.limit - env.cursor;
+                    lab15: while (true) {
+                        if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                            break lab15;
+                        }
+                        env.cursor = env.limit - v_11;
+                        break golab14;
+                    }
+                    env.cursor = env.limit - v_11;
+                    if (env.cursor <= env.limit_backward) {
+                        break lab13;
+                    }
+                    env.previous_char();
+                }
+                lab16: while (true) {
+                    var v_12 = env.limit - env.cursor;
+                    lab17: while (true) {
+                        if (!env.eq_s_b("o")) {
+                            break lab17;
+                        }
+                        break lab16;
+                    }
+                    env.cursor = env.limit - v_12;
+                    if (!env.eq_s_b("u")) {
+                        break lab13;
+                    }
+                    break lab16;
+                }
+                env.cursor = env.limit - v_10;
+                var c = env.cursor;
+                var bra = env.cursor, ket = env.cursor;
+                env.insert(bra, ket, "u");
+                env.cursor = c;
+                break lab2;
+            }
+            env.cursor = env.limit - v_3;
+            var v_13 = env.limit - env.cursor;
+            golab18: while (true) {
+                var v_14 = env.limit - env.cursor;
+                lab19: while (true) {
+                    if (!env.in_grouping_b(G_vowel, 97, 305)) {
+                        break lab19;
+                    }
+                    env.cursor = env.limit - v_14;
+                    break golab18;
+                }
+                env.cursor = env.limit - v_14;
+                if (env.cursor <= env.limit_backward) {
+                    return false;
+                }
+                env.previous_char();
+            }
+            lab20: while (true) {
+                var v_15 = env.limit - env.cursor;
+                lab21: while (true) {
+                    if (!env.eq_s_b("\u00F6")) {
+                        break lab21;
+                    }
+                    break lab20;
+                }
+                env.cursor = env.limit - v_15;
+                if (!env.eq_s_b("\u00FC")) {
+                    return false;
+                }
+                break lab20;
+            }
+            env.cursor = env.limit - v_13;
+            var c = env.cursor;
+            var bra = env.cursor, ket = env.cursor;
+            env.insert(bra, ket, "\u00FC");
+            env.cursor = c;
+            break lab2;
+        }
+        return true;
+    }
+    function r_more_than_one_syllable_word(env, context) {
+        var v_1 = env.cursor;
+        var v_2 = 2;
+        replab0: while (true) {
+            var v_3 = env.cursor;
+            lab1: for (var lab1_i = 0; lab1_i < 1; lab1_i++) {
+                golab2: while (true) {
+                    lab3: while (true) {
+                        if (!env.in_grouping(G_vowel, 97, 305)) {
+                            break lab3;
+                        }
+                        break golab2;
+                    }
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                v_2 -= 1;
+                continue replab0;
+            }
+            env.cursor = v_3;
+            break replab0;
+        }
+        if (v_2 > 0) {
+            return false;
+        }
+        env.cursor = v_1;
+        return true;
+    }
+    function r_is_reserved_word(env, context) {
+        lab0: while (true) {
+            var v_1 = env.cursor;
+            lab1: while (true) {
+                var v_2 = env.cursor;
+                golab2: while (true) {
+                    lab3: while (true) {
+                        if (!env.eq_s("ad")) {
+                            break lab3;
+                        }
+                        break golab2;
+                    }
+                    if (env.cursor >= env.limit) {
+                        break lab1;
+                    }
+                    env.next_char();
+                }
+                context.i_strlen = 2;
+                if (!(context.i_strlen == env.limit)) {
+                    break lab1;
+                }
+                env.cursor = v_2;
+                break lab0;
+            }
+            env.cursor = v_1;
+            var v_4 = env.cursor;
+            golab4: while (true) {
+                lab5: while (true) {
+                    if (!env.eq_s("soyad")) {
+                        break lab5;
+                    }
+                    break golab4;
+                }
+                if (env.cursor >= env.limit) {
+                    return false;
+                }
+                env.next_char();
+            }
+            context.i_strlen = 5;
+            if (!(context.i_strlen == env.limit)) {
+                return false;
+            }
+            env.cursor = v_4;
+            break lab0;
+        }
+        return true;
+    }
+    function r_postlude(env, context) {
+        var v_1 = env.cursor;
+        lab0: while (true) {
+            if (!r_is_reserved_word(env, context)) {
+                break lab0;
+            }
+            return false;
+        }
+        env.cursor = v_1;
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_2 = env.limit - env.cursor;
+        lab1: while (true) {
+            if (!r_append_U_to_stems_ending_with_d_or_g(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        var v_3 = env.limit - env.cursor;
+        lab2: while (true) {
+            if (!r_post_process_last_consonants(env, context)) {
+                break lab2;
+            }
+            break lab2;
+        }
+        env.cursor = env.limit - v_3;
+        env.cursor = env.limit_backward;
+        return true;
+    }
+    function stem(env) {
+        var context = {
+            b_continue_stemming_noun_suffixes: false,
+            i_strlen: 0,
+        };
+        if (!r_more_than_one_syllable_word(env, context)) {
+            return false;
+        }
+        env.limit_backward = env.cursor;
+        env.cursor = env.limit;
+        var v_1 = env.limit - env.cursor;
+        lab0: while (true) {
+            if (!r_stem_nominal_verb_suffixes(env, context)) {
+                break lab0;
+            }
+            break lab0;
+        }
+        env.cursor = env.limit - v_1;
+        if (!context.b_continue_stemming_noun_suffixes) {
+            return false;
+        }
+        var v_2 = env.limit - env.cursor;
+        lab1: while (true) {
+            if (!r_stem_noun_suffixes(env, context)) {
+                break lab1;
+            }
+            break lab1;
+        }
+        env.cursor = env.limit - v_2;
+        env.cursor = env.limit_backward;
+        if (!r_postlude(env, context)) {
+            return false;
+        }
+        return true;
+    }
+
+    elasticlunr.stemmerSupport.registerLanguage("tr", {
+        wordCharacters: "A-Za-z\\xAA\\xBA\\xC0-\\xD6\\xD8-\\xF6\\xF8-\\u02B8\\u02E0-\\u02E4\\u1D00-\\u1D25\\u1D2C-\\u1D5C\\u1D62-\\u1D65\\u1D6B-\\u1D77\\u1D79-\\u1DBE\\u1E00-\\u1EFF\\u2071\\u207F\\u2090-\\u209C\\u212A\\u212B\\u2132\\u214E\\u2160-\\u2188\\u2C60-\\u2C7F\\uA722-\\uA787\\uA78B-\\uA7AD\\uA7B0-\\uA7B7\\uA7F7-\\uA7FF\\uAB30-\\uAB5A\\uAB5C-\\uAB64\\uFB00-\\uFB06\\uFF21-\\uFF3A\\uFF41-\\uFF5A",
+        stopWords: [
+            "", "acaba", "altm\u0131\u015F", "alt\u0131", "ama", "ancak", "arada",
+            "asl\u0131nda", "ayr\u0131ca", "bana", "baz\u0131", "belki", "ben",
+            "benden", "beni", "benim", "beri", "be\u015F", "bile", "bin", "bir",
+            "biri", "birka\u00E7", "birkez", "bir\u00E7ok", "bir\u015Fey",
+            "bir\u015Feyi", "biz", "bizden", "bize", "bizi", "bizim", "bu", "buna",
+            "bunda", "bundan", "bunlar", "bunlar\u0131", "bunlar\u0131n", "bunu",
+            "bunun", "burada", "b\u00F6yle", "b\u00F6ylece", "da", "daha", "dahi",
+            "de", "defa", "de\u011Fil", "diye", "di\u011Fer", "doksan", "dokuz",
+            "dolay\u0131", "dolay\u0131s\u0131yla", "d\u00F6rt", "edecek", "eden",
+            "ederek", "edilecek", "ediliyor", "edilmesi", "ediyor", "elli", "en",
+            "etmesi", "etti", "etti\u011Fi", "etti\u011Fini", "e\u011Fer", "gibi",
+            "g\u00F6re", "halen", "hangi", "hatta", "hem", "hen\u00FCz", "hep",
+            "hepsi", "her", "herhangi", "herkesin", "hi\u00E7", "hi\u00E7bir",
+            "iki", "ile", "ilgili", "ise", "itibaren", "itibariyle", "i\u00E7in",
+            "i\u015Fte", "kadar", "kar\u015F\u0131n", "katrilyon", "kendi",
+            "kendilerine", "kendini", "kendisi", "kendisine", "kendisini", "kez",
+            "ki", "kim", "kimden", "kime", "kimi", "kimse", "k\u0131rk", "milyar",
+            "milyon", "mu", "m\u00FC", "m\u0131", "nas\u0131l", "ne", "neden",
+            "nedenle", "nerde", "nerede", "nereye", "niye", "ni\u00E7in", "o",
+            "olan", "olarak", "oldu", "olduklar\u0131n\u0131", "oldu\u011Fu",
+            "oldu\u011Funu", "olmad\u0131", "olmad\u0131\u011F\u0131", "olmak",
+            "olmas\u0131", "olmayan", "olmaz", "olsa", "olsun", "olup", "olur",
+            "olursa", "oluyor", "on", "ona", "ondan", "onlar", "onlardan",
+            "onlar\u0131", "onlar\u0131n", "onu", "onun", "otuz", "oysa", "pek",
+            "ra\u011Fmen", "sadece", "sanki", "sekiz", "seksen", "sen", "senden",
+            "seni", "senin", "siz", "sizden", "sizi", "sizin", "taraf\u0131ndan",
+            "trilyon", "t\u00FCm", "var", "vard\u0131", "ve", "veya", "ya", "yani",
+            "yapacak", "yapmak", "yapt\u0131", "yapt\u0131klar\u0131",
+            "yapt\u0131\u011F\u0131", "yapt\u0131\u011F\u0131n\u0131",
+            "yap\u0131lan", "yap\u0131lmas\u0131", "yap\u0131yor", "yedi", "yerine",
+            "yetmi\u015F", "yine", "yirmi", "yoksa", "y\u00FCz", "zaten",
+            "\u00E7ok", "\u00E7\u00FCnk\u00FC", "\u00F6yle", "\u00FCzere",
+            "\u00FC\u00E7", "\u015Fey", "\u015Feyden", "\u015Feyi", "\u015Feyler",
+            "\u015Fu", "\u015Funa", "\u015Funda", "\u015Fundan",
+            "\u015Funlar\u0131", "\u015Funu", "\u015F\u00F6yle"
+        ],
+        stem: stem
+    });
+})(elasticlunr);
@@ -0,0 +1,308 @@
+/*!
+ * What the stemmers in lunr.<language>.js need to run, and the pipeline
+ * functions the search index of a book which isn't in English refers to.
+ *
+ * SnowballEnv and Among follow rust-stemmers, which elasticlunr-rs stems the
+ * book with, so that search words are stemmed the same way. Strings are
+ * indexed by UTF-16 code unit rather than by byte.
+ */
+(function(elasticlunr) {
+    "use strict";
+
+    function Among(s, substring_i, result, method) {
+        this.s = s;
+        this.substring_i = substring_i;
+        this.result = result;
+        this.method = method;
+    }
+
+    function SnowballEnv(value) {
+        this.current = value;
+        this.cursor = 0;
+        this.limit = value.length;
+        this.limit_backward = 0;
+        this.bra = 0;
+        this.ket = value.length;
+    }
+
+    SnowballEnv.prototype.replace_s = function(bra, ket, s) {
+        var adjustment = s.length - (ket - bra);
+        this.current = this.current.slice(0, bra) + s + this.current.slice(ket);
+        this.limit += adjustment;
+        if (this.cursor >= ket) {
+            this.cursor += adjustment;
+        } else if (this.cursor > bra) {
+            this.cursor = bra;
+        }
+        return adjustment;
+    };
+
+    SnowballEnv.prototype.eq_s = function(s) {
+        if (this.cursor >= this.limit || !this.current.startsWith(s, this.cursor)) {
+            return false;
+        }
+        this.cursor += s.length;
+        return true;
+    };
+
+    SnowballEnv.prototype.eq_s_b = function(s) {
+        if (this.cursor - this.limit_backward < s.length ||
+            !this.current.startsWith(s, this.cursor - s.length)) {
+            return false;
+        }
+        this.cursor -= s.length;
+        return true;
+    };
+
+    SnowballEnv.prototype.slice_from = function(s) {
+        this.replace_s(this.bra, this.ket, s);
+        return true;
+    };
+
+    SnowballEnv.prototype.slice_del = function() {
+        return this.slice_from("");
+    };
+
+    SnowballEnv.prototype.slice_to = function() {
+        return this.current.slice(this.bra, this.ket);
+    };
+
+    SnowballEnv.prototype.insert = function(bra, ket, s) {
+        var adjustment = this.replace_s(bra, ket, s);
+        if (bra <= this.bra) {
+            this.bra += adjustment;
+        }
+        if (bra <= this.ket) {
+            this.ket += adjustment;
+        }
+    };
+
+    SnowballEnv.prototype.next_char = function() {
+        this.cursor += 1;
+    };
+
+    SnowballEnv.prototype.previous_char = function() {
+        this.cursor -= 1;
+    };
+
+    SnowballEnv.prototype.hop = function(delta) {
+        return this.cursor + delta;
+    };
+
+    // A grouping is a bitfield of which characters from `min` to `max` are in
+    // it, least significant bit first
+    function inGrouping(chars, ch, min, max) {
+        if (ch > max || ch < min) {
+            return false;
+        }
+        ch -= min;
+        return (chars[ch >> 3] & (1 << (ch & 7))) != 0;
+    }
+
+    SnowballEnv.prototype.in_grouping = function(chars, min, max) {
+        if (this.cursor >= this.limit ||
+            !inGrouping(chars, this.current.charCodeAt(this.cursor), min, max)) {
+            return false;
+        }
+        this.cursor += 1;
+        return true;
+    };
+
+    SnowballEnv.prototype.in_grouping_b = function(chars, min, max) {
+        if (this.cursor <= this.limit_backward ||
+            !inGrouping(chars, this.current.charCodeAt(this.cursor - 1), min, max)) {
+            return false;
+        }
+        this.cursor -= 1;
+        return true;
+    };
+
+    SnowballEnv.prototype.out_grouping = function(chars, min, max) {
+        if (this.cursor >= this.limit ||
+            inGrouping(chars, this.current.charCodeAt(this.cursor), min, max)) {
+            return false;
+        }
+        this.cursor += 1;
+        return true;
+    };
+
+    SnowballEnv.prototype.out_grouping_b = function(chars, min, max) {
+        if (this.cursor <= this.limit_backward ||
+            inGrouping(chars, this.current.charCodeAt(this.cursor - 1), min, max)) {
+            return false;
+        }
+        this.cursor -= 1;
+        return true;
+    };
+
+    // Finds the longest string in `amongs` which starts at the cursor, and
+    // whose method (if it has one) succeeds. `amongs` is sorted, and each of
+    // them refers to the longest of the others which it starts with.
+    SnowballEnv.prototype.find_among = function(amongs, context) {
+        var i = 0;
+        var j = amongs.length;
+        var c = this.cursor;
+        var l = this.limit;
+        var common_i = 0;
+        var common_j = 0;
+        var first_key_inspected = false;
+
+        while (true) {
+            var k = i + ((j - i) >> 1);
+            var diff = 0;
+            var common = Math.min(common_i, common_j);
+            var w = amongs[k];
+            for (var lvar = common; lvar < w.s.length; lvar++) {
+                if (c + common == l) {
+                    diff = -1;
+                    break;
+                }
+                diff = this.current.charCodeAt(c + common) - w.s.charCodeAt(lvar);
+                if (diff != 0) {
+                    break;
+                }
+                common += 1;
+            }
+            if (diff < 0) {
+                j = k;
+                common_j = common;
+            } else {
+                i = k;
+                common_i = common;
+            }
+            if (j - i <= 1) {
+                if (i > 0 || j == i || first_key_inspected) {
+                    break;
+                }
+                first_key_inspected = true;
+            }
+        }
+
+        while (true) {
+            var w = amongs[i];
+            if (common_i >= w.s.length) {
+                this.cursor = c + w.s.length;
+                if (!w.method) {
+                    return w.result;
+                }
+                var res = w.method(this, context);
+                this.cursor = c + w.s.length;
+                if (res) {
+                    return w.result;
+                }
+            }
+            i = w.substring_i;
+            if (i < 0) {
+                return 0;
+            }
+        }
+    };
+
+    // The same as `find_among`, for strings which end at the cursor
+    SnowballEnv.prototype.find_among_b = function(amongs, context) {
+        var i = 0;
+        var j = amongs.length;
+        var c = this.cursor;
+        var lb = this.limit_backward;
+        var common_i = 0;
+        var common_j = 0;
+        var first_key_inspected = false;
+
+        while (true) {
+            var k = i + ((j - i) >> 1);
+            var diff = 0;
+            var common = Math.min(common_i, common_j);
+            var w = amongs[k];
+            for (var lvar = w.s.length - common - 1; lvar >= 0; lvar--) {
+                if (c - common == lb) {
+                    diff = -1;
+                    break;
+                }
+                diff = this.current.charCodeAt(c - common - 1) - w.s.charCodeAt(lvar);
+                if (diff != 0) {
+                    break;
+                }
+                common += 1;
+            }
+            if (diff < 0) {
+                j = k;
+                common_j = common;
+            } else {
+                i = k;
+                common_i = common;
+            }
+            if (j - i <= 1) {
+                if (i > 0 || j == i || first_key_inspected) {
+                    break;
+                }
+                first_key_inspected = true;
+            }
+        }
+
+        while (true) {
+            var w = amongs[i];
+            if (common_i >= w.s.length) {
+                this.cursor = c - w.s.length;
+                if (!w.method) {
+                    return w.result;
+                }
+                var res = w.method(this, context);
+                this.cursor = c - w.s.length;
+                if (res) {
+                    return w.result;
+                }
+            }
+            i = w.substring_i;
+            if (i < 0) {
+                return 0;
+            }
+        }
+    };
+
+    // Registers the functions elasticlunr-rs puts in the pipeline of the
+    // index for a language, under the same names.
+    //
+    // `suffix` is what the names end in, `wordCharacters` a regular expression
+    // character class of what the trimmer keeps, `stopWords` the words which
+    // aren't indexed, and `stem` the Snowball stemmer of the language.
+    function registerLanguage(suffix, language) {
+        var start = new RegExp("^[^" + language.wordCharacters + "]+");
+        var end = new RegExp("[^" + language.wordCharacters + "]+$");
+        var trimmer = function(token) {
+            return token.replace(start, "").replace(end, "");
+        };
+
+        var stopWords = {};
+        language.stopWords.forEach(function(word) {
+            stopWords[word] = true;
+        });
+        var stopWordFilter = function(token) {
+            return stopWords.hasOwnProperty(token) ? undefined : token;
+        };
+
+        var stemmer = function(token) {
+            var env = new SnowballEnv(token);
+            language.stem(env);
+            return env.current;
+        };
+
+        elasticlunr.Pipeline.registerFunction(trimmer, "trimmer-" + suffix);
+        elasticlunr.Pipeline.registerFunction(stopWordFilter, "stopWordFilter-" + suffix);
+        elasticlunr.Pipeline.registerFunction(stemmer, "stemmer-" + suffix);
+    }
+
+    // Must match `trim_non_alphanumeric` in `search.rs`, which the index of a
+    // book in a language without a stemmer uses
+    function trimNonAlphanumeric(token) {
+        token = token.replace(/^[^\p{Alphabetic}\p{N}]+|[^\p{Alphabetic}\p{N}]+$/gu, "");
+        return token.length ? token : undefined;
+    }
+
+    elasticlunr.Pipeline.registerFunction(trimNonAlphanumeric, "trimmer-unicode");
+
+    elasticlunr.stemmerSupport = {
+        Among: Among,
+        SnowballEnv: SnowballEnv,
+        registerLanguage: registerLanguage
+    };
+})(elasticlunr);
@@ -4,3 +4,22 @@
 pub static JS: &[u8] = include_bytes!("searcher.js");
 pub static MARK_JS: &[u8] = include_bytes!("mark.min.js");
 pub static ELASTICLUNR_JS: &[u8] = include_bytes!("elasticlunr.min.js");
+pub static STEMMER_SUPPORT_JS: &[u8] = include_bytes!("lunr.stemmer.support.js");
+
+/// The trimmer, stop word filter and stemmer of each language besides English
+/// which elasticlunr supports, by the suffix of their names in the index
+/// (which is `du` for Dutch).
+pub static LANGUAGES: &[(&str, &[u8])] = &[
+    ("da", include_bytes!("languages/lunr.da.js")),
+    ("de", include_bytes!("languages/lunr.de.js")),
+    ("du", include_bytes!("languages/lunr.du.js")),
+    ("es", include_bytes!("languages/lunr.es.js")),
+    ("fi", include_bytes!("languages/lunr.fi.js")),
+    ("fr", include_bytes!("languages/lunr.fr.js")),
+    ("it", include_bytes!("languages/lunr.it.js")),
+    ("pt", include_bytes!("languages/lunr.pt.js")),
+    ("ro", include_bytes!("languages/lunr.ro.js")),
+    ("ru", include_bytes!("languages/lunr.ru.js")),
+    ("sv", include_bytes!("languages/lunr.sv.js")),
+    ("tr", include_bytes!("languages/lunr.tr.js")),
+];
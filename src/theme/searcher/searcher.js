@@ -28,6 +28,10 @@ window.search = window.search || {};
 
         searchindex = null,
         doc_urls = [],
+        doc_boosts = {},
+        shards = null,
+        shard_requests = {},
+        stem = elasticlunr.stemmer,
         results_options = {
             teaser_word_count: 30,
             limit_results: 30,
@@ -145,7 +149,10 @@ window.search = window.search || {};
             url.push("");
         }
 
-        return '<a href="' + path_to_root + url[0] + '?' + URL_MARK_PARAM + '=' + searchterms + '#' + url[1]
+        // Link from the site's root when it is known, so results also work on
+        // pages which aren't at their own path, like the 404 page
+        var root = (typeof site_url !== 'undefined' && site_url) || path_to_root;
+        return '<a href="' + root + url[0] + '?' + URL_MARK_PARAM + '=' + searchterms + '#' + url[1]
             + '" aria-details="teaser_' + teaser_count + '">' + result.doc.breadcrumbs + '</a>'
             + '<span class="teaser" id="teaser_' + teaser_count + '" aria-label="Search Result Teaser">' 
             + teaser + '</span>';
@@ -162,7 +169,9 @@ window.search = window.search || {};
         // maximum sum. If there are multiple maximas, then get the last one.
         // Enclose the terms in <em>.
         var stemmed_searchterms = searchterms.map(function(w) {
-            return elasticlunr.stemmer(w.toLowerCase());
+            return stem(w.toLowerCase());
+        }).filter(function(w) {
+            return w.length > 0;
         });
         var searchterm_weight = 40;
         var weighted = []; // contains elements of ["word", weight, index_in_document]
@@ -178,7 +187,7 @@ window.search = window.search || {};
                 var word = words[wordindex];
                 if (word.length > 0) {
                     for (var searchtermindex in stemmed_searchterms) {
-                        if (elasticlunr.stemmer(word).startsWith(stemmed_searchterms[searchtermindex])) {
+                        if (stem(word).startsWith(stemmed_searchterms[searchtermindex])) {
                             value = searchterm_weight;
                             searchterm_found = true;
                         }
@@ -247,11 +256,50 @@ window.search = window.search || {};
         return teaser_split.join('');
     }
 
+    // Must match `segment_cjk` in `search.rs`
+    function segmentCjk(text) {
+        return text.replace(/[\u3040-\u30ff\u3400-\u4dbf\u4e00-\u9fff\uac00-\ud7af\uf900-\ufaff\uff66-\uff9f]+/g, function(run) {
+            if (run.length == 1) {
+                return ' ' + run + ' ';
+            }
+            var pairs = [];
+            for (var i = 0; i < run.length - 1; i++) {
+                pairs.push(run.substr(i, 2));
+            }
+            return ' ' + pairs.join(' ') + ' ';
+        });
+    }
+
+    // elasticlunr.js only knows how to turn English words into search terms.
+    // The pipeline of the index for other languages is made of the functions
+    // the lunr.*.js scripts of the language register, so the search words are
+    // stemmed with the same stemmer as the book.
+    function useLanguage(language, pipeline) {
+        var stemmer = pipeline.filter(function(name) {
+            return name.startsWith('stemmer-');
+        })[0];
+        stem = stemmer ? elasticlunr.Pipeline.getRegisteredFunction(stemmer) : function(word) {
+            return word;
+        };
+
+        if (language.segment_cjk) {
+            var tokenizer = elasticlunr.tokenizer;
+            elasticlunr.tokenizer = function(str) {
+                return tokenizer(typeof str == 'string' ? segmentCjk(str) : str);
+            };
+        }
+    }
+
     function init(config) {
         results_options = config.results_options;
         search_options = config.search_options;
         searchbar_outer = config.searchbar_outer;
         doc_urls = config.doc_urls;
+        doc_boosts = config.doc_boosts || {};
+        shards = config.shards || null;
+        if (config.language) {
+            useLanguage(config.language, config.index.pipeline);
+        }
         searchindex = elasticlunr.Index.load(config.index);
 
         // Set up events
@@ -434,6 +482,62 @@ window.search = window.search || {};
         }
     }
     
+    // Loads a shard of a sharded index, falling back to its script if the
+    // fetch fails (e.g. because the book was opened from the file system).
+    function loadShard(name) {
+        if (!shard_requests[name]) {
+            var path = path_to_root + 'searchindex/' + name;
+            shard_requests[name] = fetch(path + '.json')
+                .then(response => response.json())
+                .catch(error => new Promise((resolve, reject) => {
+                    var script = document.createElement('script');
+                    script.src = path + '.js';
+                    script.onload = () => resolve(window.search.shard_contents[name]);
+                    script.onerror = reject;
+                    document.head.appendChild(script);
+                }));
+        }
+        return shard_requests[name];
+    }
+
+    // Makes sure the terms which could match the search term are in the index.
+    // Every term is in the shard for its first character.
+    function loadTermShards(searchterm) {
+        if (shards == null) { return Promise.resolve(); }
+
+        var tokens = searchindex.pipeline.run(elasticlunr.tokenizer(searchterm));
+        var names = tokens
+            .map(token => 'terms-' + token.codePointAt(0).toString(16))
+            .filter((name, i, names) => names.indexOf(name) == i && shards.terms.indexOf(name) != -1);
+
+        return Promise.all(names.map(loadShard)).then(loaded => loaded.forEach(shard => {
+            for (var field in shard) {
+                Object.assign(searchindex.index[field].root, shard[field]);
+            }
+        }));
+    }
+
+    // Makes sure the documents of the results are loaded, so they can be shown.
+    function loadResultDocs(results) {
+        if (shards == null) { return Promise.resolve(results); }
+
+        var names = results
+            .map(result => {
+                var i = shards.docs.length - 1;
+                while (i > 0 && shards.docs[i] > +result.ref) { i--; }
+                return 'docs-' + i;
+            })
+            .filter((name, i, names) => names.indexOf(name) == i);
+
+        return Promise.all(names.map(loadShard)).then(loaded => {
+            loaded.forEach(shard => Object.assign(searchindex.documentStore.docs, shard));
+            results.forEach(result => {
+                result.doc = searchindex.documentStore.getDoc(result.ref);
+            });
+            return results;
+        });
+    }
+
     function doSearch(searchterm) {
 
         // Don't search the same twice
@@ -442,24 +546,39 @@ window.search = window.search || {};
 
         if (searchindex == null) { return; }
 
-        // Do the actual search
-        var results = searchindex.search(searchterm, search_options);
-        var resultcount = Math.min(results.length, results_options.limit_results);
-
-        // Display search metrics
-        searchresults_header.innerText = formatSearchMetric(resultcount, searchterm);
-
-        // Clear and insert results
-        var searchterms  = searchterm.split(' ');
-        removeChildren(searchresults);
-        for(var i = 0; i < resultcount ; i++){
-            var resultElem = document.createElement('li');
-            resultElem.innerHTML = formatSearchResult(results[i], searchterms);
-            searchresults.appendChild(resultElem);
-        }
+        loadTermShards(searchterm)
+            .then(() => {
+                // Do the actual search
+                var results = searchindex.search(searchterm, search_options);
+                results.forEach(result => {
+                    if (doc_boosts.hasOwnProperty(result.ref)) {
+                        result.score *= doc_boosts[result.ref];
+                    }
+                });
+                results.sort((a, b) => b.score - a.score);
+                return loadResultDocs(results.slice(0, results_options.limit_results));
+            })
+            .then(results => {
+                // A later search may have finished first
+                if (current_searchterm != searchterm) { return; }
+
+                var resultcount = results.length;
+
+                // Display search metrics
+                searchresults_header.innerText = formatSearchMetric(resultcount, searchterm);
+
+                // Clear and insert results
+                var searchterms  = searchterm.split(' ');
+                removeChildren(searchresults);
+                for(var i = 0; i < resultcount ; i++){
+                    var resultElem = document.createElement('li');
+                    resultElem.innerHTML = formatSearchResult(results[i], searchterms);
+                    searchresults.appendChild(resultElem);
+                }
 
-        // Display results
-        showResults(true);
+                // Display results
+                showResults(true);
+            });
     }
 
     fetch(path_to_root + 'searchindex.json')
@@ -72,92 +72,114 @@ pub fn id_from_content(content: &str) -> String {
 /// This adjusts links, such as turning `.md` extensions to `.html`.
 ///
 /// `path` is the path to the page being rendered relative to the root of the
-/// book. This is used for the `print.html` page so that links on the print
-/// page go to the original location. Normal page rendering sets `path` to
-/// None. Ideally, print page links would link to anchors on the print page,
-/// but that is very difficult.
-fn adjust_links<'a>(event: Event<'a>, path: Option<&Path>) -> Event<'a> {
+/// book. It is used when the chapter is rendered somewhere other than its
+/// own page (e.g. the `print.html` page), so relative links still go to the
+/// original location. Normal page rendering sets `path` to None.
+///
+/// `custom` gets the first chance to rewrite each destination, which is how
+/// the print page turns links between chapters into links to its own
+/// anchors. Destinations it returns `None` for get the default treatment.
+fn adjust_links<'a, F>(event: Event<'a>, path: Option<&Path>, custom: &F) -> Event<'a>
+where
+    F: Fn(&str) -> Option<String>,
+{
+    let fix = |dest: CowStr<'a>| match custom(&dest) {
+        Some(fixed) => CowStr::from(fixed),
+        None => fix_link(dest, path),
+    };
+    let fix_html = |html: CowStr<'a>| {
+        fix_html_links(html, |dest| match custom(dest) {
+            Some(fixed) => fixed,
+            None => fix_link(dest.into(), path).into_string(),
+        })
+    };
+
+    match event {
+        Event::Start(Tag::Link(link_type, dest, title)) => {
+            Event::Start(Tag::Link(link_type, fix(dest), title))
+        }
+        Event::Start(Tag::Image(link_type, dest, title)) => {
+            Event::Start(Tag::Image(link_type, fix(dest), title))
+        }
+        Event::Html(html) => Event::Html(fix_html(html)),
+        Event::InlineHtml(html) => Event::InlineHtml(fix_html(html)),
+        _ => event,
+    }
+}
+
+/// The default fix-ups applied to a link's destination by `adjust_links`.
+fn fix_link<'a>(dest: CowStr<'a>, path: Option<&Path>) -> CowStr<'a> {
     lazy_static! {
         static ref SCHEME_LINK: Regex = Regex::new(r"^[a-z][a-z0-9+.-]*:").unwrap();
         static ref MD_LINK: Regex = Regex::new(r"(?P<link>.*)\.md(?P<anchor>#.*)?").unwrap();
     }
 
-    fn fix<'a>(dest: CowStr<'a>, path: Option<&Path>) -> CowStr<'a> {
-        if dest.starts_with('#') {
-            // Fragment-only link.
-            if let Some(path) = path {
-                let mut base = path.display().to_string();
-                if base.ends_with(".md") {
-                    base.replace_range(base.len() - 3.., ".html");
-                }
-                return format!("{}{}", base, dest).into();
-            } else {
-                return dest;
+    if dest.starts_with('#') {
+        // Fragment-only link.
+        if let Some(path) = path {
+            let mut base = path.display().to_string();
+            if base.ends_with(".md") {
+                base.replace_range(base.len() - 3.., ".html");
             }
+            return format!("{}{}", base, dest).into();
+        } else {
+            return dest;
         }
-        // Don't modify links with schemes like `https`.
-        if !SCHEME_LINK.is_match(&dest) {
-            // This is a relative link, adjust it as necessary.
-            let mut fixed_link = String::new();
-            if let Some(path) = path {
-                let base = path
-                    .parent()
-                    .expect("path can't be empty")
-                    .to_str()
-                    .expect("utf-8 paths only");
-                if !base.is_empty() {
-                    write!(fixed_link, "{}/", base).unwrap();
-                }
-            }
-
-            if let Some(caps) = MD_LINK.captures(&dest) {
-                fixed_link.push_str(&caps["link"]);
-                fixed_link.push_str(".html");
-                if let Some(anchor) = caps.name("anchor") {
-                    fixed_link.push_str(anchor.as_str());
-                }
-            } else {
-                fixed_link.push_str(&dest);
-            };
-            return CowStr::from(fixed_link);
-        }
-        dest
     }
-
-    fn fix_html<'a>(html: CowStr<'a>, path: Option<&Path>) -> CowStr<'a> {
-        // This is a terrible hack, but should be reasonably reliable. Nobody
-        // should ever parse a tag with a regex. However, there isn't anything
-        // in Rust that I know of that is suitable for handling partial html
-        // fragments like those generated by pulldown_cmark.
-        //
-        // There are dozens of HTML tags/attributes that contain paths, so
-        // feel free to add more tags if desired; these are the only ones I
-        // care about right now.
-        lazy_static! {
-            static ref HTML_LINK: Regex =
-                Regex::new(r#"(<(?:a|img) [^>]*?(?:src|href)=")([^"]+?)""#).unwrap();
+    // Don't modify links with schemes like `https`.
+    if !SCHEME_LINK.is_match(&dest) {
+        // This is a relative link, adjust it as necessary.
+        let mut fixed_link = String::new();
+        if let Some(path) = path {
+            let base = path
+                .parent()
+                .expect("path can't be empty")
+                .to_str()
+                .expect("utf-8 paths only");
+            if !base.is_empty() {
+                write!(fixed_link, "{}/", base).unwrap();
+            }
         }
 
-        HTML_LINK
-            .replace_all(&html, |caps: &regex::Captures<'_>| {
-                let fixed = fix(caps[2].into(), path);
-                format!("{}{}\"", &caps[1], fixed)
-            })
-            .into_owned()
-            .into()
+        if let Some(caps) = MD_LINK.captures(&dest) {
+            fixed_link.push_str(&caps["link"]);
+            fixed_link.push_str(".html");
+            if let Some(anchor) = caps.name("anchor") {
+                fixed_link.push_str(anchor.as_str());
+            }
+        } else {
+            fixed_link.push_str(&dest);
+        };
+        return CowStr::from(fixed_link);
     }
+    dest
+}
 
-    match event {
-        Event::Start(Tag::Link(link_type, dest, title)) => {
-            Event::Start(Tag::Link(link_type, fix(dest, path), title))
-        }
-        Event::Start(Tag::Image(link_type, dest, title)) => {
-            Event::Start(Tag::Image(link_type, fix(dest, path), title))
-        }
-        Event::Html(html) => Event::Html(fix_html(html, path)),
-        Event::InlineHtml(html) => Event::InlineHtml(fix_html(html, path)),
-        _ => event,
+/// Pass the destination of every `<a>` and `<img>` tag in a fragment of raw
+/// HTML through `fix`.
+fn fix_html_links<'a, F>(html: CowStr<'a>, fix: F) -> CowStr<'a>
+where
+    F: Fn(&str) -> String,
+{
+    // This is a terrible hack, but should be reasonably reliable. Nobody
+    // should ever parse a tag with a regex. However, there isn't anything
+    // in Rust that I know of that is suitable for handling partial html
+    // fragments like those generated by pulldown_cmark.
+    //
+    // There are dozens of HTML tags/attributes that contain paths, so
+    // feel free to add more tags if desired; these are the only ones I
+    // care about right now.
+    lazy_static! {
+        static ref HTML_LINK: Regex =
+            Regex::new(r#"(<(?:a|img) [^>]*?(?:src|href)=")([^"]+?)""#).unwrap();
     }
+
+    HTML_LINK
+        .replace_all(&html, |caps: &regex::Captures<'_>| {
+            format!("{}{}\"", &caps[1], fix(&caps[2]))
+        })
+        .into_owned()
+        .into()
 }
 
 /// Wrapper around the pulldown-cmark parser for rendering markdown to HTML.
@@ -175,12 +197,29 @@ pub fn new_cmark_parser(text: &str) -> Parser<'_> {
 }
 
 pub fn render_markdown_with_path(text: &str, curly_quotes: bool, path: Option<&Path>) -> String {
+    render_markdown_with_link_fixer(text, curly_quotes, path, |_| None)
+}
+
+/// Render markdown to HTML, letting the caller decide where some links and
+/// images should point to.
+///
+/// `fix_link` is given the destination of every link and image, and returns
+/// `None` to get the usual fix-ups (see `render_markdown_with_path`).
+pub fn render_markdown_with_link_fixer<F>(
+    text: &str,
+    curly_quotes: bool,
+    path: Option<&Path>,
+    fix_link: F,
+) -> String
+where
+    F: Fn(&str) -> Option<String>,
+{
     let mut s = String::with_capacity(text.len() * 3 / 2);
     let p = new_cmark_parser(text);
     let mut converter = EventQuoteConverter::new(curly_quotes);
     let events = p
         .map(clean_codeblock_headers)
-        .map(|event| adjust_links(event, path))
+        .map(|event| adjust_links(event, path, &fix_link))
         .map(|event| converter.convert(event));
 
     html::push_html(&mut s, events);
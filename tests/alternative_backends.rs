@@ -34,6 +34,20 @@ fn alternate_backend_with_arguments() {
     md.build().unwrap();
 }
 
+#[test]
+fn epub_backends_with_a_command_arent_replaced_by_the_built_in_one() {
+    let (md, _temp) = dummy_book_with_backend("epub", fail_cmd());
+
+    md.build().unwrap_err();
+}
+
+#[test]
+fn print_backends_with_a_command_arent_replaced_by_the_built_in_one() {
+    let (md, _temp) = dummy_book_with_backend("print", fail_cmd());
+
+    md.build().unwrap_err();
+}
+
 /// Get a command which will pipe `stdin` to the provided file.
 #[cfg(not(windows))]
 fn tee_command<P: AsRef<Path>>(out_file: P) -> String {
@@ -1,12 +1,13 @@
 mod dummy_book;
 
 use crate::dummy_book::DummyBook;
-use mdbook::book::Book;
+use mdbook::book::{Book, BookItem};
 use mdbook::config::Config;
 use mdbook::errors::*;
 use mdbook::preprocess::{Preprocessor, PreprocessorContext};
 use mdbook::renderer::{RenderContext, Renderer};
 use mdbook::MDBook;
+use std::fs;
 use std::sync::{Arc, Mutex};
 
 struct Spy(Arc<Mutex<Inner>>);
@@ -76,3 +77,50 @@ fn mdbook_runs_renderers() {
     let inner = spy.lock().unwrap();
     assert_eq!(inner.run_count, 1);
 }
+
+#[test]
+fn cached_preprocessors_only_run_when_the_book_changes() {
+    let spy: Arc<Mutex<Inner>> = Default::default();
+
+    let temp = DummyBook::new().build().unwrap();
+    let mut cfg = Config::default();
+    cfg.set("preprocessor.dummy.cache", true).unwrap();
+
+    let mut book = MDBook::load_with_config(temp.path(), cfg).unwrap();
+    book.with_preprocessor(Spy(Arc::clone(&spy)));
+    book.build().unwrap();
+    book.build().unwrap();
+
+    assert_eq!(spy.lock().unwrap().run_count, 1);
+    assert!(book.cache_dir().join("dummy").exists());
+    assert!(!book.cache_dir().starts_with(temp.path().join("book")));
+
+    book.book.for_each_mut(|item| {
+        if let BookItem::Chapter(ref mut ch) = *item {
+            ch.content.push_str("\nSomething new");
+        }
+    });
+    book.build().unwrap();
+
+    assert_eq!(spy.lock().unwrap().run_count, 2);
+    // The output for the old content was replaced
+    let entries = fs::read_dir(book.cache_dir().join("dummy")).unwrap();
+    assert_eq!(entries.count(), 1);
+}
+
+#[test]
+fn cached_preprocessor_output_is_kept_per_language() {
+    let spy: Arc<Mutex<Inner>> = Default::default();
+
+    let temp = DummyBook::new().build().unwrap();
+    let mut cfg = Config::default();
+    cfg.book.language = Some(String::from("de"));
+    cfg.set("preprocessor.dummy.cache", true).unwrap();
+
+    let mut book = MDBook::load_with_config(temp.path(), cfg).unwrap();
+    book.with_preprocessor(Spy(Arc::clone(&spy)));
+    book.build().unwrap();
+
+    assert!(book.cache_dir().join("dummy/html/de.json").exists());
+    assert!(!book.cache_dir().join("dummy/html.json").exists());
+}
@@ -0,0 +1,24 @@
+mod dummy_book;
+
+use crate::dummy_book::DummyBook;
+
+use mdbook::MDBook;
+
+#[test]
+fn mdbook_check_reports_broken_links() {
+    let temp = DummyBook::new().build().unwrap();
+    let md = MDBook::load(temp.path()).unwrap();
+
+    let broken: Vec<_> = md
+        .check()
+        .unwrap()
+        .into_iter()
+        .map(|broken| broken.to_string())
+        .collect();
+
+    assert!(broken.contains(
+        &"second/nested.md:10: link to a file which doesn't exist: ../images/picture.png"
+            .to_string()
+    ));
+    assert!(!broken.iter().any(|b| b.contains("../first/nested.md")));
+}
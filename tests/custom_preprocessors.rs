@@ -54,3 +54,63 @@ fn process_the_dummy_book() {
 
     md.build().unwrap();
 }
+
+#[test]
+#[cfg(not(windows))]
+fn preprocessors_speaking_another_protocol_are_refused() {
+    let future = CmdPreprocessor::new(
+        "future".to_string(),
+        r#"sh -c "echo '{\"protocol_versions\": [99]}'""#.to_string(),
+    )
+    .with_capabilities(true);
+    let dummy_book = DummyBook::new();
+    let temp = dummy_book.build().unwrap();
+    let mut md = MDBook::load(temp.path()).unwrap();
+    md.with_preprocessor(future);
+
+    let got = md.build().unwrap_err();
+
+    assert!(got.iter().any(|e| e
+        .to_string()
+        .contains("versions [99] of the plugin protocol")));
+}
+
+#[test]
+fn preprocessors_are_only_asked_for_their_capabilities_when_they_opt_in() {
+    let dummy_book = DummyBook::new();
+    let temp = dummy_book.build().unwrap();
+    let build = |preprocessor: CmdPreprocessor| {
+        let mut md = MDBook::load(temp.path()).unwrap();
+        md.config
+            .set("preprocessor.nop-preprocessor.blow-up", "please")
+            .unwrap();
+        md.with_preprocessor(preprocessor);
+        md.build()
+    };
+
+    // Without the handshake, the table isn't checked before running it
+    let got = build(example()).unwrap_err();
+    assert!(got.iter().any(|e| e.to_string().contains("Boom!!1!")));
+
+    let got = build(example().with_capabilities(true)).unwrap_err();
+    assert!(got
+        .iter()
+        .any(|e| e.to_string().contains("expects `blow-up` to be a boolean")));
+}
+
+#[test]
+fn persistent_preprocessors_are_reused_between_builds() {
+    let dummy_book = DummyBook::new();
+    let temp = dummy_book.build().unwrap();
+    let mut md = MDBook::load(temp.path()).unwrap();
+    md.with_preprocessor(example().with_persistent(true));
+
+    md.build().unwrap();
+    md.build().unwrap();
+
+    md.config
+        .set("preprocessor.nop-preprocessor.blow-up", true)
+        .unwrap();
+    let got = md.build().unwrap_err();
+    assert!(got.iter().any(|e| e.to_string().contains("Boom!!1!")));
+}
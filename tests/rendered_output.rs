@@ -5,6 +5,7 @@ mod dummy_book;
 
 use crate::dummy_book::{assert_contains_strings, assert_doesnt_contain_strings, DummyBook};
 
+use mdbook::book::IncrementalBuild;
 use mdbook::config::Config;
 use mdbook::errors::*;
 use mdbook::utils::fs::write_file;
@@ -122,12 +123,14 @@ fn check_correct_relative_links_in_print_page() {
     assert_contains_strings(
         first.join("print.html"),
         &[
-            r##"<a href="second/../first/nested.html">the first section</a>,"##,
+            r##"<div id="second-nested">"##,
+            r##"<a href="#first-nested">the first section</a>,"##,
             r##"<a href="second/../../std/foo/bar.html">outside</a>"##,
             r##"<img src="second/../images/picture.png" alt="Some image" />"##,
-            r##"<a href="second/nested.html#some-section">fragment link</a>"##,
-            r##"<a href="second/../first/markdown.html">HTML Link</a>"##,
+            r##"<a href="#second-nested.some-section">fragment link</a>"##,
+            r##"<a href="#first-markdown">HTML Link</a>"##,
             r##"<img src="second/../images/picture.png" alt="raw html">"##,
+            r##"id="second-nested.some-section">Some section</a></h2>"##,
         ],
     );
 }
@@ -382,6 +385,339 @@ fn book_with_a_reserved_filename_does_not_build() {
     assert!(got.is_err());
 }
 
+#[test]
+fn draft_chapters_are_listed_in_the_toc_but_not_rendered() {
+    let tmp_dir = TempFileBuilder::new().prefix("mdBook").tempdir().unwrap();
+    let src_path = tmp_dir.path().join("src");
+    fs::create_dir(&src_path).unwrap();
+
+    write_file(&src_path, "first.md", b"# First").unwrap();
+    write_file(
+        &src_path,
+        "SUMMARY.md",
+        b"# Summary\n\n- [First](first.md)\n- [Draft Chapter]()\n",
+    )
+    .unwrap();
+
+    let md = MDBook::load(tmp_dir.path()).unwrap();
+    md.build().unwrap();
+
+    let index_html = tmp_dir.path().join("book").join("index.html");
+    assert_contains_strings(
+        &index_html,
+        &[r#"<strong aria-hidden="true">2.</strong> Draft Chapter</li>"#],
+    );
+
+    let src_files = fs::read_dir(&src_path).unwrap().count();
+    assert_eq!(
+        src_files, 2,
+        "create-missing shouldn't touch draft chapters"
+    );
+}
+
+#[test]
+fn part_titles_are_shown_in_the_toc() {
+    let tmp_dir = TempFileBuilder::new().prefix("mdBook").tempdir().unwrap();
+    let src_path = tmp_dir.path().join("src");
+    fs::create_dir(&src_path).unwrap();
+
+    write_file(&src_path, "first.md", b"# First").unwrap();
+    write_file(&src_path, "second.md", b"# Second").unwrap();
+    write_file(
+        &src_path,
+        "SUMMARY.md",
+        b"# Summary\n\n# Part I\n\n- [First](first.md)\n\n# Part II\n\n- [Second](second.md)\n",
+    )
+    .unwrap();
+
+    let md = MDBook::load(tmp_dir.path()).unwrap();
+    md.build().unwrap();
+
+    let index_html = tmp_dir.path().join("book").join("index.html");
+    assert_contains_strings(
+        &index_html,
+        &[
+            r#"<li class="part-title">Part I</li>"#,
+            r#"<li class="part-title">Part II</li>"#,
+            r#"<strong aria-hidden="true">2.</strong> Second"#,
+        ],
+    );
+}
+
+#[test]
+fn multilingual_books_render_each_language_to_its_own_directory() {
+    let tmp_dir = TempFileBuilder::new().prefix("mdBook").tempdir().unwrap();
+    let src_path = tmp_dir.path().join("src");
+    let en = src_path.join("en");
+    let ja = src_path.join("ja");
+    fs::create_dir_all(&en).unwrap();
+    fs::create_dir_all(&ja).unwrap();
+
+    write_file(
+        tmp_dir.path(),
+        "book.toml",
+        b"[book]\nmultilingual = true\nlanguage = \"en\"\n\n\
+          [language.en]\nname = \"English\"\n\n\
+          [language.ja]\nname = \"Japanese\"\ntitle = \"Hon\"\n",
+    )
+    .unwrap();
+    let summary = b"# Summary\n\n- [First](first.md)\n- [Second](second.md)\n";
+    write_file(&en, "SUMMARY.md", summary).unwrap();
+    write_file(&en, "first.md", b"# First").unwrap();
+    write_file(&en, "second.md", b"# Second\n\n![logo](logo.png)").unwrap();
+    write_file(&en, "logo.png", b"not really a png").unwrap();
+    write_file(&ja, "first.md", b"# Ichi").unwrap();
+
+    let md = MDBook::load(tmp_dir.path()).unwrap();
+    assert_eq!(md.translations.len(), 1);
+    md.build().unwrap();
+
+    let book_dir = tmp_dir.path().join("book");
+    assert_contains_strings(&book_dir.join("index.html"), &["url=en/index.html"]);
+    assert_contains_strings(
+        &book_dir.join("en").join("first.html"),
+        &[
+            r#"<html lang="en""#,
+            r#"id="language-toggle""#,
+            r#"href="../ja/index.html" hreflang="ja" lang="ja">Japanese</a>"#,
+        ],
+    );
+    assert_contains_strings(
+        &book_dir.join("ja").join("first.html"),
+        &[r#"<html lang="ja""#, "Ichi", "<title>First - Hon</title>"],
+    );
+    assert_doesnt_contain_strings(&book_dir.join("ja").join("first.html"), &["untranslated"]);
+    assert_contains_strings(
+        &book_dir.join("ja").join("second.html"),
+        &[
+            r#"<p class="untranslated-notice">This chapter hasn't been translated yet.</p>"#,
+            "Second",
+        ],
+    );
+    assert!(book_dir.join("ja").join("logo.png").exists());
+}
+
+#[test]
+fn gettext_catalogs_translate_the_book() {
+    let temp = DummyBook::new().build().unwrap();
+    let mut cfg = Config::default();
+    cfg.book.language = Some(String::from("de"));
+    cfg.set("preprocessor.gettext", toml::value::Table::new())
+        .unwrap();
+
+    let md = MDBook::load_with_config(temp.path(), cfg.clone()).unwrap();
+    let template = md.translation_template();
+    assert!(template.contains("#: src/intro.md:1\nmsgid \"Introduction\"\nmsgstr \"\"\n"));
+    assert!(template.contains("msgid \"Nested Chapter\"\n"));
+
+    write_file(
+        &temp.path().join("po"),
+        "de.po",
+        b"msgid \"Introduction\"\nmsgstr \"Einleitung\"\n\n\
+          msgid \"Nested Chapter\"\nmsgstr \"Verschachteltes Kapitel\"\n",
+    )
+    .unwrap();
+    let md = MDBook::load_with_config(temp.path(), cfg).unwrap();
+    md.build().unwrap();
+
+    let book_dir = temp.path().join("book");
+    assert_contains_strings(
+        &book_dir.join("intro.html"),
+        &[r##"<h1><a class="header" href="#einleitung" id="einleitung">Einleitung</a></h1>"##],
+    );
+    assert_contains_strings(
+        &book_dir.join("first").join("nested.html"),
+        &["Verschachteltes Kapitel"],
+    );
+}
+
+#[test]
+fn chapters_get_their_own_description_and_social_card_tags() {
+    let temp = DummyBook::new().build().unwrap();
+    let mut cfg = Config::default();
+    cfg.set("output.html.site-url", "https://example.com/book/")
+        .unwrap();
+    let md = MDBook::load_with_config(temp.path(), cfg).unwrap();
+    md.build().unwrap();
+
+    let first = temp.path().join("book/first/index.html");
+    assert_contains_strings(
+        &first,
+        &[
+            r#"<meta name="description" content="more text.">"#,
+            r#"<meta property="og:title" content="First Chapter">"#,
+            r#"<meta property="og:url" content="https://example.com/book/first/index.html">"#,
+            r#"<meta name="twitter:card" content="summary">"#,
+        ],
+    );
+}
+
+#[test]
+fn site_url_adds_a_sitemap_and_canonical_links() {
+    let temp = DummyBook::new().build().unwrap();
+    let mut cfg = Config::default();
+    cfg.set("output.html.site-url", "https://example.com/book")
+        .unwrap();
+    let md = MDBook::load_with_config(temp.path(), cfg).unwrap();
+    md.build().unwrap();
+
+    let book = temp.path().join("book");
+    assert_contains_strings(
+        book.join("intro.html"),
+        &[r#"<link rel="canonical" href="https://example.com/book/intro.html">"#],
+    );
+
+    let sitemap = fs::read_to_string(book.join("sitemap.xml")).unwrap();
+    assert!(sitemap.starts_with("<?xml"));
+    assert!(sitemap.contains("<loc>https://example.com/book/intro.html</loc>"));
+    assert!(sitemap.contains("<loc>https://example.com/book/first/nested.html</loc>"));
+    assert!(sitemap.contains("<lastmod>"));
+    assert_eq!(
+        sitemap.matches("<url>").count(),
+        sitemap.matches("<loc>").count()
+    );
+}
+
+#[test]
+fn site_url_path_is_given_to_templates() {
+    let temp = DummyBook::new().build().unwrap();
+    let md = MDBook::load(temp.path()).unwrap();
+    md.build().unwrap();
+    let intro = temp.path().join("book").join("intro.html");
+    assert_contains_strings(&intro, &[r#"var site_url = "";"#]);
+
+    let mut cfg = Config::default();
+    cfg.set("output.html.site-url", "/docs/book").unwrap();
+    let md = MDBook::load_with_config(temp.path(), cfg).unwrap();
+    md.build().unwrap();
+    assert_contains_strings(&intro, &[r#"var site_url = "/docs/book/";"#]);
+    // Social cards need an absolute URL
+    assert_doesnt_contain_strings(&intro, &["og:url", "rel=\"canonical\""]);
+}
+
+fn build_with_redirects(temp: &tempfile::TempDir, redirects: &[(&str, &str)]) -> Result<()> {
+    let mut table = toml::value::Table::new();
+    for &(from, to) in redirects {
+        table.insert(from.to_string(), to.into());
+    }
+    let mut cfg = Config::default();
+    cfg.set("output.html.redirect", table).unwrap();
+    MDBook::load_with_config(temp.path(), cfg)?.build()
+}
+
+#[test]
+fn redirects_get_stub_pages() {
+    let temp = DummyBook::new().build().unwrap();
+    build_with_redirects(
+        &temp,
+        &[
+            ("old/nested.html", "first/nested.html#some-section"),
+            ("/gone.html", "https://example.com/"),
+        ],
+    )
+    .unwrap();
+
+    let book = temp.path().join("book");
+    assert_contains_strings(
+        book.join("old/nested.html"),
+        &[r#"<meta http-equiv="refresh" content="0; url=../first/nested.html#some-section">"#],
+    );
+    assert_contains_strings(
+        book.join("gone.html"),
+        &[r#"<meta http-equiv="refresh" content="0; url=https://example.com/">"#],
+    );
+}
+
+#[test]
+fn redirects_must_point_into_the_book() {
+    let temp = DummyBook::new().build().unwrap();
+    let got = build_with_redirects(&temp, &[("old.html", "missing.html")]);
+    assert!(got.is_err());
+
+    let got = build_with_redirects(&temp, &[("intro.html", "first/nested.html")]);
+    assert!(got.is_err());
+}
+
+#[test]
+fn the_404_page_links_from_the_site_root() {
+    let temp = DummyBook::new().build().unwrap();
+    let mut cfg = Config::default();
+    cfg.set("output.html.site-url", "https://example.com/book/")
+        .unwrap();
+    let md = MDBook::load_with_config(temp.path(), cfg).unwrap();
+    md.build().unwrap();
+
+    assert_contains_strings(
+        temp.path().join("book").join("404.html"),
+        &[
+            "Document not found (404)",
+            r#"<link rel="stylesheet" href="/book/css/general.css">"#,
+            r#"<a href="/book/first/nested.html">"#,
+        ],
+    );
+}
+
+#[test]
+fn the_404_page_can_be_written_by_the_book() {
+    let temp = DummyBook::new().build().unwrap();
+    fs::write(
+        temp.path().join("src").join("404.md"),
+        "# Lost?\n\nTry the index.",
+    )
+    .unwrap();
+    let md = MDBook::load(temp.path()).unwrap();
+    md.build().unwrap();
+
+    let page_404 = temp.path().join("book").join("404.html");
+    assert_contains_strings(&page_404, &["Try the index.", r#"href="/css/general.css""#]);
+    assert_doesnt_contain_strings(&page_404, &["Document not found (404)"]);
+}
+
+#[test]
+fn the_epub_renderer_packages_the_book() {
+    let temp = DummyBook::new().build().unwrap();
+    let mut cfg = Config::default();
+    cfg.book.title = Some(String::from("Dummy Book"));
+    cfg.set("output.epub", toml::value::Table::new()).unwrap();
+    let md = MDBook::load_with_config(temp.path(), cfg).unwrap();
+    md.build().unwrap();
+
+    let epub = fs::read(temp.path().join("book").join("Dummy-Book.epub")).unwrap();
+    assert_eq!(&epub[..4], b"PK\x03\x04");
+    // The mimetype must be the first, uncompressed entry
+    assert_eq!(&epub[30..58], &b"mimetypeapplication/epub+zip"[..]);
+
+    let contents = String::from_utf8_lossy(&epub);
+    assert!(contents.contains("OEBPS/content.opf"));
+    assert!(contents.contains(r#"<item id="nav" href="nav.xhtml""#));
+    assert!(contents.contains(r#"<a href="first/nested.html">1.1. Nested Chapter</a>"#));
+    assert!(contents.contains("OEBPS/first/nested.html"));
+    assert!(contents.contains(r#"<h1 id="first-nested.nested-chapter">Nested Chapter</h1>"#));
+}
+
+#[test]
+fn the_print_renderer_writes_a_single_document() {
+    let temp = DummyBook::new().build().unwrap();
+    let mut cfg = Config::default();
+    cfg.book.title = Some(String::from("Dummy Book"));
+    cfg.set("output.print", toml::value::Table::new()).unwrap();
+    let md = MDBook::load_with_config(temp.path(), cfg).unwrap();
+    md.build().unwrap();
+
+    let document = temp.path().join("book").join("Dummy-Book.html");
+    assert_contains_strings(
+        &document,
+        &[
+            r##"<a href="#first-nested">1.1. Nested Chapter</a>"##,
+            r#"<section class="chapter" id="second-nested">"#,
+            r#"<h2 id="second-nested.some-section">Some section</h2>"#,
+            r##"<a href="#first-nested">the first section</a>"##,
+            r##"<a href="#second-nested.some-section">fragment link</a>"##,
+        ],
+    );
+    assert_doesnt_contain_strings(&document, &["../first/nested.html"]);
+}
+
 #[test]
 fn by_default_mdbook_use_index_preprocessor_to_convert_readme_to_index() {
     let temp = DummyBook::new().build().unwrap();
@@ -423,6 +759,31 @@ fn theme_dir_overrides_work_correctly() {
     dummy_book::assert_contains_strings(built_index, &["This is a modified index.hbs!"]);
 }
 
+#[test]
+fn chapters_rendered_in_parallel_come_out_in_order() {
+    let first = DummyBook::new().build().unwrap();
+    let second = DummyBook::new().build().unwrap();
+    MDBook::load(first.path()).unwrap().build().unwrap();
+    MDBook::load(second.path()).unwrap().build().unwrap();
+
+    let print_page = |root: &Path| fs::read_to_string(root.join("book/print.html")).unwrap();
+    let first_print = print_page(first.path());
+
+    let positions: Vec<usize> = [
+        "Dummy Book",
+        "Nested Chapter",
+        "Second Chapter",
+        "Conclusion",
+    ]
+    .iter()
+    .map(|heading| first_print.find(&format!(">{}</a></h1>", heading)).unwrap())
+    .collect();
+    let mut sorted = positions.clone();
+    sorted.sort();
+    assert_eq!(positions, sorted);
+    assert_eq!(first_print, print_page(second.path()));
+}
+
 #[test]
 fn no_index_for_print_html() {
     let temp = DummyBook::new().build().unwrap();
@@ -469,9 +830,121 @@ fn markdown_options() {
     );
 }
 
+fn incremental_build(root: &Path) -> IncrementalBuild {
+    let root = root.to_path_buf();
+    IncrementalBuild::new(move || MDBook::load(&root)).unwrap()
+}
+
+#[test]
+fn incremental_rebuilds_only_render_the_changed_chapters() {
+    let temp = DummyBook::new().build().unwrap();
+    let mut build = incremental_build(temp.path());
+    let book_dir = temp.path().join("book");
+
+    // Anything which gets rendered again will reappear
+    fs::remove_file(book_dir.join("second.html")).unwrap();
+    fs::remove_file(book_dir.join("css/general.css")).unwrap();
+
+    let intro = temp.path().join("src/intro.md");
+    let mut content = fs::read_to_string(&intro).unwrap();
+    content.push_str("\nFreshly edited.\n");
+    fs::write(&intro, content).unwrap();
+    build.rebuild(&[intro]).unwrap();
+
+    assert_contains_strings(book_dir.join("intro.html"), &["Freshly edited."]);
+    assert_contains_strings(book_dir.join("print.html"), &["Freshly edited."]);
+    assert!(!book_dir.join("second.html").exists());
+    assert!(!book_dir.join("css/general.css").exists());
+}
+
+#[test]
+fn incremental_rebuilds_update_chapters_which_include_a_changed_file() {
+    let temp = DummyBook::new().build().unwrap();
+    let mut build = incremental_build(temp.path());
+
+    let included = temp.path().join("src/first/nested-test.rs");
+    fs::write(&included, "fn freshly_edited() {}\n").unwrap();
+    build.rebuild(&[included]).unwrap();
+
+    assert_contains_strings(
+        temp.path().join("book/first/nested.html"),
+        &["freshly_edited"],
+    );
+}
+
+#[test]
+fn incremental_rebuilds_update_chapters_which_include_a_changed_file_indirectly() {
+    let temp = DummyBook::new().build().unwrap();
+    let first = temp.path().join("src/first");
+    fs::write(first.join("nested-test.rs"), "{{#include inner.rs}}\n").unwrap();
+    fs::write(first.join("inner.rs"), "fn original() {}\n").unwrap();
+    let mut build = incremental_build(temp.path());
+
+    let included = first.join("inner.rs");
+    fs::write(&included, "fn freshly_edited() {}\n").unwrap();
+    build.rebuild(&[included]).unwrap();
+
+    assert_contains_strings(
+        temp.path().join("book/first/nested.html"),
+        &["freshly_edited"],
+    );
+}
+
+#[test]
+fn incremental_rebuilds_update_references_to_a_changed_heading() {
+    let temp = DummyBook::new().build().unwrap();
+    fs::write(temp.path().join("book.toml"), "[preprocessor.xref]\n").unwrap();
+    let intro = temp.path().join("src/intro.md");
+    fs::write(&intro, "# Introduction {{#label intro}}\n").unwrap();
+    fs::write(
+        temp.path().join("src/conclusion.md"),
+        "# Conclusion\n\nSee {{#ref intro}}.\n",
+    )
+    .unwrap();
+    let mut build = incremental_build(temp.path());
+
+    fs::write(&intro, "# Welcome {{#label intro}}\n").unwrap();
+    build.rebuild(&[intro]).unwrap();
+
+    assert_contains_strings(
+        temp.path().join("book/conclusion.html"),
+        &[r#"<a href="intro.html#welcome">Welcome</a>"#],
+    );
+}
+
+#[test]
+fn incremental_builds_survive_a_book_which_is_broken_at_first() {
+    let temp = DummyBook::new().build().unwrap();
+    fs::write(temp.path().join("book.toml"), "[preprocessor.xref]\n").unwrap();
+    let intro = temp.path().join("src/intro.md");
+    fs::write(&intro, "# Introduction\n\nSee {{#ref missing}}.\n").unwrap();
+    let mut build = incremental_build(temp.path());
+    assert!(!temp.path().join("book/intro.html").exists());
+
+    fs::write(&intro, "# Introduction {{#label missing}}\n").unwrap();
+    build.rebuild(&[intro]).unwrap();
+
+    assert!(temp.path().join("book/intro.html").exists());
+}
+
+#[test]
+fn changing_the_summary_rebuilds_everything() {
+    let temp = DummyBook::new().build().unwrap();
+    let mut build = incremental_build(temp.path());
+    let second = temp.path().join("book/second.html");
+
+    fs::remove_file(&second).unwrap();
+    build
+        .rebuild(&[temp.path().join("src/SUMMARY.md")])
+        .unwrap();
+
+    assert!(second.exists());
+}
+
 #[cfg(feature = "search")]
 mod search {
-    use crate::dummy_book::DummyBook;
+    use crate::dummy_book::{assert_contains_strings, DummyBook};
+    use mdbook::book::IncrementalBuild;
     use mdbook::MDBook;
     use std::fs::{self, File};
     use std::path::Path;
@@ -520,6 +993,167 @@ mod search {
         assert_eq!(docs[&conclusion]["body"], "I put &lt;HTML&gt; in here!");
     }
 
+    #[test]
+    fn incremental_rebuilds_patch_the_search_index() {
+        let temp = DummyBook::new().build().unwrap();
+        let root = temp.path().to_path_buf();
+        let mut build = IncrementalBuild::new(move || MDBook::load(&root)).unwrap();
+
+        let intro = temp.path().join("src/intro.md");
+        fs::write(
+            &intro,
+            "# Introduction\n\nSomething completely different.\n",
+        )
+        .unwrap();
+        build.rebuild(&[intro]).unwrap();
+        let patched = read_book_index(temp.path());
+
+        MDBook::load(temp.path()).unwrap().build().unwrap();
+        let rebuilt = read_book_index(temp.path());
+
+        assert!(patched.to_string().contains("completely different"));
+        assert_eq!(patched, rebuilt);
+    }
+
+    #[test]
+    fn sharded_index_reassembles_into_the_single_index() {
+        let temp = DummyBook::new().build().unwrap();
+        MDBook::load(temp.path()).unwrap().build().unwrap();
+        let single = read_book_index(temp.path());
+
+        let mut md = MDBook::load(temp.path()).unwrap();
+        md.config
+            .set("output.html.search.index-format", "sharded")
+            .unwrap();
+        md.build().unwrap();
+        let mut sharded = read_book_index(temp.path());
+
+        let read_shard = |name: &str| -> serde_json::Value {
+            let shard = temp
+                .path()
+                .join("book/searchindex")
+                .join(format!("{}.json", name));
+            serde_json::from_str(&fs::read_to_string(shard).unwrap()).unwrap()
+        };
+        let shards = sharded.as_object_mut().unwrap().remove("shards").unwrap();
+        let term_shards = shards["terms"].as_array().unwrap();
+        assert!(term_shards.len() > 1);
+        for name in term_shards {
+            let shard = read_shard(name.as_str().unwrap());
+            for (field, terms) in shard.as_object().unwrap() {
+                let root = &mut sharded["index"]["index"][field]["root"];
+                for (first_char, subtree) in terms.as_object().unwrap() {
+                    assert!(root.get(first_char).is_none());
+                    root[first_char] = subtree.clone();
+                }
+            }
+        }
+        for i in 0..shards["docs"].as_array().unwrap().len() {
+            let shard = read_shard(&format!("docs-{}", i));
+            for (doc_ref, doc) in shard.as_object().unwrap() {
+                sharded["index"]["documentStore"]["docs"][doc_ref] = doc.clone();
+            }
+        }
+
+        assert_eq!(sharded, single);
+    }
+
+    #[test]
+    fn search_index_uses_the_book_language() {
+        let temp = DummyBook::new().build().unwrap();
+        fs::write(
+            temp.path().join("src/intro.md"),
+            "# Einleitung\n\nDie Häuser sind groß.\n",
+        )
+        .unwrap();
+        let mut md = MDBook::load(temp.path()).unwrap();
+        md.config.set("book.language", "de").unwrap();
+        md.build().unwrap();
+
+        let index = read_book_index(temp.path());
+
+        let pipeline = &index["index"]["pipeline"];
+        assert_eq!(pipeline[0], "trimmer-de");
+        assert_eq!(pipeline[1], "stopWordFilter-de");
+        assert_eq!(pipeline[2], "stemmer-de");
+        let bodyidx = &index["index"]["index"]["body"]["root"];
+        assert_eq!(bodyidx["h"]["a"]["u"]["s"]["df"], 1);
+        assert!(bodyidx["d"]["i"]["e"].get("df").is_none());
+
+        // The search words have to be turned into the same terms
+        let book = temp.path().join("book");
+        assert!(book.join("lunr.stemmer.support.js").exists());
+        assert!(book.join("lunr.de.js").exists());
+        assert_contains_strings(
+            book.join("intro.html"),
+            &[
+                r#"<script src="lunr.stemmer.support.js""#,
+                r#"<script src="lunr.de.js""#,
+            ],
+        );
+    }
+
+    #[test]
+    fn search_index_segments_cjk_text() {
+        let temp = DummyBook::new().build().unwrap();
+        fs::write(
+            temp.path().join("src/intro.md"),
+            "# はじめに\n\n東京都に住む。\n",
+        )
+        .unwrap();
+        let mut md = MDBook::load(temp.path()).unwrap();
+        md.config.set("output.html.search.language", "ja").unwrap();
+        md.build().unwrap();
+
+        let index = read_book_index(temp.path());
+
+        assert_eq!(index["language"]["segment_cjk"], true);
+        assert_eq!(index["index"]["pipeline"][0], "trimmer-unicode");
+        assert!(temp.path().join("book/lunr.stemmer.support.js").exists());
+        let doc_urls = index["doc_urls"].as_array().unwrap();
+        let introduction = doc_urls
+            .iter()
+            .position(|s| s == "intro.html#はじめに")
+            .unwrap()
+            .to_string();
+        let bodyidx = &index["index"]["index"]["body"]["root"];
+        assert!(bodyidx["東"]["京"]["docs"].get(&introduction).is_some());
+        assert!(bodyidx["京"]["都"]["docs"].get(&introduction).is_some());
+        let docs = &index["index"]["documentStore"]["docs"];
+        assert_eq!(docs[&introduction]["body"], "東京都に住む。");
+    }
+
+    #[test]
+    fn search_config_excludes_and_boosts_chapters() {
+        let temp = DummyBook::new().build().unwrap();
+        let mut md = MDBook::load(temp.path()).unwrap();
+        md.config
+            .set("output.html.search.exclude", vec!["first/*"])
+            .unwrap();
+        let mut chapter_boost = toml::value::Table::new();
+        chapter_boost.insert("conclusion.md".to_string(), 2.5.into());
+        md.config
+            .set("output.html.search.chapter-boost", chapter_boost)
+            .unwrap();
+        md.build().unwrap();
+
+        let index = read_book_index(temp.path());
+
+        let doc_urls = index["doc_urls"].as_array().unwrap();
+        assert!(doc_urls.iter().any(|url| url == "intro.html#introduction"));
+        assert!(!doc_urls
+            .iter()
+            .any(|url| url.as_str().unwrap().starts_with("first/")));
+        let conclusion = doc_urls
+            .iter()
+            .position(|url| url == "conclusion.html#conclusion")
+            .unwrap()
+            .to_string();
+        let doc_boosts = index["doc_boosts"].as_object().unwrap();
+        assert_eq!(doc_boosts.len(), 1);
+        assert_eq!(doc_boosts[&conclusion], 2.5);
+    }
+
     // Setting this to `true` may cause issues with `cargo watch`,
     // since it may not finish writing the fixture before the tests
     // are run again.
@@ -19,3 +19,24 @@ fn mdbook_detects_book_with_failing_tests() {
 
     assert!(md.test(vec![]).is_err());
 }
+
+#[test]
+fn mdbook_test_reports_the_failing_chapter() {
+    let temp = DummyBook::new().with_passing_test(false).build().unwrap();
+    let mut md = MDBook::load(temp.path()).unwrap();
+
+    let got = md.test(vec![]).unwrap_err().to_string();
+
+    assert!(got.contains("in 1 of"));
+    assert!(got.contains("Nested Chapter (first/nested.md)"));
+}
+
+#[test]
+fn mdbook_test_can_be_limited_to_one_chapter() {
+    let temp = DummyBook::new().with_passing_test(false).build().unwrap();
+    let mut md = MDBook::load(temp.path()).unwrap();
+
+    assert!(md.test_chapter(vec![], Some("Introduction")).is_ok());
+    assert!(md.test_chapter(vec![], Some("first/nested.md")).is_err());
+    assert!(md.test_chapter(vec![], Some("Missing Chapter")).is_err());
+}